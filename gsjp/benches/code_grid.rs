@@ -0,0 +1,54 @@
+//! `MeshGrid`と`CodeGrid`でメッシュコードを走査する時間を比較するベンチマーク
+//!
+//! 次の通り実行する。
+//!
+//! ```sh
+//! cargo bench -p gsjp --bench code_grid
+//! ```
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use gsjp::mesh::{CodeGrid, Coordinate, Mesh, Mesh6, MeshGrid, MeshLevel};
+
+/// 計測を繰り返す回数
+const ITERATIONS: u32 = 5;
+
+/// 処理を繰り返して、最も短い処理時間を返す。
+fn measure<F: FnMut() -> usize>(mut f: F) -> (Duration, usize) {
+    let mut best = Duration::MAX;
+    let mut count = 0;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        count = black_box(f());
+        best = best.min(start.elapsed());
+    }
+
+    (best, count)
+}
+
+fn main() {
+    // 東京都の区部を含む範囲の8分の1地域メッシュ
+    let sw = Coordinate::new(35.5, 139.5).unwrap();
+    let ne = Coordinate::new(36.0, 140.0).unwrap();
+
+    let (mesh_grid, count) = measure(|| {
+        let mut count = 0;
+        for mesh in MeshGrid::<Mesh6>::new(sw, ne).unwrap() {
+            black_box(mesh.code().len());
+            count += 1;
+        }
+        count
+    });
+    println!("MeshGrid: {} codes in {:?}", count, mesh_grid);
+
+    let (code_grid, count) = measure(|| {
+        let mut grid = CodeGrid::new(MeshLevel::Mesh6, sw, ne).unwrap();
+        let mut count = 0;
+        while let Some(code) = grid.next_code() {
+            black_box(code.len());
+            count += 1;
+        }
+        count
+    });
+    println!("CodeGrid: {} codes in {:?}", count, code_grid);
+}
@@ -1,5 +1,10 @@
+use std::fmt;
+use std::str::FromStr;
+
 use regex::Regex;
 
+use crate::mesh::{meshes_in_bbox, Coordinate, GSJPError, Mesh};
+
 /// 国土基本図図郭
 ///
 /// 国土地理院が平面直角座標系の原点からの距離を基準に定義した図郭です。
@@ -67,6 +72,8 @@ pub enum BaseMapFrameErr {
     YOutOfRange,
     #[error("図郭がありません。")]
     FrameDoesNotExist,
+    #[error("地図情報レベルが不正です。")]
+    InvalidLevel,
 }
 
 pub type BaseMapFrameResult<T> = Result<T, BaseMapFrameErr>;
@@ -78,6 +85,24 @@ const LEVEL_2500_PATTERN: &str = "^[0-1][0-9][A-T][A-H][0-9][0-9][1-4]$";
 const LEVEL_1000_PATTERN: &str = "^[0-1][0-9][A-T][A-H][0-9][0-9][0-4][A-E]$";
 const LEVEL_500_PATTERN: &str = "^[0-1][0-9][A-T][A-H][0-9][0-9][0-9][0-9]$";
 
+/// 国土基本図図郭の図郭名の各位置に使用できる文字
+///
+/// 7文字目は地図情報レベル2500、1000及び500の図郭名、8文字目は地図情報レベル1000及び500の
+/// 図郭名に使用できる文字を合わせている。
+const FRAME_CODE_CHARS: [&str; 8] = [
+    "01",
+    "0123456789",
+    "ABCDEFGHIJKLMNOPQRST",
+    "ABCDEFGH",
+    "0123456789",
+    "0123456789",
+    "0123456789",
+    "0123456789ABCDE",
+];
+
+/// 国土基本図図郭の図郭名の長さ（地図情報レベル50000、5000、2500、1000及び500）
+const FRAME_CODE_LENS: [usize; 4] = [4, 6, 7, 8];
+
 impl TryFrom<String> for BaseMapFrame {
     type Error = BaseMapFrameErr;
 
@@ -112,6 +137,20 @@ impl TryFrom<String> for BaseMapFrame {
     }
 }
 
+impl FromStr for BaseMapFrame {
+    type Err = BaseMapFrameErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s.to_string())
+    }
+}
+
+impl fmt::Display for BaseMapFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
 impl BaseMapFrame {
     /// 国土基本図図郭のレベルと座標から国土基本図図郭を取得する。
     ///
@@ -183,11 +222,28 @@ impl BaseMapFrame {
         Ok(Self::try_from(code).unwrap())
     }
 
+    /// 国土基本図図郭の図郭名を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 国土基本図図郭の図郭名
+    #[must_use]
+    pub fn code(&self) -> &str {
+        match self {
+            BaseMapFrame::Level50000(code)
+            | BaseMapFrame::Level5000(code)
+            | BaseMapFrame::Level2500(code)
+            | BaseMapFrame::Level1000(code)
+            | BaseMapFrame::Level500(code) => code,
+        }
+    }
+
     /// 国土基本図図郭のレベルを返す。
     ///
     /// # 戻り値
     ///
     /// 国土基本図図郭のレベル
+    #[must_use]
     pub fn level(&self) -> BaseMapFrameLevel {
         match self {
             BaseMapFrame::Level50000(_) => BaseMapFrameLevel::Level50000,
@@ -203,6 +259,7 @@ impl BaseMapFrame {
     /// # 戻り値
     ///
     /// 国土基本図図郭の幅（m単位）
+    #[must_use]
     pub fn width(&self) -> u32 {
         match self {
             BaseMapFrame::Level50000(_) => 40_000,
@@ -218,6 +275,7 @@ impl BaseMapFrame {
     /// # 戻り値
     ///
     /// 国土基本図図郭の高さ（m単位）
+    #[must_use]
     pub fn height(&self) -> u32 {
         match self {
             BaseMapFrame::Level50000(_) => 30_000,
@@ -233,6 +291,7 @@ impl BaseMapFrame {
     /// # 戻り値
     ///
     /// 国土基本図図郭の範囲
+    #[must_use]
     pub fn extent(&self) -> FrameExtent {
         let (x, y) = match self {
             BaseMapFrame::Level50000(code) => level_50000_left_top(code),
@@ -245,15 +304,234 @@ impl BaseMapFrame {
         FrameExtent::new(x, y - self.height() as i32, x + self.width() as i32, y)
     }
 
-    pub fn left_frame(&self) -> BaseMapFrameResult<Self> {
+    /// 国土基本図図郭を、指定された地図情報レベルの図郭に分割する。
+    ///
+    /// 地図情報レベル50000の図郭は、地図情報レベル5000以下のすべてのレベルに分割できる。
+    /// 地図情報レベル5000の図郭は、地図情報レベル2500、1000及び500に分割できる。
+    /// 分割した図郭は、北から南、西から東に向かう順番（図郭名の順番）で格納する。
+    ///
+    /// # 引数
+    ///
+    /// * `to` - 分割後の図郭の地図情報レベル
+    ///
+    /// # 戻り値
+    ///
+    /// 分割した図郭。指定された地図情報レベルに分割できない場合は`BaseMapFrameErr::InvalidLevel`
+    pub fn subdivide(&self, to: BaseMapFrameLevel) -> BaseMapFrameResult<Vec<BaseMapFrame>> {
+        let frames = match (self, &to) {
+            (BaseMapFrame::Level50000(code), BaseMapFrameLevel::Level5000) => {
+                child_frames(code, "0123456789", "0123456789", BaseMapFrame::Level5000)
+            }
+            (BaseMapFrame::Level50000(_), BaseMapFrameLevel::Level50000) => {
+                return Err(BaseMapFrameErr::InvalidLevel)
+            }
+            (BaseMapFrame::Level50000(_), _) => {
+                // 地図情報レベル5000の図郭に分割してから、指定された地図情報レベルの図郭に分割
+                let mut frames = vec![];
+                for frame in self.subdivide(BaseMapFrameLevel::Level5000)? {
+                    frames.extend(frame.subdivide(to.clone())?);
+                }
+                frames
+            }
+            (BaseMapFrame::Level5000(code), BaseMapFrameLevel::Level2500) => (1..=4)
+                .map(|n| BaseMapFrame::Level2500(format!("{}{}", code, n)))
+                .collect(),
+            (BaseMapFrame::Level5000(code), BaseMapFrameLevel::Level1000) => {
+                child_frames(code, "01234", "ABCDE", BaseMapFrame::Level1000)
+            }
+            (BaseMapFrame::Level5000(code), BaseMapFrameLevel::Level500) => {
+                child_frames(code, "0123456789", "0123456789", BaseMapFrame::Level500)
+            }
+            _ => return Err(BaseMapFrameErr::InvalidLevel),
+        };
+
+        Ok(frames)
+    }
+
+    /// 国土基本図図郭を含む、1つ上の地図情報レベルを返す。
+    ///
+    /// 地図情報レベル2500、1000及び500の図郭は、地図情報レベル5000の図郭を分割した図郭であるため、
+    /// いずれも地図情報レベル5000を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 1つ上の地図情報レベル。地図情報レベル50000の場合は`None`
+    #[must_use]
+    pub fn parent_level(&self) -> Option<BaseMapFrameLevel> {
         match self {
-            BaseMapFrame::Level50000(_) => todo!(),
-            BaseMapFrame::Level5000(_) => todo!(),
-            BaseMapFrame::Level2500(_) => todo!(),
-            BaseMapFrame::Level1000(_) => todo!(),
-            BaseMapFrame::Level500(_) => todo!(),
+            BaseMapFrame::Level50000(_) => None,
+            BaseMapFrame::Level5000(_) => Some(BaseMapFrameLevel::Level50000),
+            BaseMapFrame::Level2500(_) | BaseMapFrame::Level1000(_) | BaseMapFrame::Level500(_) => {
+                Some(BaseMapFrameLevel::Level5000)
+            }
         }
     }
+
+    /// 国土基本図図郭を含む、指定された地図情報レベルの図郭を返す。
+    ///
+    /// 地図情報レベル1000と500の図郭名は同じ8文字であるため、図郭名の長さではなく
+    /// 図郭の地図情報レベルから、図郭名を切り詰める長さを決定する。
+    /// 図郭と同じ地図情報レベルを指定した場合は、図郭と同じ図郭を返す。
+    ///
+    /// # 引数
+    ///
+    /// * `to` - 図郭を含む図郭の地図情報レベル
+    ///
+    /// # 戻り値
+    ///
+    /// 指定された地図情報レベルの図郭。図郭を含まない地図情報レベルを指定した場合は
+    /// `BaseMapFrameErr::InvalidLevel`
+    pub fn to_level(&self, to: BaseMapFrameLevel) -> BaseMapFrameResult<BaseMapFrame> {
+        if self.level() == to {
+            return Ok(self.clone());
+        }
+        let (code, len) = match (self, &to) {
+            (
+                BaseMapFrame::Level5000(code)
+                | BaseMapFrame::Level2500(code)
+                | BaseMapFrame::Level1000(code)
+                | BaseMapFrame::Level500(code),
+                BaseMapFrameLevel::Level50000,
+            ) => (code, 4),
+            (
+                BaseMapFrame::Level2500(code)
+                | BaseMapFrame::Level1000(code)
+                | BaseMapFrame::Level500(code),
+                BaseMapFrameLevel::Level5000,
+            ) => (code, 6),
+            _ => return Err(BaseMapFrameErr::InvalidLevel),
+        };
+
+        Self::try_from(code[0..len].to_string())
+    }
+
+    /// 図郭名の一部から始まる図郭を返す。
+    ///
+    /// 図郭名の一部より長い図郭名のうち、最も短い図郭名を持つすべての図郭を、図郭名の順番で返す。
+    /// 例えば、`07AB`からは地図情報レベル5000の図郭を、`07AB101`からは地図情報レベル1000と
+    /// 地図情報レベル500の図郭を返す。
+    ///
+    /// # 引数
+    ///
+    /// * `partial` - 図郭名の一部
+    ///
+    /// # 戻り値
+    ///
+    /// 図郭名の一部から始まる図郭。図郭名の一部がいずれの地図情報レベルの図郭名の先頭とも
+    /// 一致しない場合は`BaseMapFrameErr::InvalidFrameCode`
+    pub fn completions(partial: &str) -> BaseMapFrameResult<Vec<BaseMapFrame>> {
+        let valid_prefix = partial.len() <= FRAME_CODE_CHARS.len()
+            && partial
+                .chars()
+                .zip(FRAME_CODE_CHARS)
+                .all(|(c, chars)| chars.contains(c));
+        if !valid_prefix {
+            return Err(BaseMapFrameErr::InvalidFrameCode);
+        }
+        let len = match FRAME_CODE_LENS.iter().find(|&&len| partial.len() < len) {
+            Some(&len) => len,
+            None => {
+                // 最も長い図郭名は、図郭名として正しい場合のみ受け付ける
+                Self::try_from(partial.to_string())?;
+                return Ok(vec![]);
+            }
+        };
+        let mut codes = vec![partial.to_string()];
+        for chars in &FRAME_CODE_CHARS[partial.len()..len] {
+            codes = codes
+                .iter()
+                .flat_map(|code| chars.chars().map(move |c| format!("{}{}", code, c)))
+                .collect();
+        }
+        let frames: Vec<BaseMapFrame> = codes
+            .into_iter()
+            .filter_map(|code| Self::try_from(code).ok())
+            .collect();
+        if frames.is_empty() {
+            return Err(BaseMapFrameErr::InvalidFrameCode);
+        }
+
+        Ok(frames)
+    }
+
+    /// 国土基本図図郭と重なるメッシュを返す。
+    ///
+    /// 図郭は平面直角座標系で、メッシュは緯度経度で定義されているため、平面直角座標系の座標を
+    /// 緯度経度に変換する関数を受け取る。
+    /// 図郭の四隅の座標を緯度経度に変換して、変換した座標をすべて含む範囲と重なるメッシュを返す。
+    /// 緯度経度では図郭の辺がわずかに曲がるため、図郭の辺の近くにあるメッシュを含む場合がある。
+    ///
+    /// # 引数
+    ///
+    /// * `inverse_transform` - 平面直角座標系の座標（X座標, Y座標）（m単位）を緯度経度に変換する関数
+    ///
+    /// # 戻り値
+    ///
+    /// 図郭と重なるメッシュ。変換した座標が標準地域メッシュの範囲外の場合はエラー
+    pub fn overlapping_meshes<M: Mesh>(
+        &self,
+        inverse_transform: impl Fn(f64, f64) -> Coordinate,
+    ) -> Result<Vec<M>, GSJPError> {
+        let extent = self.extent();
+        let (left, bottom) = (extent.left() as f64, extent.bottom() as f64);
+        let (right, top) = (extent.right() as f64, extent.top() as f64);
+        let corners = [
+            inverse_transform(left, top),
+            inverse_transform(right, top),
+            inverse_transform(right, bottom),
+            inverse_transform(left, bottom),
+        ];
+        let south = corners.iter().map(|c| c.lat()).fold(f64::MAX, f64::min);
+        let north = corners.iter().map(|c| c.lat()).fold(f64::MIN, f64::max);
+        let west = corners.iter().map(|c| c.lon()).fold(f64::MAX, f64::min);
+        let east = corners.iter().map(|c| c.lon()).fold(f64::MIN, f64::max);
+
+        meshes_in_bbox(Coordinate::new(south, west)?, Coordinate::new(north, east)?)
+    }
+
+    /// 国土基本図図郭の左（西）にある同じ地図情報レベルの図郭を返す。
+    ///
+    /// 平面直角座標系の系ごとに原点が異なり、隣り合う系の図郭の境界は一致しないため、
+    /// 系をまたぐ図郭は返さない。
+    /// 例えば、地図情報レベル50000の東西方向の番号が`A`の図郭の左の図郭はない。
+    ///
+    /// # 戻り値
+    ///
+    /// 左の図郭。左の図郭が系の範囲外になる場合は`BaseMapFrameErr::FrameDoesNotExist`
+    pub fn left_frame(&self) -> BaseMapFrameResult<Self> {
+        self.horizontal_frame(-1)
+    }
+
+    /// 国土基本図図郭の右（東）にある同じ地図情報レベルの図郭を返す。
+    ///
+    /// `left_frame`と同様に、系をまたぐ図郭は返さない。
+    /// 例えば、地図情報レベル50000の東西方向の番号が`H`の図郭の右の図郭はない。
+    ///
+    /// # 戻り値
+    ///
+    /// 右の図郭。右の図郭が系の範囲外になる場合は`BaseMapFrameErr::FrameDoesNotExist`
+    pub fn right_frame(&self) -> BaseMapFrameResult<Self> {
+        self.horizontal_frame(1)
+    }
+
+    /// 国土基本図図郭から東西方向に図郭の数だけ離れた、同じ地図情報レベルの図郭を返す。
+    ///
+    /// # 引数
+    ///
+    /// * `n` - 東西方向に離れた図郭の数（東が正）
+    ///
+    /// # 戻り値
+    ///
+    /// 離れた図郭。図郭が系の範囲外になる場合は`BaseMapFrameErr::FrameDoesNotExist`
+    fn horizontal_frame(&self, n: i32) -> BaseMapFrameResult<Self> {
+        // 図郭名は検証済みであるため、先頭の2文字を系の番号として解析できる
+        let system = self.code()[0..2].parse::<u8>().unwrap();
+        let (x, y) = self.extent().center_xy();
+        let x = x + (n * self.width() as i32) as f64;
+
+        Self::from_level_xy(system, self.level(), x, y)
+            .map_err(|_| BaseMapFrameErr::FrameDoesNotExist)
+    }
 }
 
 /// 国土基本図図郭の範囲
@@ -272,6 +550,10 @@ pub struct FrameExtent {
 impl FrameExtent {
     /// 国土基本図図郭の範囲を作成する。
     ///
+    /// `width`と`height`は右端と左端、上端と下端の差を符号なし整数で返すため、
+    /// 左端が右端より小さく、下端が上端より小さくなければならない。
+    /// 左端が右端以上の場合、または下端が上端以上の場合はパニックする。
+    ///
     /// # 引数
     ///
     /// * `left` - 国土基本図図郭の左端のx座標
@@ -283,6 +565,19 @@ impl FrameExtent {
     ///
     /// 国土基本図図郭の範囲
     pub fn new(left: i32, bottom: i32, right: i32, top: i32) -> Self {
+        assert!(
+            left < right,
+            "左端のx座標({})が右端のx座標({})以上です。",
+            left,
+            right
+        );
+        assert!(
+            bottom < top,
+            "下端のy座標({})が上端のy座標({})以上です。",
+            bottom,
+            top
+        );
+
         Self {
             left,
             bottom,
@@ -296,7 +591,8 @@ impl FrameExtent {
     /// # 戻り値
     ///
     /// 国土基本図図郭の左端のx座標
-    pub fn left(&self) -> i32 {
+    #[must_use]
+    pub const fn left(&self) -> i32 {
         self.left
     }
 
@@ -305,7 +601,8 @@ impl FrameExtent {
     /// # 戻り値
     ///
     /// 国土基本図図郭の下端のy座標
-    pub fn bottom(&self) -> i32 {
+    #[must_use]
+    pub const fn bottom(&self) -> i32 {
         self.bottom
     }
 
@@ -314,7 +611,8 @@ impl FrameExtent {
     /// # 戻り値
     ///
     /// 国土基本図図郭の右端のx座標
-    pub fn right(&self) -> i32 {
+    #[must_use]
+    pub const fn right(&self) -> i32 {
         self.right
     }
 
@@ -323,29 +621,187 @@ impl FrameExtent {
     /// # 戻り値
     ///
     /// 国土基本図図郭の上端のy座標
-    pub fn top(&self) -> i32 {
+    #[must_use]
+    pub const fn top(&self) -> i32 {
         self.top
     }
 
     /// 国土基本図図郭の幅をm単位で返す。
     ///
+    /// 右端が左端より小さい場合は、符号なし整数に変換したときに値が循環しないように0を返す。
+    ///
     /// # 戻り値
     ///
     /// 国土基本図図郭の幅（m単位）
-    pub fn width(&self) -> u32 {
-        (self.right - self.left) as u32
+    #[must_use]
+    pub const fn width(&self) -> u32 {
+        span(self.left, self.right)
     }
 
     /// 国土基本図図郭の高さをm単位で返す。
     ///
+    /// 上端が下端より小さい場合は、符号なし整数に変換したときに値が循環しないように0を返す。
+    ///
     /// # 戻り値
     ///
     /// 国土基本図図郭の高さ（m単位）
-    pub fn height(&self) -> u32 {
-        (self.top - self.bottom) as u32
+    #[must_use]
+    pub const fn height(&self) -> u32 {
+        span(self.bottom, self.top)
+    }
+
+    /// 国土基本図図郭の面積を平方m単位で返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 国土基本図図郭の面積（平方m単位）
+    #[must_use]
+    pub const fn area_m2(&self) -> f64 {
+        self.width() as f64 * self.height() as f64
+    }
+
+    /// 座標が国土基本図図郭に含まれるか確認する。
+    ///
+    /// 図郭の左端と下端は図郭に含み、右端と上端は図郭に含まない。
+    ///
+    /// # 引数
+    ///
+    /// * `x` - X座標（m単位）
+    /// * `y` - Y座標（m単位）
+    ///
+    /// # 戻り値
+    ///
+    /// 座標が図郭に含まれる場合は`true`、含まれない場合は`false`
+    #[must_use]
+    pub const fn contains_xy(&self, x: f64, y: f64) -> bool {
+        self.left as f64 <= x
+            && x < self.right as f64
+            && self.bottom as f64 <= y
+            && y < self.top as f64
+    }
+
+    /// 国土基本図図郭の中心の座標を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 国土基本図図郭の中心の座標（X座標, Y座標）（m単位）
+    #[must_use]
+    pub const fn center_xy(&self) -> (f64, f64) {
+        (
+            (self.left as f64 + self.right as f64) / 2.0,
+            (self.bottom as f64 + self.top as f64) / 2.0,
+        )
+    }
+
+    /// 国土基本図図郭の範囲が、別の範囲と重なるか確認する。
+    ///
+    /// `contains_xy`と同様に、範囲の右端と上端は範囲に含まないため、辺を共有するだけの範囲は重ならない。
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 別の範囲
+    ///
+    /// # 戻り値
+    ///
+    /// 範囲が重なる場合は`true`、重ならない場合は`false`
+    #[must_use]
+    pub const fn intersects(&self, other: &FrameExtent) -> bool {
+        self.left < other.right
+            && other.left < self.right
+            && self.bottom < other.top
+            && other.bottom < self.top
+    }
+
+    /// 国土基本図図郭の範囲と、別の範囲が重なる範囲を返す。
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 別の範囲
+    ///
+    /// # 戻り値
+    ///
+    /// 重なる範囲。範囲が重ならない場合は`None`
+    #[must_use]
+    pub fn intersection(&self, other: &FrameExtent) -> Option<FrameExtent> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        Some(FrameExtent::new(
+            self.left.max(other.left),
+            self.bottom.max(other.bottom),
+            self.right.min(other.right),
+            self.top.min(other.top),
+        ))
+    }
+
+    /// 国土基本図図郭の範囲と、別の範囲を含む最小の範囲を返す。
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 別の範囲
+    ///
+    /// # 戻り値
+    ///
+    /// 2つの範囲を含む最小の範囲
+    #[must_use]
+    pub fn union(&self, other: &FrameExtent) -> FrameExtent {
+        FrameExtent::new(
+            self.left.min(other.left),
+            self.bottom.min(other.bottom),
+            self.right.max(other.right),
+            self.top.max(other.top),
+        )
     }
 }
 
+/// 始点から終点までの長さを返す。
+///
+/// `i32`の差は`i32`の範囲を超える場合があるため、`i64`で計算する。
+///
+/// # 引数
+///
+/// * `from` - 始点の座標
+/// * `to` - 終点の座標
+///
+/// # 戻り値
+///
+/// 始点から終点までの長さ。終点が始点より小さい場合は0
+const fn span(from: i32, to: i32) -> u32 {
+    let diff = to as i64 - from as i64;
+    if diff < 0 {
+        0
+    } else {
+        diff as u32
+    }
+}
+
+/// 図郭名に南北方向の番号と東西方向の番号を付与して、分割した図郭を返す。
+///
+/// # 引数
+///
+/// * `code` - 分割する図郭の図郭名
+/// * `rows` - 北から南に向かう南北方向の番号
+/// * `cols` - 西から東に向かう東西方向の番号
+/// * `frame` - 分割後の地図情報レベルの図郭を作成する関数
+///
+/// # 戻り値
+///
+/// 分割した図郭
+fn child_frames(
+    code: &str,
+    rows: &str,
+    cols: &str,
+    frame: fn(String) -> BaseMapFrame,
+) -> Vec<BaseMapFrame> {
+    rows.chars()
+        .flat_map(|row| {
+            cols.chars()
+                .map(move |col| frame(format!("{}{}{}", code, row, col)))
+        })
+        .collect()
+}
+
 /// 地図情報レベル50000の図郭の左上の座標を返す。
 fn level_50000_left_top(code: &str) -> (i32, i32) {
     assert!(4 <= code.len(), "図郭コード({})の長さが不正です。", code);
@@ -453,6 +909,7 @@ fn level_500_left_top(code: &str) -> (i32, i32) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mesh::Mesh3;
 
     const EPSILON: f64 = 1e-6;
 
@@ -480,6 +937,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn code_ok() {
+        let codes = vec!["07AB", "07AB10", "07AB103", "07AB101A", "07AB1010"];
+        for code in codes {
+            let frame = BaseMapFrame::try_from(String::from(code)).unwrap();
+            assert_eq!(code, frame.code());
+        }
+    }
+
+    #[test]
+    fn from_str_display_ok() {
+        let inputs = vec![
+            ("07AB", BaseMapFrameLevel::Level50000),
+            ("07AB10", BaseMapFrameLevel::Level5000),
+            ("07AB103", BaseMapFrameLevel::Level2500),
+            ("07AB101A", BaseMapFrameLevel::Level1000),
+            ("07AB1010", BaseMapFrameLevel::Level500),
+        ];
+        for (code, level) in inputs {
+            let frame = code.parse::<BaseMapFrame>().unwrap();
+            assert_eq!(level, frame.level(), "{}", code);
+            assert_eq!(code, format!("{frame}"));
+            assert_eq!(frame, frame.to_string().parse::<BaseMapFrame>().unwrap());
+        }
+    }
+
+    #[test]
+    fn from_str_err() {
+        let codes = vec!["", "07A", "07AB1", "07AB105", "07ZB"];
+        for code in codes {
+            assert_eq!(
+                Err(BaseMapFrameErr::InvalidFrameCode),
+                code.parse::<BaseMapFrame>(),
+                "{}",
+                code
+            );
+        }
+    }
+
     #[test]
     fn width_ok() {
         let frames = vec![
@@ -538,6 +1034,306 @@ mod tests {
         }
     }
 
+    #[test]
+    fn area_m2_ok() {
+        let frame = BaseMapFrame::try_from(String::from("07AA")).unwrap();
+        assert_eq!(1.2e9, frame.extent().area_m2());
+    }
+
+    #[test]
+    fn contains_xy_ok() {
+        // 07JE44の図郭の範囲は、X座標が16,000mから20,000m、Y座標が15,000mから18,000m
+        let extent = BaseMapFrame::try_from(String::from("07JE44"))
+            .unwrap()
+            .extent();
+        let inputs = vec![
+            // 南西端（左端と下端は図郭に含む）
+            (16_000.0, 15_000.0, true),
+            // 南東端（右端は図郭に含まない）
+            (20_000.0, 15_000.0, false),
+            // 北東端（右端と上端は図郭に含まない）
+            (20_000.0, 18_000.0, false),
+            // 北西端（上端は図郭に含まない）
+            (16_000.0, 18_000.0, false),
+            // 中心
+            (18_000.0, 16_500.0, true),
+            // 右端と上端の直前
+            (19_999.999, 17_999.999, true),
+            // 左端と下端の直前
+            (15_999.999, 14_999.999, false),
+        ];
+        for (x, y, expected) in inputs {
+            assert_eq!(expected, extent.contains_xy(x, y), "x: {}, y: {}", x, y);
+        }
+    }
+
+    #[test]
+    fn extent_new_ok() {
+        let extent = FrameExtent::new(-40_000, -30_000, 0, 0);
+        assert_eq!(40_000, extent.width());
+        assert_eq!(30_000, extent.height());
+        let extent = FrameExtent::new(0, 0, 1, 1);
+        assert_eq!(1, extent.width());
+        assert_eq!(1, extent.height());
+    }
+
+    #[test]
+    #[should_panic(expected = "左端のx座標(100)が右端のx座標(0)以上です。")]
+    fn extent_new_reversed_x() {
+        let _ = FrameExtent::new(100, 0, 0, 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "下端のy座標(100)が上端のy座標(0)以上です。")]
+    fn extent_new_reversed_y() {
+        let _ = FrameExtent::new(0, 100, 100, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "左端のx座標(0)が右端のx座標(0)以上です。")]
+    fn extent_new_empty() {
+        let _ = FrameExtent::new(0, 0, 0, 100);
+    }
+
+    #[test]
+    fn extent_width_height_degenerate() {
+        // 範囲の作成時の検証を経ない、右端が左端より小さく、上端が下端より小さい範囲
+        let extent = FrameExtent {
+            left: 100,
+            bottom: 100,
+            right: 0,
+            top: -100,
+        };
+        assert_eq!(0, extent.width());
+        assert_eq!(0, extent.height());
+        assert_eq!(0.0, extent.area_m2());
+        // `i32`の差が`i32`の範囲を超える範囲
+        let extent = FrameExtent {
+            left: i32::MIN,
+            bottom: i32::MIN,
+            right: i32::MAX,
+            top: i32::MAX,
+        };
+        assert_eq!(u32::MAX, extent.width());
+        assert_eq!(u32::MAX, extent.height());
+    }
+
+    #[test]
+    fn intersects_ok() {
+        let extent = FrameExtent::new(0, 0, 100, 100);
+        let inputs = vec![
+            // 重なる
+            (FrameExtent::new(50, 50, 150, 150), true),
+            // 含む
+            (FrameExtent::new(10, 10, 20, 20), true),
+            (FrameExtent::new(-10, -10, 110, 110), true),
+            // 辺を共有する
+            (FrameExtent::new(100, 0, 200, 100), false),
+            (FrameExtent::new(0, 100, 100, 200), false),
+            (FrameExtent::new(-100, 0, 0, 100), false),
+            (FrameExtent::new(0, -100, 100, 0), false),
+            // 頂点を共有する
+            (FrameExtent::new(100, 100, 200, 200), false),
+            // 離れている
+            (FrameExtent::new(200, 200, 300, 300), false),
+            (FrameExtent::new(0, 200, 100, 300), false),
+        ];
+        for (other, expected) in inputs {
+            assert_eq!(expected, extent.intersects(&other), "{:?}", other);
+            assert_eq!(expected, other.intersects(&extent), "{:?}", other);
+        }
+    }
+
+    #[test]
+    fn intersection_ok() {
+        let extent = FrameExtent::new(0, 0, 100, 100);
+        let inputs = vec![
+            (
+                FrameExtent::new(50, 50, 150, 150),
+                Some(FrameExtent::new(50, 50, 100, 100)),
+            ),
+            (
+                FrameExtent::new(10, -10, 20, 110),
+                Some(FrameExtent::new(10, 0, 20, 100)),
+            ),
+            (FrameExtent::new(100, 0, 200, 100), None),
+            (FrameExtent::new(200, 200, 300, 300), None),
+        ];
+        for (other, expected) in inputs {
+            assert_eq!(expected, extent.intersection(&other), "{:?}", other);
+            assert_eq!(expected, other.intersection(&extent), "{:?}", other);
+        }
+    }
+
+    #[test]
+    fn union_ok() {
+        let extent = FrameExtent::new(0, 0, 100, 100);
+        let inputs = vec![
+            (
+                FrameExtent::new(50, 50, 150, 150),
+                FrameExtent::new(0, 0, 150, 150),
+            ),
+            (
+                FrameExtent::new(100, 0, 200, 100),
+                FrameExtent::new(0, 0, 200, 100),
+            ),
+            (
+                FrameExtent::new(200, -300, 300, -200),
+                FrameExtent::new(0, -300, 300, 100),
+            ),
+            (FrameExtent::new(10, 10, 20, 20), extent),
+        ];
+        for (other, expected) in inputs {
+            assert_eq!(expected, extent.union(&other), "{:?}", other);
+            assert_eq!(expected, other.union(&extent), "{:?}", other);
+        }
+        // 隣り合う図郭を合わせた範囲
+        let a = BaseMapFrame::try_from(String::from("07JE44")).unwrap();
+        let b = a.right_frame().unwrap();
+        let union = a.extent().union(&b.extent());
+        assert_eq!(a.width() * 2, union.width());
+        assert_eq!(a.height(), union.height());
+    }
+
+    #[test]
+    fn center_xy_ok() {
+        let extent = BaseMapFrame::try_from(String::from("07JE44"))
+            .unwrap()
+            .extent();
+        let (x, y) = extent.center_xy();
+        assert_eq!((18_000.0, 16_500.0), (x, y));
+        assert!(extent.contains_xy(x, y));
+    }
+
+    #[test]
+    fn subdivide_ok() {
+        let inputs = vec![
+            ("07JE", BaseMapFrameLevel::Level5000, 100),
+            ("07JE", BaseMapFrameLevel::Level2500, 400),
+            ("07JE", BaseMapFrameLevel::Level1000, 2_500),
+            ("07JE", BaseMapFrameLevel::Level500, 10_000),
+            ("07JE44", BaseMapFrameLevel::Level2500, 4),
+            ("07JE44", BaseMapFrameLevel::Level1000, 25),
+            ("07JE44", BaseMapFrameLevel::Level500, 100),
+        ];
+        for (code, level, count) in inputs {
+            let parent = BaseMapFrame::try_from(String::from(code)).unwrap();
+            let extent = parent.extent();
+            let children = parent.subdivide(level.clone()).unwrap();
+            assert_eq!(count, children.len(), "{}: {:?}", code, level);
+            let mut area = 0.0;
+            for child in children {
+                assert_eq!(level, child.level(), "{:?}", child);
+                let child_extent = child.extent();
+                assert!(
+                    extent.left() <= child_extent.left()
+                        && child_extent.right() <= extent.right()
+                        && extent.bottom() <= child_extent.bottom()
+                        && child_extent.top() <= extent.top(),
+                    "{:?}: {:?}",
+                    child,
+                    child_extent
+                );
+                area += child_extent.area_m2();
+            }
+            // 分割した図郭は重ならずに親の図郭を覆う
+            assert_eq!(extent.area_m2(), area, "{}: {:?}", code, level);
+        }
+    }
+
+    #[test]
+    fn subdivide_order_ok() {
+        let parent = BaseMapFrame::try_from(String::from("07JE44")).unwrap();
+        let children = parent.subdivide(BaseMapFrameLevel::Level1000).unwrap();
+        // 北西端から始まり、東西方向に並ぶ
+        assert_eq!(
+            BaseMapFrame::try_from(String::from("07JE440A")).unwrap(),
+            children[0]
+        );
+        assert_eq!(
+            BaseMapFrame::try_from(String::from("07JE440B")).unwrap(),
+            children[1]
+        );
+        assert_eq!(
+            BaseMapFrame::try_from(String::from("07JE444E")).unwrap(),
+            children[24]
+        );
+    }
+
+    #[test]
+    fn subdivide_err() {
+        let inputs = vec![
+            ("07JE", BaseMapFrameLevel::Level50000),
+            ("07JE44", BaseMapFrameLevel::Level50000),
+            ("07JE44", BaseMapFrameLevel::Level5000),
+            ("07JE441", BaseMapFrameLevel::Level1000),
+            ("07JE440A", BaseMapFrameLevel::Level500),
+            ("07JE4400", BaseMapFrameLevel::Level500),
+        ];
+        for (code, level) in inputs {
+            let frame = BaseMapFrame::try_from(String::from(code)).unwrap();
+            assert_eq!(
+                Err(BaseMapFrameErr::InvalidLevel),
+                frame.subdivide(level.clone()),
+                "{}: {:?}",
+                code,
+                level
+            );
+        }
+    }
+
+    #[test]
+    fn parent_level_ok() {
+        let inputs = vec![
+            ("07JE", None),
+            ("07JE44", Some(BaseMapFrameLevel::Level50000)),
+            ("07JE441", Some(BaseMapFrameLevel::Level5000)),
+            ("07JE440A", Some(BaseMapFrameLevel::Level5000)),
+            ("07JE4400", Some(BaseMapFrameLevel::Level5000)),
+        ];
+        for (code, expected) in inputs {
+            let frame = BaseMapFrame::try_from(String::from(code)).unwrap();
+            assert_eq!(expected, frame.parent_level(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn to_level_ok() {
+        let inputs = vec![
+            ("07JE4400", BaseMapFrameLevel::Level5000, "07JE44"),
+            ("07JE4400", BaseMapFrameLevel::Level50000, "07JE"),
+            ("07JE440A", BaseMapFrameLevel::Level5000, "07JE44"),
+            ("07JE440A", BaseMapFrameLevel::Level50000, "07JE"),
+            ("07JE441", BaseMapFrameLevel::Level5000, "07JE44"),
+            ("07JE44", BaseMapFrameLevel::Level50000, "07JE"),
+            ("07JE4400", BaseMapFrameLevel::Level500, "07JE4400"),
+        ];
+        for (code, level, expected) in inputs {
+            let frame = BaseMapFrame::try_from(String::from(code)).unwrap();
+            let expected = BaseMapFrame::try_from(String::from(expected)).unwrap();
+            assert_eq!(expected, frame.to_level(level).unwrap(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn to_level_err() {
+        let inputs = vec![
+            ("07JE", BaseMapFrameLevel::Level5000),
+            ("07JE44", BaseMapFrameLevel::Level500),
+            ("07JE4400", BaseMapFrameLevel::Level1000),
+            ("07JE440A", BaseMapFrameLevel::Level2500),
+        ];
+        for (code, level) in inputs {
+            let frame = BaseMapFrame::try_from(String::from(code)).unwrap();
+            assert_eq!(
+                Err(BaseMapFrameErr::InvalidLevel),
+                frame.to_level(level),
+                "{}",
+                code
+            );
+        }
+    }
+
     #[test]
     fn level_50000_extent_ok() {
         let frames = vec![
@@ -921,4 +1717,158 @@ mod tests {
             }
         }
     }
+
+    /// 平面直角座標系の座標を、東経139度、北緯35度を原点として1mを0.00001度とみなして変換する。
+    fn synthetic_transform(x: f64, y: f64) -> Coordinate {
+        Coordinate::new(35.0 + y / 100_000.0, 139.0 + x / 100_000.0).unwrap()
+    }
+
+    #[test]
+    fn overlapping_meshes_ok() {
+        // 07JE44の図郭の範囲は、X座標が16,000mから20,000m、Y座標が15,000mから18,000mであるため、
+        // 東経139.16度から139.2度、北緯35.15度から35.18度の範囲に変換される
+        let frame = BaseMapFrame::try_from(String::from("07JE44")).unwrap();
+        let meshes = frame
+            .overlapping_meshes::<Mesh3>(synthetic_transform)
+            .unwrap();
+        let expected = meshes_in_bbox::<Mesh3>(
+            Coordinate::new(35.15, 139.16).unwrap(),
+            Coordinate::new(35.18, 139.2).unwrap(),
+        )
+        .unwrap();
+        assert!(!meshes.is_empty());
+        assert_eq!(expected, meshes);
+        for mesh in &meshes {
+            assert!(
+                mesh.south() < 35.18 && 35.15 < mesh.north(),
+                "{}",
+                mesh.code()
+            );
+            assert!(
+                mesh.west() < 139.2 && 139.16 < mesh.east(),
+                "{}",
+                mesh.code()
+            );
+        }
+    }
+
+    #[test]
+    fn overlapping_meshes_err() {
+        // 標準地域メッシュの範囲外に変換される
+        let frame = BaseMapFrame::try_from(String::from("07JE44")).unwrap();
+        let result = frame.overlapping_meshes::<Mesh3>(|x, y| {
+            Coordinate::new(60.0 + y / 100_000.0, 139.0 + x / 100_000.0).unwrap()
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn left_right_frame_ok() {
+        let inputs = vec![
+            ("07AB", "07AA", "07AC"),
+            ("07TG", "07TF", "07TH"),
+            ("07AB10", "07AA19", "07AB11"),
+            ("07AB19", "07AB18", "07AC10"),
+            ("07AB101", "07AA192", "07AB102"),
+            ("07AB102", "07AB101", "07AB111"),
+            ("07AB101A", "07AA191E", "07AB101B"),
+            ("07AB101E", "07AB101D", "07AB111A"),
+            ("07AB1010", "07AA1919", "07AB1011"),
+            ("07AB1019", "07AB1018", "07AB1110"),
+        ];
+        for (code, left, right) in inputs {
+            let frame = BaseMapFrame::try_from(String::from(code)).unwrap();
+            assert_eq!(left, frame.left_frame().unwrap().code(), "{}", code);
+            assert_eq!(right, frame.right_frame().unwrap().code(), "{}", code);
+            assert_eq!(frame.level(), frame.left_frame().unwrap().level());
+        }
+    }
+
+    #[test]
+    fn left_right_frame_err() {
+        // 系の西端の図郭
+        for code in ["07AA", "07TA", "07AA10", "07AA103", "07AA100A", "07AA1000"] {
+            let frame = BaseMapFrame::try_from(String::from(code)).unwrap();
+            assert_eq!(
+                Err(BaseMapFrameErr::FrameDoesNotExist),
+                frame.left_frame(),
+                "{}",
+                code
+            );
+        }
+        // 系の東端の図郭
+        for code in ["07AH", "07TH", "07AH19", "07AH194", "07AH194E", "07AH1999"] {
+            let frame = BaseMapFrame::try_from(String::from(code)).unwrap();
+            assert_eq!(
+                Err(BaseMapFrameErr::FrameDoesNotExist),
+                frame.right_frame(),
+                "{}",
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn completions_ok() {
+        // 地図情報レベル50000の図郭名の一部
+        let frames = BaseMapFrame::completions("07A").unwrap();
+        assert_eq!(8, frames.len());
+        assert_eq!("07AA", frames[0].code());
+        assert_eq!("07AH", frames[7].code());
+        // 地図情報レベル5000の子の図郭
+        let frames = BaseMapFrame::completions("07AB").unwrap();
+        let children = BaseMapFrame::try_from(String::from("07AB"))
+            .unwrap()
+            .subdivide(BaseMapFrameLevel::Level5000)
+            .unwrap();
+        assert_eq!(100, frames.len());
+        assert_eq!(children, frames);
+        // 地図情報レベル2500の図郭
+        let frames = BaseMapFrame::completions("07AB10").unwrap();
+        assert_eq!(4, frames.len());
+        assert!(frames
+            .iter()
+            .all(|frame| frame.level() == BaseMapFrameLevel::Level2500));
+        // 地図情報レベル1000と500の図郭
+        let frames = BaseMapFrame::completions("07AB101").unwrap();
+        let level_1000 = frames
+            .iter()
+            .filter(|frame| frame.level() == BaseMapFrameLevel::Level1000)
+            .map(|frame| frame.code())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec!["07AB101A", "07AB101B", "07AB101C", "07AB101D", "07AB101E"],
+            level_1000
+        );
+        assert_eq!(15, frames.len());
+        assert!(frames
+            .iter()
+            .all(|frame| frame.code().starts_with("07AB101")));
+        // 最も長い図郭名
+        assert_eq!(
+            Vec::<BaseMapFrame>::new(),
+            BaseMapFrame::completions("07AB101A").unwrap()
+        );
+    }
+
+    #[test]
+    fn completions_err() {
+        let inputs = vec![
+            "2",
+            "07U",
+            "07AI",
+            "07ABX",
+            "07AB105A",
+            "07AB101F",
+            "07AB101AA",
+        ];
+        for partial in inputs {
+            assert_eq!(
+                Err(BaseMapFrameErr::InvalidFrameCode),
+                BaseMapFrame::completions(partial),
+                "{}",
+                partial
+            );
+        }
+    }
 }
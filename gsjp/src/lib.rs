@@ -1,2 +1,11 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("`std`フィーチャーを無効にする場合は、`libm`フィーチャーを有効にしてください。");
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
 pub mod base_map_frame;
+mod math;
 pub mod mesh;
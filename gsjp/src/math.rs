@@ -0,0 +1,133 @@
+//! 浮動小数点数の演算
+//!
+//! `std`フィーチャーが有効な場合は標準ライブラリの演算を、無効な場合は`libm`の演算を使用する。
+
+/// 引数以下の最大の整数を返す。
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn floor(x: f64) -> f64 {
+    x.floor()
+}
+
+/// 引数以下の最大の整数を返す。
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn floor(x: f64) -> f64 {
+    libm::floor(x)
+}
+
+/// 引数以上の最小の整数を返す。
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn ceil(x: f64) -> f64 {
+    x.ceil()
+}
+
+/// 引数以上の最小の整数を返す。
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn ceil(x: f64) -> f64 {
+    libm::ceil(x)
+}
+
+/// 引数に最も近い整数を返す。
+///
+/// 2つの整数の中間にある場合は、0から遠い方の整数を返す。
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn round(x: f64) -> f64 {
+    x.round()
+}
+
+/// 引数に最も近い整数を返す。
+///
+/// 2つの整数の中間にある場合は、0から遠い方の整数を返す。
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn round(x: f64) -> f64 {
+    libm::round(x)
+}
+
+/// 引数（ラジアン単位）の余弦を返す。
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn cos(x: f64) -> f64 {
+    x.cos()
+}
+
+/// 引数（ラジアン単位）の余弦を返す。
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn cos(x: f64) -> f64 {
+    libm::cos(x)
+}
+
+/// 引数（ラジアン単位）の正弦を返す。
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn sin(x: f64) -> f64 {
+    x.sin()
+}
+
+/// 引数（ラジアン単位）の正弦を返す。
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn sin(x: f64) -> f64 {
+    libm::sin(x)
+}
+
+/// 引数の逆正弦（ラジアン単位）を返す。
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn asin(x: f64) -> f64 {
+    x.asin()
+}
+
+/// 引数の逆正弦（ラジアン単位）を返す。
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn asin(x: f64) -> f64 {
+    libm::asin(x)
+}
+
+/// 引数の平方根を返す。
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+
+/// 引数の平方根を返す。
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+
+/// 引数（ラジアン単位）の正接を返す。
+#[cfg(all(feature = "mvt", feature = "std"))]
+#[inline]
+pub(crate) fn tan(x: f64) -> f64 {
+    x.tan()
+}
+
+/// 引数（ラジアン単位）の正接を返す。
+#[cfg(all(feature = "mvt", not(feature = "std")))]
+#[inline]
+pub(crate) fn tan(x: f64) -> f64 {
+    libm::tan(x)
+}
+
+/// 引数の自然対数を返す。
+#[cfg(all(feature = "mvt", feature = "std"))]
+#[inline]
+pub(crate) fn ln(x: f64) -> f64 {
+    x.ln()
+}
+
+/// 引数の自然対数を返す。
+#[cfg(all(feature = "mvt", not(feature = "std")))]
+#[inline]
+pub(crate) fn ln(x: f64) -> f64 {
+    libm::log(x)
+}
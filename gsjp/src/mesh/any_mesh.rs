@@ -0,0 +1,1033 @@
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::mesh::mesh1::validate_mesh1_code;
+use crate::mesh::mesh2::validate_mesh2_code;
+use crate::mesh::mesh3::validate_mesh3_code;
+use crate::mesh::mesh4::validate_mesh4_code;
+use crate::mesh::mesh5::validate_mesh5_code;
+use crate::mesh::mesh6::validate_mesh6_code;
+use crate::mesh::{
+    Coordinate, EarthModel, GSJPError, Mesh, Mesh1, Mesh2, Mesh3, Mesh4, Mesh5, Mesh6, MeshLevel,
+    NeighborDirection,
+};
+
+/// 任意のレベルのメッシュ
+///
+/// レベルが異なるメッシュを、トレイトオブジェクトを使用せずに1つのコレクションで扱うために使用する。
+///
+/// ハッシュ値と等価性は、メッシュのレベルとメッシュコードで決まる。
+/// このため、`HashSet`などで同じメッシュコードのメッシュは1つにまとまり、
+/// メッシュコードの先頭が一致するレベルの異なるメッシュ（例えば、`53393599`と`533935991`）は
+/// 別のメッシュとして扱われる。
+///
+/// 順序は、粗いレベルのメッシュが先で、同じレベルのメッシュはメッシュコードの順番である。
+///
+/// `Mesh`トレイトのメソッドは内部のメッシュに委譲するため、隣のメッシュなどは内部のメッシュと同じレベルになる。
+/// ただし、座標だけではレベルを決定できないため、`from_coordinate`と`from_coordinate_eps`は
+/// 常に基準地域メッシュを作成する。`MeshGrid`や`MeshSet`など、座標からメッシュを作成する処理で
+/// `AnyMesh`を指定した場合も基準地域メッシュになるため、他のレベルで扱う場合はそのレベルの型を指定すること。
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AnyMesh {
+    /// 第1次地域区画
+    Mesh1(Mesh1),
+    /// 第2次地域区画
+    Mesh2(Mesh2),
+    /// 基準地域メッシュ（第3次地域区画）
+    Mesh3(Mesh3),
+    /// 2分の1地域メッシュ（分割地域メッシュ）
+    Mesh4(Mesh4),
+    /// 4分の1地域メッシュ（分割地域メッシュ）
+    Mesh5(Mesh5),
+    /// 8分の1地域メッシュ（分割地域メッシュ）
+    Mesh6(Mesh6),
+}
+
+impl Mesh for AnyMesh {
+    /// メッシュコードの長さからメッシュのレベルを判定して、メッシュを作成する。
+    fn new(code: String) -> Result<Self, GSJPError> {
+        match MeshLevel::from_code_len(code.len()) {
+            Some(MeshLevel::Mesh1) => Ok(Self::Mesh1(Mesh1::new(code)?)),
+            Some(MeshLevel::Mesh2) => Ok(Self::Mesh2(Mesh2::new(code)?)),
+            Some(MeshLevel::Mesh3) => Ok(Self::Mesh3(Mesh3::new(code)?)),
+            Some(MeshLevel::Mesh4) => Ok(Self::Mesh4(Mesh4::new(code)?)),
+            Some(MeshLevel::Mesh5) => Ok(Self::Mesh5(Mesh5::new(code)?)),
+            Some(MeshLevel::Mesh6) => Ok(Self::Mesh6(Mesh6::new(code)?)),
+            None => Err(GSJPError::InvalidMeshCode),
+        }
+    }
+
+    /// 座標だけではメッシュのレベルを決定できないため、基準地域メッシュを作成する。
+    fn from_coordinate(coord: Coordinate) -> Result<Self, GSJPError> {
+        Ok(Self::Mesh3(Mesh3::from_coordinate(coord)?))
+    }
+
+    fn code(&self) -> &str {
+        match self {
+            Self::Mesh1(mesh) => mesh.code(),
+            Self::Mesh2(mesh) => mesh.code(),
+            Self::Mesh3(mesh) => mesh.code(),
+            Self::Mesh4(mesh) => mesh.code(),
+            Self::Mesh5(mesh) => mesh.code(),
+            Self::Mesh6(mesh) => mesh.code(),
+        }
+    }
+
+    fn into_code(self) -> String {
+        match self {
+            Self::Mesh1(mesh) => mesh.into_code(),
+            Self::Mesh2(mesh) => mesh.into_code(),
+            Self::Mesh3(mesh) => mesh.into_code(),
+            Self::Mesh4(mesh) => mesh.into_code(),
+            Self::Mesh5(mesh) => mesh.into_code(),
+            Self::Mesh6(mesh) => mesh.into_code(),
+        }
+    }
+
+    fn north(&self) -> f64 {
+        match self {
+            Self::Mesh1(mesh) => mesh.north(),
+            Self::Mesh2(mesh) => mesh.north(),
+            Self::Mesh3(mesh) => mesh.north(),
+            Self::Mesh4(mesh) => mesh.north(),
+            Self::Mesh5(mesh) => mesh.north(),
+            Self::Mesh6(mesh) => mesh.north(),
+        }
+    }
+
+    fn east(&self) -> f64 {
+        match self {
+            Self::Mesh1(mesh) => mesh.east(),
+            Self::Mesh2(mesh) => mesh.east(),
+            Self::Mesh3(mesh) => mesh.east(),
+            Self::Mesh4(mesh) => mesh.east(),
+            Self::Mesh5(mesh) => mesh.east(),
+            Self::Mesh6(mesh) => mesh.east(),
+        }
+    }
+
+    fn south(&self) -> f64 {
+        match self {
+            Self::Mesh1(mesh) => mesh.south(),
+            Self::Mesh2(mesh) => mesh.south(),
+            Self::Mesh3(mesh) => mesh.south(),
+            Self::Mesh4(mesh) => mesh.south(),
+            Self::Mesh5(mesh) => mesh.south(),
+            Self::Mesh6(mesh) => mesh.south(),
+        }
+    }
+
+    fn west(&self) -> f64 {
+        match self {
+            Self::Mesh1(mesh) => mesh.west(),
+            Self::Mesh2(mesh) => mesh.west(),
+            Self::Mesh3(mesh) => mesh.west(),
+            Self::Mesh4(mesh) => mesh.west(),
+            Self::Mesh5(mesh) => mesh.west(),
+            Self::Mesh6(mesh) => mesh.west(),
+        }
+    }
+
+    fn north_mesh(&self) -> Result<Self, GSJPError> {
+        match self {
+            Self::Mesh1(mesh) => mesh.north_mesh().map(Self::Mesh1),
+            Self::Mesh2(mesh) => mesh.north_mesh().map(Self::Mesh2),
+            Self::Mesh3(mesh) => mesh.north_mesh().map(Self::Mesh3),
+            Self::Mesh4(mesh) => mesh.north_mesh().map(Self::Mesh4),
+            Self::Mesh5(mesh) => mesh.north_mesh().map(Self::Mesh5),
+            Self::Mesh6(mesh) => mesh.north_mesh().map(Self::Mesh6),
+        }
+    }
+
+    fn east_mesh(&self) -> Result<Self, GSJPError> {
+        match self {
+            Self::Mesh1(mesh) => mesh.east_mesh().map(Self::Mesh1),
+            Self::Mesh2(mesh) => mesh.east_mesh().map(Self::Mesh2),
+            Self::Mesh3(mesh) => mesh.east_mesh().map(Self::Mesh3),
+            Self::Mesh4(mesh) => mesh.east_mesh().map(Self::Mesh4),
+            Self::Mesh5(mesh) => mesh.east_mesh().map(Self::Mesh5),
+            Self::Mesh6(mesh) => mesh.east_mesh().map(Self::Mesh6),
+        }
+    }
+
+    fn south_mesh(&self) -> Result<Self, GSJPError> {
+        match self {
+            Self::Mesh1(mesh) => mesh.south_mesh().map(Self::Mesh1),
+            Self::Mesh2(mesh) => mesh.south_mesh().map(Self::Mesh2),
+            Self::Mesh3(mesh) => mesh.south_mesh().map(Self::Mesh3),
+            Self::Mesh4(mesh) => mesh.south_mesh().map(Self::Mesh4),
+            Self::Mesh5(mesh) => mesh.south_mesh().map(Self::Mesh5),
+            Self::Mesh6(mesh) => mesh.south_mesh().map(Self::Mesh6),
+        }
+    }
+
+    fn west_mesh(&self) -> Result<Self, GSJPError> {
+        match self {
+            Self::Mesh1(mesh) => mesh.west_mesh().map(Self::Mesh1),
+            Self::Mesh2(mesh) => mesh.west_mesh().map(Self::Mesh2),
+            Self::Mesh3(mesh) => mesh.west_mesh().map(Self::Mesh3),
+            Self::Mesh4(mesh) => mesh.west_mesh().map(Self::Mesh4),
+            Self::Mesh5(mesh) => mesh.west_mesh().map(Self::Mesh5),
+            Self::Mesh6(mesh) => mesh.west_mesh().map(Self::Mesh6),
+        }
+    }
+
+    fn level(&self) -> MeshLevel {
+        match self {
+            Self::Mesh1(mesh) => mesh.level(),
+            Self::Mesh2(mesh) => mesh.level(),
+            Self::Mesh3(mesh) => mesh.level(),
+            Self::Mesh4(mesh) => mesh.level(),
+            Self::Mesh5(mesh) => mesh.level(),
+            Self::Mesh6(mesh) => mesh.level(),
+        }
+    }
+
+    fn neighbor(&self, dir: NeighborDirection) -> Result<Self, GSJPError> {
+        match self {
+            Self::Mesh1(mesh) => mesh.neighbor(dir).map(Self::Mesh1),
+            Self::Mesh2(mesh) => mesh.neighbor(dir).map(Self::Mesh2),
+            Self::Mesh3(mesh) => mesh.neighbor(dir).map(Self::Mesh3),
+            Self::Mesh4(mesh) => mesh.neighbor(dir).map(Self::Mesh4),
+            Self::Mesh5(mesh) => mesh.neighbor(dir).map(Self::Mesh5),
+            Self::Mesh6(mesh) => mesh.neighbor(dir).map(Self::Mesh6),
+        }
+    }
+
+    fn north_east_mesh(&self) -> Result<Self, GSJPError> {
+        match self {
+            Self::Mesh1(mesh) => mesh.north_east_mesh().map(Self::Mesh1),
+            Self::Mesh2(mesh) => mesh.north_east_mesh().map(Self::Mesh2),
+            Self::Mesh3(mesh) => mesh.north_east_mesh().map(Self::Mesh3),
+            Self::Mesh4(mesh) => mesh.north_east_mesh().map(Self::Mesh4),
+            Self::Mesh5(mesh) => mesh.north_east_mesh().map(Self::Mesh5),
+            Self::Mesh6(mesh) => mesh.north_east_mesh().map(Self::Mesh6),
+        }
+    }
+
+    fn south_east_mesh(&self) -> Result<Self, GSJPError> {
+        match self {
+            Self::Mesh1(mesh) => mesh.south_east_mesh().map(Self::Mesh1),
+            Self::Mesh2(mesh) => mesh.south_east_mesh().map(Self::Mesh2),
+            Self::Mesh3(mesh) => mesh.south_east_mesh().map(Self::Mesh3),
+            Self::Mesh4(mesh) => mesh.south_east_mesh().map(Self::Mesh4),
+            Self::Mesh5(mesh) => mesh.south_east_mesh().map(Self::Mesh5),
+            Self::Mesh6(mesh) => mesh.south_east_mesh().map(Self::Mesh6),
+        }
+    }
+
+    fn south_west_mesh(&self) -> Result<Self, GSJPError> {
+        match self {
+            Self::Mesh1(mesh) => mesh.south_west_mesh().map(Self::Mesh1),
+            Self::Mesh2(mesh) => mesh.south_west_mesh().map(Self::Mesh2),
+            Self::Mesh3(mesh) => mesh.south_west_mesh().map(Self::Mesh3),
+            Self::Mesh4(mesh) => mesh.south_west_mesh().map(Self::Mesh4),
+            Self::Mesh5(mesh) => mesh.south_west_mesh().map(Self::Mesh5),
+            Self::Mesh6(mesh) => mesh.south_west_mesh().map(Self::Mesh6),
+        }
+    }
+
+    fn north_west_mesh(&self) -> Result<Self, GSJPError> {
+        match self {
+            Self::Mesh1(mesh) => mesh.north_west_mesh().map(Self::Mesh1),
+            Self::Mesh2(mesh) => mesh.north_west_mesh().map(Self::Mesh2),
+            Self::Mesh3(mesh) => mesh.north_west_mesh().map(Self::Mesh3),
+            Self::Mesh4(mesh) => mesh.north_west_mesh().map(Self::Mesh4),
+            Self::Mesh5(mesh) => mesh.north_west_mesh().map(Self::Mesh5),
+            Self::Mesh6(mesh) => mesh.north_west_mesh().map(Self::Mesh6),
+        }
+    }
+
+    fn north_mesh_opt(&self) -> Option<Self> {
+        match self {
+            Self::Mesh1(mesh) => mesh.north_mesh_opt().map(Self::Mesh1),
+            Self::Mesh2(mesh) => mesh.north_mesh_opt().map(Self::Mesh2),
+            Self::Mesh3(mesh) => mesh.north_mesh_opt().map(Self::Mesh3),
+            Self::Mesh4(mesh) => mesh.north_mesh_opt().map(Self::Mesh4),
+            Self::Mesh5(mesh) => mesh.north_mesh_opt().map(Self::Mesh5),
+            Self::Mesh6(mesh) => mesh.north_mesh_opt().map(Self::Mesh6),
+        }
+    }
+
+    fn east_mesh_opt(&self) -> Option<Self> {
+        match self {
+            Self::Mesh1(mesh) => mesh.east_mesh_opt().map(Self::Mesh1),
+            Self::Mesh2(mesh) => mesh.east_mesh_opt().map(Self::Mesh2),
+            Self::Mesh3(mesh) => mesh.east_mesh_opt().map(Self::Mesh3),
+            Self::Mesh4(mesh) => mesh.east_mesh_opt().map(Self::Mesh4),
+            Self::Mesh5(mesh) => mesh.east_mesh_opt().map(Self::Mesh5),
+            Self::Mesh6(mesh) => mesh.east_mesh_opt().map(Self::Mesh6),
+        }
+    }
+
+    fn south_mesh_opt(&self) -> Option<Self> {
+        match self {
+            Self::Mesh1(mesh) => mesh.south_mesh_opt().map(Self::Mesh1),
+            Self::Mesh2(mesh) => mesh.south_mesh_opt().map(Self::Mesh2),
+            Self::Mesh3(mesh) => mesh.south_mesh_opt().map(Self::Mesh3),
+            Self::Mesh4(mesh) => mesh.south_mesh_opt().map(Self::Mesh4),
+            Self::Mesh5(mesh) => mesh.south_mesh_opt().map(Self::Mesh5),
+            Self::Mesh6(mesh) => mesh.south_mesh_opt().map(Self::Mesh6),
+        }
+    }
+
+    fn west_mesh_opt(&self) -> Option<Self> {
+        match self {
+            Self::Mesh1(mesh) => mesh.west_mesh_opt().map(Self::Mesh1),
+            Self::Mesh2(mesh) => mesh.west_mesh_opt().map(Self::Mesh2),
+            Self::Mesh3(mesh) => mesh.west_mesh_opt().map(Self::Mesh3),
+            Self::Mesh4(mesh) => mesh.west_mesh_opt().map(Self::Mesh4),
+            Self::Mesh5(mesh) => mesh.west_mesh_opt().map(Self::Mesh5),
+            Self::Mesh6(mesh) => mesh.west_mesh_opt().map(Self::Mesh6),
+        }
+    }
+
+    fn offset(&self, east: i64, north: i64) -> Result<Self, GSJPError> {
+        match self {
+            Self::Mesh1(mesh) => mesh.offset(east, north).map(Self::Mesh1),
+            Self::Mesh2(mesh) => mesh.offset(east, north).map(Self::Mesh2),
+            Self::Mesh3(mesh) => mesh.offset(east, north).map(Self::Mesh3),
+            Self::Mesh4(mesh) => mesh.offset(east, north).map(Self::Mesh4),
+            Self::Mesh5(mesh) => mesh.offset(east, north).map(Self::Mesh5),
+            Self::Mesh6(mesh) => mesh.offset(east, north).map(Self::Mesh6),
+        }
+    }
+
+    fn offset_m(&self, north_m: f64, east_m: f64) -> Result<Self, GSJPError> {
+        match self {
+            Self::Mesh1(mesh) => mesh.offset_m(north_m, east_m).map(Self::Mesh1),
+            Self::Mesh2(mesh) => mesh.offset_m(north_m, east_m).map(Self::Mesh2),
+            Self::Mesh3(mesh) => mesh.offset_m(north_m, east_m).map(Self::Mesh3),
+            Self::Mesh4(mesh) => mesh.offset_m(north_m, east_m).map(Self::Mesh4),
+            Self::Mesh5(mesh) => mesh.offset_m(north_m, east_m).map(Self::Mesh5),
+            Self::Mesh6(mesh) => mesh.offset_m(north_m, east_m).map(Self::Mesh6),
+        }
+    }
+
+    /// 移動した座標を含む、メッシュと同じレベルのメッシュを返す。
+    fn offset_m_with(
+        &self,
+        north_m: f64,
+        east_m: f64,
+        model: EarthModel,
+    ) -> Result<Self, GSJPError> {
+        match self {
+            Self::Mesh1(mesh) => mesh.offset_m_with(north_m, east_m, model).map(Self::Mesh1),
+            Self::Mesh2(mesh) => mesh.offset_m_with(north_m, east_m, model).map(Self::Mesh2),
+            Self::Mesh3(mesh) => mesh.offset_m_with(north_m, east_m, model).map(Self::Mesh3),
+            Self::Mesh4(mesh) => mesh.offset_m_with(north_m, east_m, model).map(Self::Mesh4),
+            Self::Mesh5(mesh) => mesh.offset_m_with(north_m, east_m, model).map(Self::Mesh5),
+            Self::Mesh6(mesh) => mesh.offset_m_with(north_m, east_m, model).map(Self::Mesh6),
+        }
+    }
+}
+
+impl From<Mesh1> for AnyMesh {
+    fn from(mesh: Mesh1) -> Self {
+        Self::Mesh1(mesh)
+    }
+}
+
+impl From<Mesh2> for AnyMesh {
+    fn from(mesh: Mesh2) -> Self {
+        Self::Mesh2(mesh)
+    }
+}
+
+impl From<Mesh3> for AnyMesh {
+    fn from(mesh: Mesh3) -> Self {
+        Self::Mesh3(mesh)
+    }
+}
+
+impl From<Mesh4> for AnyMesh {
+    fn from(mesh: Mesh4) -> Self {
+        Self::Mesh4(mesh)
+    }
+}
+
+impl From<Mesh5> for AnyMesh {
+    fn from(mesh: Mesh5) -> Self {
+        Self::Mesh5(mesh)
+    }
+}
+
+impl From<Mesh6> for AnyMesh {
+    fn from(mesh: Mesh6) -> Self {
+        Self::Mesh6(mesh)
+    }
+}
+
+/// メッシュの重複を取り除く。
+///
+/// 同じレベルで同じメッシュコードのメッシュを重複とみなし、最初に現れたメッシュを残す。
+/// 残したメッシュの順番は、引数の順番を維持する。
+///
+/// # 引数
+///
+/// * `meshes` - メッシュ
+///
+/// # 戻り値
+///
+/// 重複を取り除いたメッシュ
+pub fn mesh_dedup(meshes: Vec<AnyMesh>) -> Vec<AnyMesh> {
+    // メッシュを複製しないように、メッシュへの参照で最初に現れたメッシュか判定する
+    let mut seen = BTreeSet::new();
+    let first = meshes
+        .iter()
+        .map(|mesh| seen.insert(mesh))
+        .collect::<Vec<_>>();
+
+    meshes
+        .into_iter()
+        .zip(first)
+        .filter_map(|(mesh, first)| first.then_some(mesh))
+        .collect()
+}
+
+/// メッシュコードから、期待したレベルのメッシュを作成する。
+///
+/// メッシュコードが正しい形式であることを確認した後で、メッシュコードから判定したレベルが
+/// 期待したレベルと一致することを確認する。
+///
+/// # 引数
+///
+/// * `level` - 期待したメッシュのレベル
+/// * `code` - メッシュコード
+///
+/// # 戻り値
+///
+/// メッシュ。メッシュコードが不正な場合は`GSJPError::InvalidMeshCode`、
+/// レベルが一致しない場合は`GSJPError::MeshLevelMismatch`
+pub fn parse_expecting(level: MeshLevel, code: &str) -> Result<AnyMesh, GSJPError> {
+    let mesh = AnyMesh::new(code.to_string())?;
+    let actual = mesh.level();
+    if actual != level {
+        return Err(GSJPError::MeshLevelMismatch {
+            expected: level,
+            actual,
+        });
+    }
+
+    Ok(mesh)
+}
+
+/// メッシュのレベルの名前を前置したメッシュコードから、メッシュを作成する。
+///
+/// `Mesh3:53393599`のように、メッシュのレベルの名前とメッシュコードを`:`で連結した文字列を読み込み、
+/// メッシュコードから判定したレベルが、前置したレベルと一致することを確認する。
+///
+/// # 引数
+///
+/// * `tagged` - メッシュのレベルの名前を前置したメッシュコード
+///
+/// # 戻り値
+///
+/// メッシュ。`:`がない場合やメッシュコードが不正な場合は`GSJPError::InvalidMeshCode`、
+/// レベルの名前が不正な場合は`GSJPError::InvalidMeshLevel`、
+/// レベルが一致しない場合は`GSJPError::MeshLevelMismatch`
+pub fn parse_tagged(tagged: &str) -> Result<AnyMesh, GSJPError> {
+    let (name, code) = tagged.split_once(':').ok_or(GSJPError::InvalidMeshCode)?;
+    let level = MeshLevel::from_name(name).ok_or(GSJPError::InvalidMeshLevel)?;
+
+    parse_expecting(level, code)
+}
+
+/// 公的な文書で使用される、ハイフンで区切ったメッシュコードから、メッシュを作成する。
+///
+/// `5339-35-99-1-1`のように、第1次地域区画（4桁）、第2次地域区画（2桁）、基準地域メッシュ（2桁）、
+/// 分割地域メッシュの分割番号（1桁ずつ）をハイフンで区切った文字列を読み込み、
+/// 区切りの数からメッシュのレベルを判定する。
+///
+/// 市町村のデータセットで使用される、`53393599-1`のように基準地域メッシュのメッシュコード（8桁）に
+/// 分割番号をハイフンで連結した、分割地域メッシュの文字列も読み込める。
+///
+/// # 引数
+///
+/// * `s` - ハイフンで区切ったメッシュコード
+///
+/// # 戻り値
+///
+/// メッシュ。区切りの数や各部分の桁数が不正な場合や、メッシュコードが不正な場合は
+/// `GSJPError::InvalidMeshCode`
+pub fn from_official_display(s: &str) -> Result<AnyMesh, GSJPError> {
+    // 各レベルで追加されるメッシュコードの桁数
+    const PART_LENS: [usize; 6] = [4, 2, 2, 1, 1, 1];
+    // 基準地域メッシュのメッシュコードに分割番号を連結する場合の、各部分の桁数
+    const DIVISION_PART_LENS: [usize; 4] = [8, 1, 1, 1];
+
+    let parts = s.split('-').collect::<Vec<_>>();
+    let part_lens = if parts[0].len() == DIVISION_PART_LENS[0] && 1 < parts.len() {
+        &DIVISION_PART_LENS[..]
+    } else {
+        &PART_LENS[..]
+    };
+    if part_lens.len() < parts.len()
+        || parts
+            .iter()
+            .zip(part_lens)
+            .any(|(part, &len)| part.len() != len)
+    {
+        return Err(GSJPError::InvalidMeshCode);
+    }
+
+    // 各部分の桁数を確認したため、連結したメッシュコードの長さからレベルを判定できる
+    AnyMesh::new(parts.concat())
+}
+
+/// 複数のメッシュコードが、期待したレベルの正しいメッシュコードであることを検証する。
+///
+/// メッシュコードを先頭から順に検証して、最初に検証に失敗したメッシュコードの位置とエラーを返す。
+///
+/// # 引数
+///
+/// * `level` - 期待したメッシュのレベル
+/// * `codes` - メッシュコード
+///
+/// # 戻り値
+///
+/// `()`。検証に失敗した場合は、最初に検証に失敗したメッシュコードの位置とエラー
+pub fn validate_codes<S: AsRef<str>>(
+    level: MeshLevel,
+    codes: &[S],
+) -> Result<(), (usize, GSJPError)> {
+    for (index, code) in codes.iter().enumerate() {
+        parse_expecting(level, code.as_ref()).map_err(|e| (index, e))?;
+    }
+
+    Ok(())
+}
+
+/// メッシュコードから、指定されたレベルでメッシュを含むメッシュのメッシュコードを返す。
+///
+/// メッシュを作成せずに、メッシュコードを検証してから、上位のメッシュのメッシュコードの長さに切り詰める。
+/// 同じレベルを指定した場合は、メッシュコードをそのまま返す。
+///
+/// # 引数
+///
+/// * `code` - メッシュコード
+/// * `from` - メッシュコードのメッシュのレベル
+/// * `to` - 返すメッシュコードのメッシュのレベル
+///
+/// # 戻り値
+///
+/// メッシュを含むメッシュのメッシュコード。
+/// メッシュコードが`from`のレベルの正しいメッシュコードでない場合や、`to`に`from`より細かいレベルを
+/// 指定した場合はエラー
+pub fn parent_code(code: &str, from: MeshLevel, to: MeshLevel) -> Result<String, GSJPError> {
+    if from < to {
+        return Err(GSJPError::InvalidMeshLevel);
+    }
+    match from {
+        MeshLevel::Mesh1 => validate_mesh1_code(code)?,
+        MeshLevel::Mesh2 => validate_mesh2_code(code)?,
+        MeshLevel::Mesh3 => validate_mesh3_code(code)?,
+        MeshLevel::Mesh4 => validate_mesh4_code(code)?,
+        MeshLevel::Mesh5 => validate_mesh5_code(code)?,
+        MeshLevel::Mesh6 => validate_mesh6_code(code)?,
+    }
+
+    Ok(code[0..to.code_len()].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{HashMap, HashSet};
+    use std::hash::{Hash, Hasher};
+
+    use super::*;
+    use crate::mesh::tests::eq_f64;
+
+    #[test]
+    fn any_mesh_new_ok() {
+        let inputs = vec![
+            "5339",
+            "533935",
+            "53393599",
+            "533935991",
+            "5339359911",
+            "53393599111",
+        ];
+        for code in inputs {
+            let mesh = AnyMesh::new(String::from(code)).unwrap();
+            assert_eq!(code, mesh.code(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn any_mesh_code_formatted_ok() {
+        let inputs = vec![
+            ("5339", "5339"),
+            ("533935", "5339-35"),
+            ("53393599", "5339-35-99"),
+            ("533935993", "5339-35-99-3"),
+            ("5339359934", "5339-35-99-3-4"),
+            ("53393599342", "5339-35-99-3-4-2"),
+        ];
+        for (code, expected) in inputs {
+            let mesh = AnyMesh::new(String::from(code)).unwrap();
+            assert_eq!(expected, mesh.code_formatted(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn any_mesh_u64_ok() {
+        let codes = vec![
+            (3018_u64, "3018"),
+            (301800, "301800"),
+            (30180000, "30180000"),
+            (301800001, "301800001"),
+            (3018000011, "3018000011"),
+            (71497799444, "71497799444"),
+        ];
+        for (value, code) in codes {
+            let mesh = AnyMesh::new(code.to_string()).unwrap();
+            assert_eq!(value, mesh.as_u64(), "{}", code);
+            assert_eq!(mesh, AnyMesh::from_u64(value).unwrap(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn any_mesh_from_u64_err() {
+        let values = vec![0_u64, 18, 30180, 3018000, 301800000];
+        for value in values {
+            assert!(AnyMesh::from_u64(value).is_err(), "{}", value);
+        }
+    }
+
+    #[test]
+    fn any_mesh_new_err() {
+        let inputs = vec!["", "53393", "5339359", "533935990", "533935991111"];
+        for code in inputs {
+            assert!(AnyMesh::new(String::from(code)).is_err(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn any_mesh_hash_set_ok() {
+        let mut set = HashSet::new();
+        assert!(set.insert(AnyMesh::new(String::from("53393599")).unwrap()));
+        assert!(!set.insert(AnyMesh::new(String::from("53393599")).unwrap()));
+        assert_eq!(1, set.len());
+    }
+
+    #[test]
+    fn any_mesh_hash_set_levels_ok() {
+        // メッシュコードの先頭が一致する、レベルの異なるメッシュ
+        let meshes = vec![
+            AnyMesh::from(Mesh1::new(String::from("5339")).unwrap()),
+            AnyMesh::from(Mesh2::new(String::from("533935")).unwrap()),
+            AnyMesh::from(Mesh3::new(String::from("53393599")).unwrap()),
+            AnyMesh::from(Mesh4::new(String::from("533935991")).unwrap()),
+            AnyMesh::from(Mesh5::new(String::from("5339359911")).unwrap()),
+            AnyMesh::from(Mesh6::new(String::from("53393599111")).unwrap()),
+        ];
+        let set = meshes.iter().cloned().collect::<HashSet<_>>();
+        assert_eq!(meshes.len(), set.len());
+        for mesh in &meshes {
+            assert!(set.contains(mesh), "{:?}", mesh);
+        }
+    }
+
+    #[test]
+    fn any_mesh_hash_map_ok() {
+        // メッシュをキーとして、メッシュに関連付けた値を管理する
+        let codes = [
+            "5339",
+            "533935",
+            "53393599",
+            "533935991",
+            "5339359911",
+            "53393599111",
+        ];
+        let mut map = HashMap::new();
+        for (value, code) in codes.iter().enumerate() {
+            assert!(map
+                .insert(AnyMesh::new(code.to_string()).unwrap(), value)
+                .is_none());
+        }
+        assert_eq!(codes.len(), map.len());
+        // 新しく作成した等しいメッシュで値を参照できる
+        for (value, code) in codes.iter().enumerate() {
+            let key = AnyMesh::new(code.to_string()).unwrap();
+            assert_eq!(Some(&value), map.get(&key), "{}", code);
+        }
+        let key = AnyMesh::from(Mesh3::new(String::from("53393599")).unwrap());
+        *map.get_mut(&key).unwrap() += 10;
+        assert_eq!(Some(&12), map.get(&key));
+        let key = AnyMesh::new(String::from("53393598")).unwrap();
+        assert!(!map.contains_key(&key));
+    }
+
+    #[test]
+    fn any_mesh_hash_eq_consistent() {
+        fn hash(mesh: &AnyMesh) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            mesh.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let codes = vec!["5339", "533935", "53393599", "533935991", "5339359911"];
+        for code in codes {
+            let a = AnyMesh::new(code.to_string()).unwrap();
+            let b = AnyMesh::new(code.to_string()).unwrap();
+            let c = a.clone();
+            assert_eq!(a, b, "{}", code);
+            assert_eq!(hash(&a), hash(&b), "{}", code);
+            assert_eq!(hash(&a), hash(&c), "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh_dedup_ok() {
+        let codes = vec![
+            "53393599",
+            "533935991",
+            "53393599",
+            "53394509",
+            "533935991",
+            "5339",
+            "53394509",
+        ];
+        let meshes = codes
+            .into_iter()
+            .map(|code| AnyMesh::new(String::from(code)).unwrap())
+            .collect::<Vec<_>>();
+        let codes = mesh_dedup(meshes)
+            .iter()
+            .map(|mesh| mesh.code().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["53393599", "533935991", "53394509", "5339"], codes);
+    }
+
+    #[test]
+    fn parse_expecting_ok() {
+        let mesh = parse_expecting(MeshLevel::Mesh3, "53393599").unwrap();
+        assert!(matches!(mesh, AnyMesh::Mesh3(_)), "{:?}", mesh);
+        assert_eq!("53393599", mesh.code());
+    }
+
+    #[test]
+    fn parse_expecting_level_mismatch() {
+        let result = parse_expecting(MeshLevel::Mesh3, "533935991");
+        assert!(
+            matches!(
+                result,
+                Err(GSJPError::MeshLevelMismatch {
+                    expected: MeshLevel::Mesh3,
+                    actual: MeshLevel::Mesh4
+                })
+            ),
+            "{:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn parse_expecting_invalid_code() {
+        let codes = vec!["5339359", "53393a99", "533935995"];
+        for code in codes {
+            let result = parse_expecting(MeshLevel::Mesh3, code);
+            assert!(
+                matches!(result, Err(GSJPError::InvalidMeshCode)),
+                "{}: {:?}",
+                code,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn parse_tagged_round_trip() {
+        let codes = [
+            "5339",
+            "533935",
+            "53393599",
+            "533935991",
+            "5339359912",
+            "53393599123",
+        ];
+        for code in codes {
+            let mesh = AnyMesh::new(code.to_string()).unwrap();
+            let tagged = mesh.tagged_code();
+            assert_eq!(format!("{}:{}", mesh.level().name(), code), tagged);
+            assert_eq!(mesh, parse_tagged(&tagged).unwrap());
+        }
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        assert_eq!("Mesh3:53393599", mesh.tagged_code());
+        assert_eq!(
+            AnyMesh::Mesh3(mesh),
+            parse_tagged("Mesh3:53393599").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_tagged_err() {
+        let inputs = [
+            "53393599",
+            "Mesh3-53393599",
+            "Mesh3:5339359a",
+            "Mesh3:",
+            ":53393599",
+        ];
+        for input in inputs {
+            let result = parse_tagged(input);
+            assert!(
+                matches!(
+                    result,
+                    Err(GSJPError::InvalidMeshCode) | Err(GSJPError::InvalidMeshLevel)
+                ),
+                "{}: {:?}",
+                input,
+                result
+            );
+        }
+        for input in ["Mesh7:53393599", "mesh3:53393599", "Mesh2x:533935"] {
+            let result = parse_tagged(input);
+            assert!(
+                matches!(result, Err(GSJPError::InvalidMeshLevel)),
+                "{}: {:?}",
+                input,
+                result
+            );
+        }
+        let result = parse_tagged("Mesh4:53393599");
+        assert!(
+            matches!(
+                result,
+                Err(GSJPError::MeshLevelMismatch {
+                    expected: MeshLevel::Mesh4,
+                    actual: MeshLevel::Mesh3
+                })
+            ),
+            "{:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn from_official_display_round_trip() {
+        let inputs = [
+            ("5339-35-99", MeshLevel::Mesh3, "53393599"),
+            ("5339-35-99-1-1", MeshLevel::Mesh5, "5339359911"),
+            ("5339", MeshLevel::Mesh1, "5339"),
+            ("5339-35-99-4-3-2", MeshLevel::Mesh6, "53393599432"),
+        ];
+        for (display, level, code) in inputs {
+            let mesh = from_official_display(display).unwrap();
+            assert_eq!(level, mesh.level(), "{}", display);
+            assert_eq!(code, mesh.code(), "{}", display);
+            assert_eq!(display, mesh.to_official_display());
+        }
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        assert_eq!(
+            AnyMesh::from(mesh.clone()),
+            from_official_display(&mesh.to_official_display()).unwrap()
+        );
+        let mesh = Mesh5::new(String::from("5339359924")).unwrap();
+        assert_eq!("5339-35-99-2-4", mesh.to_official_display());
+        assert_eq!(
+            AnyMesh::from(mesh.clone()),
+            from_official_display(&mesh.to_official_display()).unwrap()
+        );
+    }
+
+    #[test]
+    fn from_official_display_division_ok() {
+        let inputs = [
+            ("53393599-1", MeshLevel::Mesh4, "533935991"),
+            ("53393599-1-2", MeshLevel::Mesh5, "5339359912"),
+            ("53393599-1-2-3", MeshLevel::Mesh6, "53393599123"),
+        ];
+        for (dashed, level, code) in inputs {
+            let mesh = from_official_display(dashed).unwrap();
+            assert_eq!(level, mesh.level(), "{}", dashed);
+            assert_eq!(code, mesh.code(), "{}", dashed);
+            // 区切り方が異なっても、同じメッシュになる
+            assert_eq!(
+                mesh,
+                from_official_display(&mesh.to_official_display()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn from_official_display_division_err() {
+        let inputs = [
+            "53393599-",
+            "53393599-12",
+            "53393599-1-2-3-4",
+            "53393599-5",
+            "5339359-1",
+            "533935991-1",
+            "53393599-1-35",
+        ];
+        for input in inputs {
+            let result = from_official_display(input);
+            assert!(
+                matches!(result, Err(GSJPError::InvalidMeshCode)),
+                "{}: {:?}",
+                input,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn from_official_display_err() {
+        let inputs = [
+            "",
+            "53393599",
+            "5339-3599",
+            "533-935-99",
+            "5339-35-99-11",
+            "5339-35-99-1-1-1-1",
+            "5339-35-99-",
+            "5339-35-9a",
+            "5339-35-99-5",
+        ];
+        for input in inputs {
+            let result = from_official_display(input);
+            assert!(
+                matches!(result, Err(GSJPError::InvalidMeshCode)),
+                "{}: {:?}",
+                input,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn validate_codes_ok() {
+        let codes = vec!["53393599", "53393690", "53394509"];
+        assert!(validate_codes(MeshLevel::Mesh3, &codes).is_ok());
+        let codes = codes.into_iter().map(String::from).collect::<Vec<_>>();
+        assert!(validate_codes(MeshLevel::Mesh3, &codes).is_ok());
+        assert!(validate_codes::<&str>(MeshLevel::Mesh3, &[]).is_ok());
+    }
+
+    #[test]
+    fn validate_codes_err() {
+        let codes = vec!["53393599", "53393690", "53393a99", "5339359"];
+        let result = validate_codes(MeshLevel::Mesh3, &codes);
+        assert!(
+            matches!(result, Err((2, GSJPError::InvalidMeshCode))),
+            "{:?}",
+            result
+        );
+        // レベルが一致しないメッシュコード
+        let codes = vec!["53393599", "533935"];
+        let result = validate_codes(MeshLevel::Mesh3, &codes);
+        assert!(
+            matches!(result, Err((1, GSJPError::MeshLevelMismatch { .. }))),
+            "{:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn any_mesh_north_ok() {
+        let mesh3 = Mesh3::new(String::from("53393599")).unwrap();
+        let mesh = AnyMesh::from(mesh3.clone());
+        assert!(
+            eq_f64(mesh3.north(), mesh.north()),
+            "expected: {}, actual: {}",
+            mesh3.north(),
+            mesh.north()
+        );
+    }
+
+    #[test]
+    fn any_mesh_north_mesh_ok() {
+        let mesh = AnyMesh::from(Mesh3::new(String::from("53393599")).unwrap());
+        let north_mesh = mesh.north_mesh().unwrap();
+        assert!(matches!(north_mesh, AnyMesh::Mesh3(_)), "{:?}", north_mesh);
+        assert_eq!("53394509", north_mesh.code());
+    }
+
+    #[test]
+    fn any_mesh_diagonal_mesh_ok() {
+        // 分割地域メッシュは、斜め隣のメッシュを分割番号から直接計算する
+        let mesh4 = Mesh4::new(String::from("533935994")).unwrap();
+        let mesh = AnyMesh::from(mesh4.clone());
+        assert_eq!(MeshLevel::Mesh4, mesh.level());
+        let inputs = vec![
+            (mesh.north_east_mesh(), mesh4.north_east_mesh()),
+            (mesh.south_east_mesh(), mesh4.south_east_mesh()),
+            (mesh.south_west_mesh(), mesh4.south_west_mesh()),
+            (mesh.north_west_mesh(), mesh4.north_west_mesh()),
+        ];
+        for (actual, expected) in inputs {
+            assert_eq!(AnyMesh::from(expected.unwrap()), actual.unwrap());
+        }
+        assert_eq!(
+            AnyMesh::from(mesh4.offset(2, -3).unwrap()),
+            mesh.offset(2, -3).unwrap()
+        );
+        assert_eq!(
+            Some(AnyMesh::from(mesh4.north_mesh().unwrap())),
+            mesh.north_mesh_opt()
+        );
+    }
+
+    #[test]
+    fn any_mesh_offset_m_keeps_level() {
+        let codes = [
+            "5339",
+            "533935",
+            "53393599",
+            "533935994",
+            "5339359943",
+            "53393599432",
+        ];
+        for code in codes {
+            let mesh = AnyMesh::new(code.to_string()).unwrap();
+            // 移動しない場合は、同じレベルの同じメッシュ
+            assert_eq!(mesh, mesh.offset_m(0.0, 0.0).unwrap(), "{}", code);
+            // メッシュの幅だけ東に移動すると、同じレベルの東隣のメッシュに移る
+            assert_eq!(
+                mesh.east_mesh().unwrap(),
+                mesh.offset_m(0.0, mesh.width_m()).unwrap(),
+                "{}",
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn parent_code_ok() {
+        let inputs = vec![
+            (MeshLevel::Mesh1, "5339"),
+            (MeshLevel::Mesh2, "533935"),
+            (MeshLevel::Mesh3, "53393599"),
+            (MeshLevel::Mesh4, "533935994"),
+            (MeshLevel::Mesh5, "5339359943"),
+            (MeshLevel::Mesh6, "53393599432"),
+        ];
+        for (to, expected) in inputs {
+            let code = parent_code("53393599432", MeshLevel::Mesh6, to).unwrap();
+            assert_eq!(expected, code, "{:?}", to);
+        }
+    }
+
+    #[test]
+    fn parent_code_err() {
+        // 細かいレベルを指定
+        assert!(matches!(
+            parent_code("53393599", MeshLevel::Mesh3, MeshLevel::Mesh4),
+            Err(GSJPError::InvalidMeshLevel)
+        ));
+        // メッシュコードとレベルが一致しない
+        assert!(matches!(
+            parent_code("533935994", MeshLevel::Mesh3, MeshLevel::Mesh2),
+            Err(GSJPError::InvalidMeshCode)
+        ));
+        // 不正なメッシュコード
+        assert!(matches!(
+            parent_code("53393599435", MeshLevel::Mesh6, MeshLevel::Mesh1),
+            Err(GSJPError::InvalidMeshCode)
+        ));
+    }
+}
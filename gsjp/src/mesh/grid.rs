@@ -0,0 +1,1307 @@
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::math;
+use crate::mesh::{
+    Coordinate, EarthModel, GSJPError, Mesh, Mesh6, MeshLevel, BOUNDARY_EPSILON, SOUTHERNMOST,
+    WESTERNMOST,
+};
+
+/// 全国の範囲で南北方向に並ぶ第1次地域区画の数
+const MESH1_ROWS: usize = 42;
+/// 全国の範囲で東西方向に並ぶ第1次地域区画の数
+const MESH1_COLS: usize = 32;
+
+/// 範囲内のメッシュを走査するイテレーター
+///
+/// 南西端の座標を含むメッシュから、西から東、南から北に向かってメッシュを返す。
+/// 次に返すメッシュの位置を保持するため、`skip`や`take`で走査を中断して、途中から再開できる。
+#[derive(Debug, Clone)]
+pub struct MeshGrid<M: Mesh> {
+    /// 最初に返すメッシュの中心の緯度（度単位）
+    lat: f64,
+    /// 最初に返すメッシュの中心の経度（度単位）
+    lon: f64,
+    /// メッシュの高さ（度単位）
+    height: f64,
+    /// メッシュの幅（度単位）
+    width: f64,
+    /// 南北方向のメッシュの数
+    rows: usize,
+    /// 東西方向のメッシュの数
+    cols: usize,
+    /// 次に返すメッシュの位置
+    index: usize,
+    _mesh: PhantomData<M>,
+}
+
+impl<M: Mesh> MeshGrid<M> {
+    /// 範囲内のメッシュを走査するイテレーターを作成する。
+    ///
+    /// 範囲と重なるメッシュを返す。
+    /// ただし、北端と東端の座標の位置から始まるメッシュと、全国の範囲を超えるメッシュは返さない。
+    ///
+    /// # 引数
+    ///
+    /// * `south_west` - 範囲の南西端の座標
+    /// * `north_east` - 範囲の北東端の座標
+    ///
+    /// # 戻り値
+    ///
+    /// 範囲内のメッシュを走査するイテレーター
+    pub fn new(south_west: Coordinate, north_east: Coordinate) -> Result<Self, GSJPError> {
+        let first = M::from_coordinate(south_west)?;
+        let height = first.north() - first.south();
+        let width = first.east() - first.west();
+        let center = first.center();
+        let (_, (rows, cols)) = grid_cells(&first, &north_east);
+
+        Ok(Self {
+            lat: center.lat(),
+            lon: center.lon(),
+            height,
+            width,
+            rows,
+            cols,
+            index: 0,
+            _mesh: PhantomData,
+        })
+    }
+
+    /// メッシュを返さないイテレーターを作成する。
+    fn empty() -> Self {
+        Self {
+            lat: 0.0,
+            lon: 0.0,
+            height: 0.0,
+            width: 0.0,
+            rows: 0,
+            cols: 0,
+            index: 0,
+            _mesh: PhantomData,
+        }
+    }
+
+    /// 次に返すメッシュの中心の座標を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 次に返すメッシュの中心の座標、すべてのメッシュを返した場合は`None`
+    pub fn next_center(&self) -> Option<Coordinate> {
+        if self.rows * self.cols <= self.index {
+            return None;
+        }
+
+        self.center_at(self.index / self.cols, self.index % self.cols)
+    }
+
+    /// 指定された位置のメッシュの中心の座標を返す。
+    fn center_at(&self, row: usize, col: usize) -> Option<Coordinate> {
+        Coordinate::new(
+            self.lat + self.height * row as f64,
+            self.lon + self.width * col as f64,
+        )
+        .ok()
+    }
+}
+
+/// 範囲と重なるメッシュを返す。
+///
+/// メッシュは、西から東、南から北に向かう順番で格納する。
+///
+/// # 引数
+///
+/// * `south_west` - 範囲の南西端の座標
+/// * `north_east` - 範囲の北東端の座標
+///
+/// # 戻り値
+///
+/// 範囲と重なるメッシュ
+pub fn meshes_in_bbox<M: Mesh>(
+    south_west: Coordinate,
+    north_east: Coordinate,
+) -> Result<Vec<M>, GSJPError> {
+    Ok(MeshGrid::new(south_west, north_east)?.collect())
+}
+
+/// 座標から指定された距離以内に中心があるメッシュを返す。
+///
+/// メッシュの一部が円と重なるかではなく、メッシュの中心が円の内部（円周上を含む）にあるかで判定する。
+/// このため、メッシュの一部が円と重なっていても、メッシュの中心が円の外部にある場合は含めない。
+/// 距離は、地球を半径`EARTH_RADIUS`の球とみなした大円距離である。
+///
+/// 円を囲む範囲と重なるメッシュを候補として、候補のメッシュの中心までの距離で絞り込む。
+/// メッシュは、西から東、南から北に向かう順番で格納する。
+///
+/// # 引数
+///
+/// * `center` - 円の中心の座標
+/// * `radius_m` - 円の半径（m単位）
+///
+/// # 戻り値
+///
+/// 座標から指定された距離以内に中心があるメッシュ。半径が負の場合、または円を囲む範囲が
+/// 標準地域メッシュの範囲外の場合はエラー
+pub fn meshes_within_distance<M: Mesh>(
+    center: Coordinate,
+    radius_m: f64,
+) -> Result<Vec<M>, GSJPError> {
+    if radius_m < 0.0 {
+        return Err(GSJPError::OutOfRange(
+            format!("半径が負です({})。", radius_m).into(),
+        ));
+    }
+
+    let model = EarthModel::default();
+    let lat_diff = (radius_m / model.radius()).to_degrees();
+    // 円を囲む範囲の東西方向の幅は、赤道から遠い側の緯度で求める
+    let far_lat = (center.lat().abs() + lat_diff).min(89.0).to_radians();
+    let lon_diff = lat_diff / math::cos(far_lat);
+    let south_west = Coordinate::new(center.lat() - lat_diff, center.lon() - lon_diff)?;
+    let north_east = Coordinate::new(center.lat() + lat_diff, center.lon() + lon_diff)?;
+    let meshes = meshes_in_bbox::<M>(south_west, north_east)?
+        .into_iter()
+        .filter(|mesh| mesh.center().distance_m(&center, model) <= radius_m)
+        .collect();
+
+    Ok(meshes)
+}
+
+/// 範囲を、範囲と重なるメッシュの境界に合わせて拡げた範囲を返す。
+///
+/// `MeshGrid`と同様に、北端と東端の座標の位置から始まるメッシュは範囲と重ならないとみなす。
+/// このため、メッシュの境界上にある北端と東端は拡げない。
+/// 範囲の面積が0の場合は、南西端の座標を含むメッシュの範囲を返す。
+///
+/// # 引数
+///
+/// * `south` - 範囲の南端の緯度（度単位）
+/// * `west` - 範囲の西端の経度（度単位）
+/// * `north` - 範囲の北端の緯度（度単位）
+/// * `east` - 範囲の東端の経度（度単位）
+///
+/// # 戻り値
+///
+/// 拡げた範囲の南西端の座標と北東端の座標。
+/// 範囲の南端が北端より北にある場合、西端が東端より東にある場合、または範囲が標準地域メッシュの範囲外の場合はエラー
+pub fn snap_bbox_to_grid<M: Mesh>(
+    south: f64,
+    west: f64,
+    north: f64,
+    east: f64,
+) -> Result<(Coordinate, Coordinate), GSJPError> {
+    if north < south {
+        return Err(GSJPError::OutOfRange(
+            format!("範囲の南端({})が北端({})より北にあります。", south, north).into(),
+        ));
+    }
+    if east < west {
+        return Err(GSJPError::OutOfRange(
+            format!("範囲の西端({})が東端({})より東にあります。", west, east).into(),
+        ));
+    }
+
+    let first = M::from_coordinate(Coordinate::new(south, west)?)?;
+    let height = first.north() - first.south();
+    let width = first.east() - first.west();
+    let rows = count(first.south(), north, height).max(1);
+    let cols = count(first.west(), east, width).max(1);
+    // 北東端のメッシュの中心から、北東端のメッシュを決定する
+    let center = first.center();
+    let last = M::from_coordinate(Coordinate::new(
+        center.lat() + height * (rows - 1) as f64,
+        center.lon() + width * (cols - 1) as f64,
+    )?)?;
+
+    Ok((first.south_west(), last.north_east()))
+}
+
+/// 順序集合に含まれるメッシュのうち、南西端のメッシュと北東端のメッシュを角とする範囲に含まれるメッシュを返す。
+///
+/// 同じレベルのメッシュは、メッシュコードの順番で並ぶ。
+/// メッシュコードは上位のメッシュから順に南北方向と東西方向の位置を表すため、範囲に含まれるメッシュの
+/// メッシュコードは、南西端のメッシュのメッシュコードと北東端のメッシュのメッシュコードの間にある。
+/// このため、メッシュコードの順番で2つのメッシュの間にあるメッシュを走査して、範囲に含まれるメッシュに絞り込む。
+///
+/// 南西端のメッシュが北東端のメッシュより北または東にある場合は、空のベクターを返す。
+///
+/// # 引数
+///
+/// * `set` - メッシュの順序集合
+/// * `sw` - 範囲の南西端のメッシュ
+/// * `ne` - 範囲の北東端のメッシュ
+///
+/// # 戻り値
+///
+/// 範囲に含まれるメッシュ。メッシュコードの順番で格納する
+pub fn range_query<M: Mesh + Ord + Clone>(set: &BTreeSet<M>, sw: &M, ne: &M) -> Vec<M> {
+    if ne < sw {
+        return vec![];
+    }
+
+    set.range(sw..=ne)
+        .filter(|mesh| {
+            sw.south() - BOUNDARY_EPSILON <= mesh.south()
+                && sw.west() - BOUNDARY_EPSILON <= mesh.west()
+                && mesh.north() <= ne.north() + BOUNDARY_EPSILON
+                && mesh.east() <= ne.east() + BOUNDARY_EPSILON
+        })
+        .cloned()
+        .collect()
+}
+
+/// 範囲と重なるメッシュを、南北方向の行ごとに並列に作成して返す。
+///
+/// メッシュの順番は、スレッドの数に関わらず`meshes_in_bbox`と同じである。
+///
+/// # 引数
+///
+/// * `south_west` - 範囲の南西端の座標
+/// * `north_east` - 範囲の北東端の座標
+///
+/// # 戻り値
+///
+/// 範囲と重なるメッシュ
+#[cfg(feature = "rayon")]
+pub fn par_meshes_in_bbox<M: Mesh + Send + Sync>(
+    south_west: Coordinate,
+    north_east: Coordinate,
+) -> Result<Vec<M>, GSJPError> {
+    use rayon::prelude::*;
+
+    let grid = MeshGrid::<M>::new(south_west, north_east)?;
+    let grid = &grid;
+
+    Ok((0..grid.rows)
+        .into_par_iter()
+        .flat_map_iter(|row| {
+            (0..grid.cols).filter_map(move |col| M::from_coordinate(grid.center_at(row, col)?).ok())
+        })
+        .collect())
+}
+
+impl<M: Mesh> Iterator for MeshGrid<M> {
+    type Item = M;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let coord = self.next_center()?;
+        self.index += 1;
+
+        M::from_coordinate(coord).ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.rows * self.cols).saturating_sub(self.index);
+
+        (remaining, Some(remaining))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.index = self.index.saturating_add(n);
+
+        self.next()
+    }
+}
+
+impl<M: Mesh> ExactSizeIterator for MeshGrid<M> {}
+
+/// 2つの座標を結ぶ線分が通過するメッシュを返す。
+///
+/// 始点を含むメッシュから終点を含むメッシュまで、線分が通過する順番でメッシュを格納する。
+/// 線分がメッシュの頂点を通過する場合は、頂点を共有する斜め方向のメッシュに移動し、
+/// 頂点で接する東西または南北のメッシュは含めない。
+///
+/// # 引数
+///
+/// * `a` - 線分の始点の座標
+/// * `b` - 線分の終点の座標
+///
+/// # 戻り値
+///
+/// 線分が通過するメッシュ
+pub fn meshes_along_line<M: Mesh>(a: Coordinate, b: Coordinate) -> Result<Vec<M>, GSJPError> {
+    // 線分上の位置（始点が0、終点が1）を比較するときの許容誤差
+    const T_EPSILON: f64 = 1e-9;
+
+    let end = M::from_coordinate(b)?;
+    let mut mesh = M::from_coordinate(a)?;
+    let d_lat = b.lat() - a.lat();
+    let d_lon = b.lon() - a.lon();
+    let mut meshes = vec![];
+    loop {
+        if mesh.code() == end.code() {
+            meshes.push(mesh);
+            break;
+        }
+        // 線分がメッシュの東西の辺と南北の辺を通過する線分上の位置
+        let t_lon = if 0.0 < d_lon {
+            (mesh.east() - a.lon()) / d_lon
+        } else if d_lon < 0.0 {
+            (mesh.west() - a.lon()) / d_lon
+        } else {
+            f64::INFINITY
+        };
+        let t_lat = if 0.0 < d_lat {
+            (mesh.north() - a.lat()) / d_lat
+        } else if d_lat < 0.0 {
+            (mesh.south() - a.lat()) / d_lat
+        } else {
+            f64::INFINITY
+        };
+        if 1.0 + T_EPSILON < t_lon.min(t_lat) {
+            // 浮動小数点数の誤差で終点を含むメッシュに到達しなかった場合
+            meshes.push(mesh);
+            break;
+        }
+        let next = if (t_lon - t_lat).abs() < T_EPSILON {
+            match (0.0 < d_lat, 0.0 < d_lon) {
+                (true, true) => mesh.north_east_mesh()?,
+                (true, false) => mesh.north_west_mesh()?,
+                (false, true) => mesh.south_east_mesh()?,
+                (false, false) => mesh.south_west_mesh()?,
+            }
+        } else if t_lon < t_lat {
+            if 0.0 < d_lon {
+                mesh.east_mesh()?
+            } else {
+                mesh.west_mesh()?
+            }
+        } else if 0.0 < d_lat {
+            mesh.north_mesh()?
+        } else {
+            mesh.south_mesh()?
+        };
+        meshes.push(mesh);
+        mesh = next;
+    }
+
+    Ok(meshes)
+}
+
+/// 矩形に並ぶメッシュの範囲
+///
+/// 範囲の南西端のメッシュと北東端のメッシュで範囲を表現し、両端のメッシュを範囲に含める。
+/// 範囲にメッシュを含まない空の範囲も表現できる。
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeshRange<M: Mesh> {
+    /// 範囲の南西端のメッシュと北東端のメッシュ、空の範囲の場合は`None`
+    corners: Option<(M, M)>,
+}
+
+impl<M: Mesh> MeshRange<M> {
+    /// 南西端のメッシュと北東端のメッシュから範囲を作成する。
+    ///
+    /// 北東端のメッシュが南西端のメッシュより南または西にある場合は、空の範囲を作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `south_west` - 範囲の南西端のメッシュ
+    /// * `north_east` - 範囲の北東端のメッシュ
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュの範囲。2つのメッシュのレベルが異なる場合はエラー
+    pub fn new(south_west: M, north_east: M) -> Result<Self, GSJPError> {
+        if south_west.level() != north_east.level() {
+            return Err(GSJPError::MeshLevelMismatch {
+                expected: south_west.level(),
+                actual: north_east.level(),
+            });
+        }
+        if north_east.south() < south_west.south() - BOUNDARY_EPSILON
+            || north_east.west() < south_west.west() - BOUNDARY_EPSILON
+        {
+            return Ok(Self::empty());
+        }
+
+        Ok(Self {
+            corners: Some((south_west, north_east)),
+        })
+    }
+
+    /// 空の範囲を作成する。
+    ///
+    /// # 戻り値
+    ///
+    /// 空の範囲
+    pub fn empty() -> Self {
+        Self { corners: None }
+    }
+
+    /// 範囲の南西端のメッシュを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 範囲の南西端のメッシュ、空の範囲の場合は`None`
+    pub fn south_west(&self) -> Option<&M> {
+        self.corners.as_ref().map(|(south_west, _)| south_west)
+    }
+
+    /// 範囲の北東端のメッシュを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 範囲の北東端のメッシュ、空の範囲の場合は`None`
+    pub fn north_east(&self) -> Option<&M> {
+        self.corners.as_ref().map(|(_, north_east)| north_east)
+    }
+
+    /// 範囲が空であるか確認する。
+    ///
+    /// # 戻り値
+    ///
+    /// 範囲が空の場合は`true`、空でない場合は`false`
+    pub fn is_empty(&self) -> bool {
+        self.corners.is_none()
+    }
+
+    /// 範囲に含まれるメッシュの数を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 範囲に含まれるメッシュの数
+    pub fn len(&self) -> usize {
+        self.iter().len()
+    }
+
+    /// メッシュが範囲に含まれるか確認する。
+    ///
+    /// # 引数
+    ///
+    /// * `mesh` - メッシュ
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュが範囲に含まれる場合は`true`、含まれない場合は`false`
+    pub fn contains(&self, mesh: &M) -> bool {
+        let Some((south_west, north_east)) = &self.corners else {
+            return false;
+        };
+
+        mesh.level() == south_west.level()
+            && south_west.south() - BOUNDARY_EPSILON <= mesh.south()
+            && mesh.south() <= north_east.south() + BOUNDARY_EPSILON
+            && south_west.west() - BOUNDARY_EPSILON <= mesh.west()
+            && mesh.west() <= north_east.west() + BOUNDARY_EPSILON
+    }
+
+    /// 2つの範囲の両方に含まれるメッシュの範囲を返す。
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 別の範囲
+    ///
+    /// # 戻り値
+    ///
+    /// 2つの範囲の両方に含まれるメッシュの範囲。2つの範囲が重ならない場合は空の範囲
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (Some((sw1, ne1)), Some((sw2, ne2))) = (&self.corners, &other.corners) else {
+            return Self::empty();
+        };
+        if sw1.level() != sw2.level() {
+            return Self::empty();
+        }
+        // 南西端のメッシュは南端と西端が大きい方、北東端のメッシュは南端と西端が小さい方を選択して、
+        // それぞれのメッシュの中心を含むメッシュを作成する
+        let half_height = (sw1.north() - sw1.south()) / 2.0;
+        let half_width = (sw1.east() - sw1.west()) / 2.0;
+        let south_west = M::from_coordinate(Coordinate::new_unchecked(
+            sw1.south().max(sw2.south()) + half_height,
+            sw1.west().max(sw2.west()) + half_width,
+        ));
+        let north_east = M::from_coordinate(Coordinate::new_unchecked(
+            ne1.south().min(ne2.south()) + half_height,
+            ne1.west().min(ne2.west()) + half_width,
+        ));
+
+        match (south_west, north_east) {
+            (Ok(south_west), Ok(north_east)) => {
+                Self::new(south_west, north_east).unwrap_or_else(|_| Self::empty())
+            }
+            _ => Self::empty(),
+        }
+    }
+
+    /// 範囲に含まれるメッシュを走査するイテレーターを返す。
+    ///
+    /// メッシュは、西から東、南から北に向かう順番で返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 範囲に含まれるメッシュを走査するイテレーター
+    pub fn iter(&self) -> MeshGrid<M> {
+        match &self.corners {
+            // 範囲の両端のメッシュの中心は、標準地域メッシュの範囲内にある
+            Some((south_west, north_east)) => {
+                MeshGrid::new(south_west.center(), north_east.center())
+                    .unwrap_or_else(|_| MeshGrid::empty())
+            }
+            None => MeshGrid::empty(),
+        }
+    }
+}
+
+/// ポリゴンの辺が通過するメッシュを返す。
+///
+/// ポリゴンの各辺が通過するメッシュを`meshes_along_line`で求めて、重複を取り除いて返す。
+/// ポリゴンの内部にあり、辺が通過しないメッシュは含めない。
+/// 最初の頂点と最後の頂点が異なる場合は、最後の頂点と最初の頂点を結ぶ辺も走査する。
+///
+/// メッシュは、南端の緯度が小さい順、南端の緯度が同じ場合は西端の経度が小さい順に格納する。
+///
+/// # 引数
+///
+/// * `ring` - ポリゴンの頂点の座標
+///
+/// # 戻り値
+///
+/// ポリゴンの辺が通過するメッシュ
+pub fn meshes_crossing_polygon<M: Mesh>(ring: &[Coordinate]) -> Result<Vec<M>, GSJPError> {
+    let (Some(first), Some(last)) = (ring.first(), ring.last()) else {
+        return Ok(vec![]);
+    };
+
+    let mut codes = BTreeSet::new();
+    let mut meshes = vec![];
+    let closing = (first != last).then_some([*last, *first]);
+    let edges = ring
+        .windows(2)
+        .chain(closing.as_ref().map(|edge| &edge[..]));
+    for edge in edges {
+        for mesh in meshes_along_line::<M>(edge[0], edge[1])? {
+            if codes.insert(mesh.code().to_string()) {
+                meshes.push(mesh);
+            }
+        }
+    }
+    // 頂点が1つの場合は、頂点を含むメッシュを返す
+    if meshes.is_empty() {
+        meshes.push(M::from_coordinate(*first)?);
+    }
+    meshes.sort_by(|a, b| {
+        a.south()
+            .total_cmp(&b.south())
+            .then(a.west().total_cmp(&b.west()))
+    });
+
+    Ok(meshes)
+}
+
+/// 範囲内のメッシュのメッシュコードを、メッシュを作成せずに走査する構造体
+///
+/// `MeshGrid`はメッシュごとにメッシュコードの`String`を作成するが、この構造体は
+/// 内部のバッファにメッシュコードを書き込んで、バッファへの参照を返す。
+/// このため、全国の範囲のように多数のメッシュを走査する場合でも、メモリの割り当てはバッファの1回のみである。
+///
+/// 返すメッシュコードは内部のバッファを参照するため、次のメッシュコードを取得するまでしか使用できない。
+/// この制約のため`Iterator`を実装せず、`next_code`メソッドでメッシュコードを取得する。
+/// メッシュコードを保持する場合は、`to_string`などで複製すること。
+///
+/// メッシュコードを返す順番は`MeshGrid`と同じである。
+#[derive(Debug, Clone)]
+pub struct CodeGrid {
+    /// メッシュのレベル
+    level: MeshLevel,
+    /// 最初に返すメッシュの、全国の範囲の南端から数えた位置
+    row: usize,
+    /// 最初に返すメッシュの、全国の範囲の西端から数えた位置
+    col: usize,
+    /// 南北方向のメッシュの数
+    rows: usize,
+    /// 東西方向のメッシュの数
+    cols: usize,
+    /// 次に返すメッシュの位置
+    index: usize,
+    /// メッシュコードを書き込むバッファ
+    buf: String,
+}
+
+impl CodeGrid {
+    /// 範囲内のメッシュのメッシュコードを走査する構造体を作成する。
+    ///
+    /// 返すメッシュコードの範囲は`MeshGrid::new`と同じである。
+    ///
+    /// # 引数
+    ///
+    /// * `level` - メッシュのレベル
+    /// * `south_west` - 範囲の南西端の座標
+    /// * `north_east` - 範囲の北東端の座標
+    ///
+    /// # 戻り値
+    ///
+    /// 範囲内のメッシュのメッシュコードを走査する構造体
+    pub fn new(
+        level: MeshLevel,
+        south_west: Coordinate,
+        north_east: Coordinate,
+    ) -> Result<Self, GSJPError> {
+        let first = Mesh6::from_coordinate(south_west)?.to_level(level)?;
+        let ((row, col), (rows, cols)) = grid_cells(&first, &north_east);
+
+        Ok(Self {
+            level,
+            row,
+            col,
+            rows,
+            cols,
+            index: 0,
+            buf: String::with_capacity(level.code_len()),
+        })
+    }
+
+    /// 次のメッシュコードを返す。
+    ///
+    /// 返すメッシュコードは内部のバッファを参照するため、次にこのメソッドを呼び出すまで有効である。
+    ///
+    /// # 戻り値
+    ///
+    /// 次のメッシュコード、すべてのメッシュコードを返した場合は`None`
+    pub fn next_code(&mut self) -> Option<&str> {
+        if self.rows * self.cols <= self.index {
+            return None;
+        }
+        let row = self.row + self.index / self.cols;
+        let col = self.col + self.index % self.cols;
+        self.index += 1;
+        write_code(self.level, row, col, &mut self.buf);
+
+        Some(&self.buf)
+    }
+
+    /// 残りのメッシュコードの数を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 残りのメッシュコードの数
+    pub fn remaining(&self) -> usize {
+        (self.rows * self.cols).saturating_sub(self.index)
+    }
+}
+
+/// 全国の範囲を覆う、指定されたレベルのメッシュの数を返す。
+///
+/// 全国の範囲（北緯20度から48度、東経118度から150度）の矩形に含まれるメッシュの数であり、
+/// 陸地を含むメッシュの数ではない。
+///
+/// # 引数
+///
+/// * `level` - メッシュのレベル
+///
+/// # 戻り値
+///
+/// 全国の範囲を覆うメッシュの数
+pub fn total_mesh_count(level: MeshLevel) -> u64 {
+    let per_mesh1 = cells_per_mesh1(level) as u64;
+
+    (MESH1_ROWS * MESH1_COLS) as u64 * per_mesh1 * per_mesh1
+}
+
+/// 第1次地域区画の南北方向（東西方向）に並ぶ、指定されたレベルのメッシュの数を返す。
+fn cells_per_mesh1(level: MeshLevel) -> usize {
+    match level {
+        MeshLevel::Mesh1 => 1,
+        MeshLevel::Mesh2 => 8,
+        MeshLevel::Mesh3 => 80,
+        MeshLevel::Mesh4 => 160,
+        MeshLevel::Mesh5 => 320,
+        MeshLevel::Mesh6 => 640,
+    }
+}
+
+/// 全国の範囲の南端と西端から数えたメッシュの位置から、メッシュコードをバッファに書き込む。
+///
+/// # 引数
+///
+/// * `level` - メッシュのレベル
+/// * `row` - 全国の範囲の南端から数えたメッシュの位置
+/// * `col` - 全国の範囲の西端から数えたメッシュの位置
+/// * `buf` - メッシュコードを書き込むバッファ
+fn write_code(level: MeshLevel, row: usize, col: usize, buf: &mut String) {
+    // 指定されたレベルのメッシュの位置を、上位のレベルのメッシュの位置に変換する
+    let per_mesh1 = cells_per_mesh1(level);
+    let at = |cells: usize| (row / (per_mesh1 / cells), col / (per_mesh1 / cells));
+    buf.clear();
+    let mut push_digit = |d: usize| buf.push(char::from(b'0' + d as u8));
+    let (lat, lon) = at(1);
+    push_digit((30 + lat) / 10);
+    push_digit((30 + lat) % 10);
+    push_digit((18 + lon) / 10);
+    push_digit((18 + lon) % 10);
+    if MeshLevel::Mesh2 <= level {
+        let (lat, lon) = at(8);
+        push_digit(lat % 8);
+        push_digit(lon % 8);
+    }
+    if MeshLevel::Mesh3 <= level {
+        let (lat, lon) = at(80);
+        push_digit(lat % 10);
+        push_digit(lon % 10);
+    }
+    // 分割地域メッシュの分割番号は、南西が1、南東が2、北西が3、北東が4
+    for (division, cells) in [
+        (MeshLevel::Mesh4, 160),
+        (MeshLevel::Mesh5, 320),
+        (MeshLevel::Mesh6, 640),
+    ] {
+        if division <= level {
+            let (lat, lon) = at(cells);
+            push_digit(2 * (lat % 2) + lon % 2 + 1);
+        }
+    }
+}
+
+/// 範囲の南西端の座標を含むメッシュから、範囲と重なるメッシュの位置と数を返す。
+///
+/// 全国の範囲を超えるメッシュを含めないように、メッシュの数を全国の範囲の北端と東端までに制限する。
+///
+/// # 引数
+///
+/// * `first` - 範囲の南西端の座標を含むメッシュ
+/// * `north_east` - 範囲の北東端の座標
+///
+/// # 戻り値
+///
+/// 南西端のメッシュの全国の範囲の南端と西端から数えた位置と、南北方向と東西方向のメッシュの数
+fn grid_cells<M: Mesh>(first: &M, north_east: &Coordinate) -> ((usize, usize), (usize, usize)) {
+    let height = first.north() - first.south();
+    let width = first.east() - first.west();
+    let row = math::round((first.south() - SOUTHERNMOST) / height) as usize;
+    let col = math::round((first.west() - WESTERNMOST) / width) as usize;
+    let per_mesh1 = cells_per_mesh1(first.level());
+    let rows = count(first.south(), north_east.lat(), height).min(MESH1_ROWS * per_mesh1 - row);
+    let cols = count(first.west(), north_east.lon(), width).min(MESH1_COLS * per_mesh1 - col);
+
+    ((row, col), (rows, cols))
+}
+
+/// 始点から終点までに並ぶメッシュの数を返す。
+///
+/// 浮動小数点数の誤差で、終点から始まるメッシュを数えないように、わずかに小さく切り上げる。
+fn count(start: f64, end: f64, size: f64) -> usize {
+    let n = math::ceil((end - start) / size - 1e-9);
+    if n <= 0.0 {
+        return 0;
+    }
+
+    n as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::tests::eq_f64;
+    use crate::mesh::{AnyMesh, Mesh1, Mesh2, Mesh3, Mesh4, Mesh5};
+
+    /// メッシュの中心の座標を走査して、メッシュを作成する。
+    fn walk_centers<M: Mesh>(width: f64, height: f64) -> Vec<M> {
+        let mut meshes = vec![];
+        let mut lat = 20.0 + height / 2.0;
+        while lat < 48.0 {
+            let mut lon = 118.0 + width / 2.0;
+            while lon < 150.0 {
+                meshes.push(M::from_coordinate(Coordinate::new(lat, lon).unwrap()).unwrap());
+                lon += width;
+            }
+            lat += height;
+        }
+
+        meshes
+    }
+
+    fn grid<M: Mesh>() -> MeshGrid<M> {
+        MeshGrid::new(
+            Coordinate::new(20.0, 118.0).unwrap(),
+            Coordinate::new(48.0, 150.0).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn mesh_grid_mesh1_ok() {
+        let expected = walk_centers::<Mesh1>(1.0, 40.0 / 60.0);
+        let actual = grid::<Mesh1>().collect::<Vec<_>>();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn mesh_grid_mesh2_ok() {
+        let expected = walk_centers::<Mesh2>(7.0 / 60.0 + 30.0 / 3600.0, 5.0 / 60.0);
+        let actual = grid::<Mesh2>().collect::<Vec<_>>();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn mesh_grid_bbox_ok() {
+        // 53393599から53394600までの範囲
+        let grid = MeshGrid::<Mesh3>::new(
+            Coordinate::new(35.665, 139.74).unwrap(),
+            Coordinate::new(35.675, 139.76).unwrap(),
+        )
+        .unwrap();
+        let codes = grid.map(|mesh| mesh.code().to_string()).collect::<Vec<_>>();
+        assert_eq!(vec!["53393599", "53393690", "53394509", "53394600"], codes);
+    }
+
+    #[test]
+    fn mesh_grid_resume_ok() {
+        let expected = grid::<Mesh1>().collect::<Vec<_>>();
+        let mut grid = grid::<Mesh1>();
+        let mut actual = grid.by_ref().take(10).collect::<Vec<_>>();
+        actual.extend(grid);
+        assert_eq!(expected, actual);
+
+        let skipped = self::grid::<Mesh1>().skip(35).collect::<Vec<_>>();
+        assert_eq!(expected[35..], skipped[..]);
+    }
+
+    #[test]
+    fn mesh_grid_size_hint_ok() {
+        let mut grid = grid::<Mesh1>();
+        assert_eq!((42 * 32, Some(42 * 32)), grid.size_hint());
+        grid.by_ref().take(10).for_each(drop);
+        assert_eq!(42 * 32 - 10, grid.len());
+        assert_eq!(42 * 32 - 10, grid.count());
+    }
+
+    #[test]
+    fn mesh_grid_national_edge_ok() {
+        // 北東端が全国の範囲を超える範囲
+        let sw = Coordinate::new(47.99, 149.99).unwrap();
+        let ne = Coordinate::new(48.9, 150.9).unwrap();
+        let grid = MeshGrid::<Mesh3>::new(sw, ne).unwrap();
+        assert_eq!(grid.len(), grid.clone().count());
+        let codes = grid.map(|mesh| mesh.code().to_string()).collect::<Vec<_>>();
+        assert_eq!(vec!["71497789", "71497799"], codes);
+
+        let grid = MeshGrid::<Mesh1>::new(Coordinate::new(47.5, 139.5).unwrap(), ne).unwrap();
+        assert_eq!(grid.len(), grid.clone().count());
+        assert_eq!(11, grid.len());
+    }
+
+    #[test]
+    fn meshes_in_bbox_ok() {
+        let sw = Coordinate::new(35.665, 139.74).unwrap();
+        let ne = Coordinate::new(35.675, 139.76).unwrap();
+        let meshes = meshes_in_bbox::<Mesh3>(sw, ne).unwrap();
+        let expected = MeshGrid::<Mesh3>::new(sw, ne).unwrap().collect::<Vec<_>>();
+        assert_eq!(expected, meshes);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_meshes_in_bbox_ok() {
+        use crate::mesh::Mesh6;
+
+        let sw = Coordinate::new(35.65, 139.7).unwrap();
+        let ne = Coordinate::new(35.7, 139.75).unwrap();
+        let expected = meshes_in_bbox::<Mesh6>(sw, ne).unwrap();
+        let actual = par_meshes_in_bbox::<Mesh6>(sw, ne).unwrap();
+        assert!(!expected.is_empty());
+        assert_eq!(expected, actual);
+    }
+
+    fn codes<M: Mesh>(meshes: &[M]) -> Vec<&str> {
+        meshes.iter().map(|mesh| mesh.code()).collect()
+    }
+
+    #[test]
+    fn meshes_along_line_same_mesh_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let meshes = meshes_along_line::<Mesh3>(mesh.center(), mesh.center()).unwrap();
+        assert_eq!(vec!["53393599"], codes(&meshes));
+    }
+
+    #[test]
+    fn meshes_along_line_horizontal_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let east = mesh.east_mesh().unwrap();
+        let meshes = meshes_along_line::<Mesh3>(mesh.center(), east.center()).unwrap();
+        assert_eq!(vec!["53393599", "53393690"], codes(&meshes));
+        // 逆方向
+        let meshes = meshes_along_line::<Mesh3>(east.center(), mesh.center()).unwrap();
+        assert_eq!(vec!["53393690", "53393599"], codes(&meshes));
+    }
+
+    #[test]
+    fn meshes_along_line_vertical_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let north = mesh.north_mesh().unwrap().north_mesh().unwrap();
+        let meshes = meshes_along_line::<Mesh3>(mesh.center(), north.center()).unwrap();
+        assert_eq!(vec!["53393599", "53394509", "53394519"], codes(&meshes));
+    }
+
+    #[test]
+    fn meshes_along_line_corner_ok() {
+        // 頂点を通過する線分
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let north_east = mesh.north_east_mesh().unwrap();
+        let meshes = meshes_along_line::<Mesh3>(mesh.center(), north_east.center()).unwrap();
+        assert_eq!(vec!["53393599", "53394600"], codes(&meshes));
+    }
+
+    #[test]
+    fn meshes_along_line_diagonal_ok() {
+        // 頂点を通過しない斜めの線分は、東西または南北のメッシュを経由する
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let b = Coordinate::new(mesh.north() + 0.001, mesh.east() + 0.006).unwrap();
+        let meshes = meshes_along_line::<Mesh3>(mesh.center(), b).unwrap();
+        assert_eq!(vec!["53393599", "53393690", "53394600"], codes(&meshes));
+    }
+
+    fn range(south_west: &str, north_east: &str) -> MeshRange<Mesh3> {
+        MeshRange::new(
+            Mesh3::new(String::from(south_west)).unwrap(),
+            Mesh3::new(String::from(north_east)).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn mesh_range_iter_ok() {
+        let range = range("53393599", "53394600");
+        assert_eq!(4, range.len());
+        let codes = range
+            .iter()
+            .map(|mesh| mesh.code().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["53393599", "53393690", "53394509", "53394600"], codes);
+    }
+
+    #[test]
+    fn mesh_range_contains_ok() {
+        let range = range("53393599", "53394600");
+        for code in ["53393599", "53393690", "53394509", "53394600"] {
+            assert!(
+                range.contains(&Mesh3::new(String::from(code)).unwrap()),
+                "{}",
+                code
+            );
+        }
+        for code in ["53393598", "53393691", "53394519", "53393589"] {
+            assert!(
+                !range.contains(&Mesh3::new(String::from(code)).unwrap()),
+                "{}",
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn mesh_range_intersection_ok() {
+        // 53393599を南西端とする3x3の範囲と、53394600を南西端とする3x3の範囲
+        let a = range("53393599", "53394611");
+        let b = range("53394600", "53394622");
+        let actual = a.intersection(&b);
+        assert_eq!("53394600", actual.south_west().unwrap().code());
+        assert_eq!("53394611", actual.north_east().unwrap().code());
+        assert_eq!(4, actual.len());
+        assert_eq!(actual, b.intersection(&a));
+    }
+
+    #[test]
+    fn mesh_range_intersection_disjoint() {
+        let a = range("53393599", "53394600");
+        let b = range("53394611", "53394622");
+        let actual = a.intersection(&b);
+        assert!(actual.is_empty());
+        assert_eq!(0, actual.len());
+        assert!(!actual.contains(&Mesh3::new(String::from("53394611")).unwrap()));
+    }
+
+    #[test]
+    fn mesh_range_new_empty() {
+        let range = range("53394600", "53393599");
+        assert!(range.is_empty());
+        assert_eq!(0, range.iter().count());
+    }
+
+    /// `MeshGrid`で作成したメッシュのメッシュコードと、`CodeGrid`のメッシュコードを比較する。
+    fn assert_code_grid<M: Mesh>(level: MeshLevel, south_west: Coordinate, north_east: Coordinate) {
+        let expected = MeshGrid::<M>::new(south_west, north_east)
+            .unwrap()
+            .map(|mesh| mesh.code().to_string())
+            .collect::<Vec<_>>();
+        let mut grid = CodeGrid::new(level, south_west, north_east).unwrap();
+        assert_eq!(expected.len(), grid.remaining(), "{:?}", level);
+        let mut actual = vec![];
+        while let Some(code) = grid.next_code() {
+            actual.push(code.to_string());
+        }
+        assert!(!actual.is_empty(), "{:?}", level);
+        assert_eq!(expected, actual, "{:?}", level);
+    }
+
+    #[test]
+    fn code_grid_ok() {
+        // 第1次地域区画と第2次地域区画の境界をまたぐ範囲
+        let sw = Coordinate::new(35.63, 139.73).unwrap();
+        let ne = Coordinate::new(35.68, 139.77).unwrap();
+        assert_code_grid::<Mesh3>(MeshLevel::Mesh3, sw, ne);
+        assert_code_grid::<Mesh4>(MeshLevel::Mesh4, sw, ne);
+        assert_code_grid::<Mesh5>(MeshLevel::Mesh5, sw, ne);
+        assert_code_grid::<Mesh6>(MeshLevel::Mesh6, sw, ne);
+        let sw = Coordinate::new(35.3, 138.8).unwrap();
+        let ne = Coordinate::new(36.9, 140.2).unwrap();
+        assert_code_grid::<Mesh1>(MeshLevel::Mesh1, sw, ne);
+        assert_code_grid::<Mesh2>(MeshLevel::Mesh2, sw, ne);
+    }
+
+    #[test]
+    fn code_grid_national_ok() {
+        let sw = Coordinate::new(20.0, 118.0).unwrap();
+        let ne = Coordinate::new(48.0, 150.0).unwrap();
+        assert_code_grid::<Mesh1>(MeshLevel::Mesh1, sw, ne);
+        assert_code_grid::<Mesh2>(MeshLevel::Mesh2, sw, ne);
+    }
+
+    #[test]
+    fn code_grid_national_edge_ok() {
+        // 北東端が全国の範囲を超える範囲
+        let ne = Coordinate::new(48.9, 150.9).unwrap();
+        let sw = Coordinate::new(47.5, 139.5).unwrap();
+        assert_code_grid::<Mesh1>(MeshLevel::Mesh1, sw, ne);
+        let sw = Coordinate::new(47.99, 149.99).unwrap();
+        assert_code_grid::<Mesh3>(MeshLevel::Mesh3, sw, ne);
+        assert_code_grid::<Mesh6>(MeshLevel::Mesh6, sw, ne);
+    }
+
+    #[test]
+    fn meshes_crossing_polygon_ok() {
+        // 基準地域メッシュの中心を頂点とする三角形
+        let base = Mesh3::new(String::from("53393599")).unwrap();
+        let a = base.clone();
+        let b = base.offset(6, 0).unwrap();
+        let c = base.offset(3, 6).unwrap();
+        let ring = vec![a.center(), b.center(), c.center(), a.center()];
+        let meshes = meshes_crossing_polygon::<Mesh3>(&ring).unwrap();
+        for vertex in [&a, &b, &c] {
+            assert!(meshes.contains(vertex), "{}", vertex.code());
+        }
+        // 南端の辺が通過するメッシュ
+        for east in 0..=6 {
+            assert!(meshes.contains(&base.offset(east, 0).unwrap()), "{}", east);
+        }
+        // 三角形の内部にあり、辺が通過しないメッシュ
+        for east in 2..=4 {
+            assert!(!meshes.contains(&base.offset(east, 2).unwrap()), "{}", east);
+        }
+        // 重複がなく、南端の緯度、西端の経度の順に並ぶ
+        let codes = meshes
+            .iter()
+            .map(|mesh| mesh.code())
+            .collect::<BTreeSet<_>>();
+        assert_eq!(codes.len(), meshes.len());
+        assert!(meshes.windows(2).all(|pair| {
+            pair[0].south() < pair[1].south()
+                || (eq_f64(pair[0].south(), pair[1].south()) && pair[0].west() < pair[1].west())
+        }));
+        // 閉じていない頂点の並び
+        let open = meshes_crossing_polygon::<Mesh3>(&ring[..3]).unwrap();
+        assert_eq!(meshes, open);
+    }
+
+    #[test]
+    fn meshes_crossing_polygon_degenerate() {
+        assert!(meshes_crossing_polygon::<Mesh3>(&[]).unwrap().is_empty());
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let meshes = meshes_crossing_polygon::<Mesh3>(&[mesh.center()]).unwrap();
+        assert_eq!(vec![mesh], meshes);
+    }
+
+    #[test]
+    fn mesh_grid_empty_ok() {
+        let grid = MeshGrid::<Mesh1>::new(
+            Coordinate::new(36.0, 140.0).unwrap(),
+            Coordinate::new(35.0, 139.0).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(0, grid.len());
+    }
+
+    #[test]
+    fn total_mesh_count_ok() {
+        // 第1次地域区画は、南北に42個、東西に32個並ぶ
+        assert_eq!(42 * 32, total_mesh_count(MeshLevel::Mesh1));
+        let inputs = vec![
+            (MeshLevel::Mesh1, MeshLevel::Mesh2, 8 * 8),
+            (MeshLevel::Mesh2, MeshLevel::Mesh3, 10 * 10),
+            (MeshLevel::Mesh3, MeshLevel::Mesh4, 2 * 2),
+            (MeshLevel::Mesh4, MeshLevel::Mesh5, 2 * 2),
+            (MeshLevel::Mesh5, MeshLevel::Mesh6, 2 * 2),
+        ];
+        for (coarse, fine, factor) in inputs {
+            assert_eq!(
+                total_mesh_count(coarse) * factor,
+                total_mesh_count(fine),
+                "{:?}",
+                fine
+            );
+        }
+    }
+
+    #[test]
+    fn total_mesh_count_matches_grid() {
+        assert_eq!(
+            total_mesh_count(MeshLevel::Mesh1),
+            grid::<Mesh1>().len() as u64
+        );
+        assert_eq!(
+            total_mesh_count(MeshLevel::Mesh2),
+            grid::<Mesh2>().len() as u64
+        );
+    }
+
+    #[test]
+    fn mesh_ord_ok() {
+        let a = Mesh3::new(String::from("53393599")).unwrap();
+        let b = Mesh3::new(String::from("53394500")).unwrap();
+        assert!(a < b);
+        let set = [b.clone(), a.clone(), b.clone()]
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        assert_eq!(vec![&a, &b], set.iter().collect::<Vec<_>>());
+        // 粗いレベルのメッシュが先になる
+        let coarse = AnyMesh::from(Mesh2::new(String::from("533936")).unwrap());
+        assert!(coarse < AnyMesh::from(a));
+    }
+
+    #[test]
+    fn range_query_ok() {
+        // 基準地域メッシュ53393599を南西端とする、南北6行、東西6列のメッシュ
+        let sw = Mesh3::new(String::from("53393599")).unwrap();
+        let ne = sw
+            .offset_m(sw.height_m() * 5.0, sw.width_m() * 5.0)
+            .unwrap();
+        let set = meshes_in_bbox::<Mesh3>(sw.center(), ne.center())
+            .unwrap()
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        assert_eq!(36, set.len());
+        // 南西端から2行目と3列目を南西端とする、南北3行、東西2列の範囲
+        let query_sw = sw
+            .north_mesh()
+            .unwrap()
+            .east_mesh()
+            .unwrap()
+            .east_mesh()
+            .unwrap();
+        let query_ne = query_sw
+            .offset_m(sw.height_m() * 2.0, sw.width_m())
+            .unwrap();
+        let meshes = range_query(&set, &query_sw, &query_ne);
+        let expected = meshes_in_bbox::<Mesh3>(query_sw.center(), query_ne.center())
+            .unwrap()
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(6, meshes.len());
+        assert_eq!(expected, meshes);
+        // メッシュコードの順番で並ぶ
+        assert!(meshes.windows(2).all(|pair| pair[0] < pair[1]));
+        // 1つのメッシュの範囲
+        assert_eq!(vec![sw.clone()], range_query(&set, &sw, &sw));
+        // 南西端と北東端が逆の場合
+        assert!(range_query(&set, &ne, &sw).is_empty());
+        // 順序集合に含まれないメッシュを角とする範囲
+        let outside = ne.north_mesh().unwrap().east_mesh().unwrap();
+        assert_eq!(set.len(), range_query(&set, &sw, &outside).len());
+    }
+
+    #[test]
+    fn snap_bbox_to_grid_single_cell() {
+        // 53393599に含まれる範囲
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let (sw, ne) = snap_bbox_to_grid::<Mesh3>(35.6585, 139.7450, 35.6660, 139.7490).unwrap();
+        assert_eq!(mesh.south_west(), sw);
+        assert_eq!(mesh.north_east(), ne);
+        // 面積が0の範囲
+        let (sw, ne) = snap_bbox_to_grid::<Mesh3>(35.66, 139.74, 35.66, 139.74).unwrap();
+        assert_eq!(mesh.south_west(), sw);
+        assert_eq!(mesh.north_east(), ne);
+        // メッシュと同じ範囲
+        let (sw, ne) =
+            snap_bbox_to_grid::<Mesh3>(mesh.south(), mesh.west(), mesh.north(), mesh.east())
+                .unwrap();
+        assert_eq!(mesh.south_west(), sw);
+        assert_eq!(mesh.north_east(), ne);
+    }
+
+    #[test]
+    fn snap_bbox_to_grid_multi_cell() {
+        // 53393599から北に2つ、東に3つ離れたメッシュまでの範囲
+        let first = Mesh3::new(String::from("53393599")).unwrap();
+        let last = first.offset(3, 2).unwrap();
+        let (sw, ne) = snap_bbox_to_grid::<Mesh3>(
+            first.south() + 0.001,
+            first.west() + 0.001,
+            last.north() - 0.001,
+            last.east() - 0.001,
+        )
+        .unwrap();
+        assert_eq!(first.south_west(), sw);
+        assert_eq!(last.north_east(), ne);
+        // 拡げた範囲のメッシュは、元の範囲と重なるメッシュと一致する
+        let grid = MeshGrid::<Mesh3>::new(sw, ne).unwrap().collect::<Vec<_>>();
+        assert_eq!(4 * 3, grid.len());
+        assert_eq!(&first, grid.first().unwrap());
+        assert_eq!(&last, grid.last().unwrap());
+        // 第1次地域区画
+        let (sw, ne) = snap_bbox_to_grid::<Mesh1>(35.5, 139.5, 36.9, 140.1).unwrap();
+        assert_eq!(Mesh1::new(String::from("5339")).unwrap().south_west(), sw);
+        assert_eq!(Mesh1::new(String::from("5540")).unwrap().north_east(), ne);
+    }
+
+    #[test]
+    fn snap_bbox_to_grid_err() {
+        assert!(snap_bbox_to_grid::<Mesh3>(35.7, 139.7, 35.6, 139.8).is_err());
+        assert!(snap_bbox_to_grid::<Mesh3>(35.6, 139.8, 35.7, 139.7).is_err());
+        assert!(snap_bbox_to_grid::<Mesh3>(10.0, 139.7, 35.7, 139.8).is_err());
+        assert!(snap_bbox_to_grid::<Mesh3>(35.6, 139.7, 35.7, 170.0).is_err());
+    }
+
+    #[test]
+    fn meshes_within_distance_ok() {
+        // 基準地域メッシュの南北方向の長さは約927m、東西方向の長さは約1,129m
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let center = mesh.center();
+        // 中心のみ
+        let meshes = meshes_within_distance::<Mesh3>(center, 0.0).unwrap();
+        assert_eq!(vec![mesh.clone()], meshes);
+        // 北と南のメッシュは含み、東と西のメッシュは含まない
+        let meshes = meshes_within_distance::<Mesh3>(center, 1_000.0).unwrap();
+        assert_eq!(
+            vec![
+                mesh.south_mesh().unwrap(),
+                mesh.clone(),
+                mesh.north_mesh().unwrap()
+            ],
+            meshes
+        );
+        assert!(!meshes.contains(&mesh.east_mesh().unwrap()));
+        // 東と西のメッシュを含み、北東などのメッシュは含まない
+        let meshes = meshes_within_distance::<Mesh3>(center, 1_200.0).unwrap();
+        assert_eq!(5, meshes.len());
+        assert!(meshes.contains(&mesh.east_mesh().unwrap()));
+        assert!(meshes.contains(&mesh.west_mesh().unwrap()));
+        assert!(!meshes.contains(&mesh.north_mesh().unwrap().east_mesh().unwrap()));
+        // すべてのメッシュの中心が円の内部にある
+        let meshes = meshes_within_distance::<Mesh3>(center, 5_000.0).unwrap();
+        assert!(meshes
+            .iter()
+            .all(|m| m.center().distance_m(&center, EarthModel::default()) <= 5_000.0));
+    }
+
+    #[test]
+    fn meshes_within_distance_err() {
+        let center = Coordinate::new(35.658581, 139.745433).unwrap();
+        assert!(meshes_within_distance::<Mesh3>(center, -1.0).is_err());
+        // 円を囲む範囲が標準地域メッシュの範囲外
+        let center = Coordinate::new(SOUTHERNMOST, 139.745433).unwrap();
+        assert!(meshes_within_distance::<Mesh3>(center, 1_000.0).is_err());
+    }
+}
@@ -1,6 +1,11 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::math;
 use crate::mesh::{
-    contains_coordinate, Coordinate, GSJPError, Mesh, EASTERNMOST, NORTHERNMOST, SOUTHERNMOST,
-    WESTERNMOST,
+    cell_index, code_from_bytes, code_to_bytes, contains_coordinate, validate_code_len, Coordinate,
+    GSJPError, Mesh, MeshLevel, EASTERNMOST, NORTHERNMOST, SOUTHERNMOST, WESTERNMOST,
 };
 
 /// 第1次地域区画の南端と北端の緯度の差
@@ -38,12 +43,116 @@ const MESH1_LON_DIFF: f64 = 1.0; // 1度
 /// * 南東端の第1次地域区画のメッシュコードは`3049`
 /// * 南西端の第1次地域区画のメッシュコードは`3018`
 /// * 北西端の第1次地域区画のメッシュコードは`7118`
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Mesh1 {
     /// メッシュコード
     code: String,
 }
 
+impl Mesh1 {
+    /// 第1次地域区画のメッシュコードの長さ
+    pub const CODE_LEN: usize = MeshLevel::Mesh1.code_len();
+
+    /// 第1次地域区画のメッシュコードを整数で返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 整数のメッシュコード
+    #[must_use]
+    pub fn as_u16(&self) -> u16 {
+        // メッシュコードは検証済みの4桁の数字
+        self.code.parse().unwrap()
+    }
+
+    /// 整数のメッシュコードから第1次地域区画を作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `code` - 整数のメッシュコード
+    ///
+    /// # 戻り値
+    ///
+    /// 第1次地域区画
+    pub fn from_u16(code: u16) -> Result<Self, GSJPError> {
+        Self::new(format!("{:04}", code))
+    }
+
+    /// 第1次地域区画の周囲にある、範囲内の第1次地域区画を返す。
+    ///
+    /// メッシュコードを1回だけ解析して、北から時計回り（北、北東、東、南東、南、南西、西、北西）の順に、
+    /// 標準地域メッシュの範囲内にある第1次地域区画だけを作成する。
+    /// 範囲の内部の第1次地域区画は8個、範囲の角の第1次地域区画は3個の第1次地域区画を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 周囲にある範囲内の第1次地域区画
+    #[must_use]
+    pub fn neighbors_existing(&self) -> Vec<Mesh1> {
+        const OFFSETS: [(i16, i16); 8] = [
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+        ];
+        let lat = self.code[0..2].parse::<i16>().unwrap();
+        let lon = self.code[2..4].parse::<i16>().unwrap();
+        let lat_range = mesh1_lat_min() as i16..=mesh1_lat_max() as i16;
+        let lon_range = mesh1_lon_min() as i16..=mesh1_lon_max() as i16;
+
+        OFFSETS
+            .iter()
+            .map(|(d_lat, d_lon)| (lat + d_lat, lon + d_lon))
+            .filter(|(lat, lon)| lat_range.contains(lat) && lon_range.contains(lon))
+            .map(|(lat, lon)| Mesh1 {
+                code: format!("{:02}{:02}", lat, lon),
+            })
+            .collect()
+    }
+
+    /// 第1次地域区画を、2バイトのバイト列に変換する（形式は`code_to_bytes`を参照）。
+    ///
+    /// # 戻り値
+    ///
+    /// 2バイトのバイト列
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 2] {
+        code_to_bytes(self.as_u64())
+    }
+
+    /// `to_bytes`で変換したバイト列から第1次地域区画を作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `bytes` - ビッグエンディアンで整数のメッシュコードを格納した2バイトのバイト列
+    ///
+    /// # 戻り値
+    ///
+    /// 第1次地域区画
+    pub fn from_bytes(bytes: [u8; 2]) -> Result<Self, GSJPError> {
+        Self::from_u64(code_from_bytes(bytes))
+    }
+
+    /// メッシュコードが第1次地域区画のメッシュコードとして正しいか確認する。
+    ///
+    /// `new`と同様にメッシュコードを検証するが、第1次地域区画を作成しない。
+    ///
+    /// # 引数
+    ///
+    /// * `code` - 第1次地域区画のメッシュコード
+    ///
+    /// # 戻り値
+    ///
+    /// 正しい場合は`true`、正しくない場合は`false`
+    #[must_use]
+    pub fn is_valid(code: &str) -> bool {
+        validate_mesh1_code(code).is_ok()
+    }
+}
+
 impl Mesh for Mesh1 {
     fn new(code: String) -> Result<Self, GSJPError> {
         validate_mesh1_code(&code)?;
@@ -54,8 +163,8 @@ impl Mesh for Mesh1 {
     fn from_coordinate(coord: Coordinate) -> Result<Self, GSJPError> {
         contains_coordinate(&coord)?;
 
-        let lat = (coord.lat() * 1.5) as u8;
-        let lon = (coord.lon() - 100.0) as u8;
+        let lat = cell_index(coord.lat(), MESH1_LAT_DIFF);
+        let lon = cell_index(coord.lon() - 100.0, MESH1_LON_DIFF);
         let code = format!("{:02}{:02}", lat, lon);
 
         Ok(Mesh1 { code })
@@ -65,6 +174,10 @@ impl Mesh for Mesh1 {
         &self.code
     }
 
+    fn into_code(self) -> String {
+        self.code
+    }
+
     fn north(&self) -> f64 {
         self.south() + MESH1_LAT_DIFF
     }
@@ -82,38 +195,75 @@ impl Mesh for Mesh1 {
     }
 
     fn north_mesh(&self) -> Result<Self, GSJPError> {
-        let lat = self.code[0..2].parse::<u8>().unwrap() + 1;
+        // 範囲の変更でメッシュコードの各部分が`u8`の上限または下限に近づいても、パニックせずにエラーを返す
+        let lat = self.code[0..2]
+            .parse::<u8>()
+            .unwrap()
+            .checked_add(1)
+            .ok_or(GSJPError::InvalidMeshCode)?;
         let code = format!("{:02}{}", lat, &self.code[2..4]);
 
         Mesh1::new(code)
     }
 
     fn east_mesh(&self) -> Result<Self, GSJPError> {
-        let lon = self.code[2..4].parse::<u8>().unwrap() + 1;
+        let lon = self.code[2..4]
+            .parse::<u8>()
+            .unwrap()
+            .checked_add(1)
+            .ok_or(GSJPError::InvalidMeshCode)?;
         let code = format!("{}{:02}", &self.code[0..2], lon);
 
         Mesh1::new(code)
     }
 
     fn south_mesh(&self) -> Result<Self, GSJPError> {
-        let lat = self.code[0..2].parse::<u8>().unwrap() - 1;
+        let lat = self.code[0..2]
+            .parse::<u8>()
+            .unwrap()
+            .checked_sub(1)
+            .ok_or(GSJPError::InvalidMeshCode)?;
         let code = format!("{:02}{}", lat, &self.code[2..4]);
 
         Mesh1::new(code)
     }
 
     fn west_mesh(&self) -> Result<Self, GSJPError> {
-        let lon = self.code[2..4].parse::<u8>().unwrap() - 1;
+        let lon = self.code[2..4]
+            .parse::<u8>()
+            .unwrap()
+            .checked_sub(1)
+            .ok_or(GSJPError::InvalidMeshCode)?;
         let code = format!("{}{:02}", &self.code[0..2], lon);
 
         Mesh1::new(code)
     }
 }
 
+impl PartialEq<str> for Mesh1 {
+    fn eq(&self, other: &str) -> bool {
+        self.code == other
+    }
+}
+
+impl PartialEq<&str> for Mesh1 {
+    fn eq(&self, other: &&str) -> bool {
+        self.code == *other
+    }
+}
+
+impl From<Mesh1> for String {
+    /// メッシュを消費して、メッシュコードを返す。
+    fn from(mesh: Mesh1) -> Self {
+        mesh.code
+    }
+}
+
 /// 第1次地域区画のメッシュコードを検証する。
 ///
-/// 緯度の範囲を20度から46度までとする。
-/// 経度の範囲を122度から154度までとする。
+/// メッシュコードは4桁のASCII数字でなければならない。
+/// また、メッシュコードが表現する区画は、緯度20度から48度まで、経度118度から150度までの
+/// 範囲に収まらなければならない。
 ///
 /// # 引数
 ///
@@ -123,36 +273,57 @@ impl Mesh for Mesh1 {
 ///
 /// `()`
 pub(crate) fn validate_mesh1_code(code: &str) -> Result<(), GSJPError> {
+    validate_code_len(code, Mesh1::CODE_LEN)?;
     // メッシュコードを緯度部分と経度部分に分割
-    if code.len() != 4 {
+    if !code.bytes().all(|b| b.is_ascii_digit()) {
         return Err(GSJPError::InvalidMeshCode);
     }
-    let lat = &code[0..2];
-    let lon = &code[2..4];
+    let lat = code[0..2].parse::<u8>().unwrap();
+    let lon = code[2..4].parse::<u8>().unwrap();
 
     // 緯度部分を検証
-    let lat_min = ((SOUTHERNMOST * 1.5) as u8).to_string();
-    let lat_max = (((NORTHERNMOST - MESH1_LAT_DIFF) * 1.5) as u8).to_string();
-    if lat < &lat_min || lat > &lat_max {
+    if lat < mesh1_lat_min() || mesh1_lat_max() < lat {
         return Err(GSJPError::InvalidMeshCode);
     }
 
     // 経度部分を検証
-    let lon_min = (WESTERNMOST as u8 % 100).to_string();
-    let lon_max = ((EASTERNMOST - MESH1_LON_DIFF) as u8 % 100).to_string();
-    if lon < &lon_min || lon > &lon_max {
+    if lon < mesh1_lon_min() || mesh1_lon_max() < lon {
         return Err(GSJPError::InvalidMeshCode);
     }
 
     Ok(())
 }
 
+// 浮動小数点数の切り捨てによる誤差を避けるため、メッシュコードの緯度部分と経度部分の範囲は、
+// 丸めてから整数に変換する
+
+/// 第1次地域区画のメッシュコードの緯度部分の最小値を返す。
+fn mesh1_lat_min() -> u8 {
+    math::round(SOUTHERNMOST * 1.5) as u8
+}
+
+/// 第1次地域区画のメッシュコードの緯度部分の最大値を返す。
+fn mesh1_lat_max() -> u8 {
+    math::round((NORTHERNMOST - MESH1_LAT_DIFF) * 1.5) as u8
+}
+
+/// 第1次地域区画のメッシュコードの経度部分の最小値を返す。
+fn mesh1_lon_min() -> u8 {
+    math::round(WESTERNMOST - 100.0) as u8
+}
+
+/// 第1次地域区画のメッシュコードの経度部分の最大値を返す。
+fn mesh1_lon_max() -> u8 {
+    math::round(EASTERNMOST - MESH1_LON_DIFF - 100.0) as u8
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::mesh::tests::eq_f64;
     use crate::mesh::{
-        Coordinate, NeighborDirection, EASTERNMOST, NORTHERNMOST, SOUTHERNMOST, WESTERNMOST,
+        Coordinate, MeshLevel, NeighborDirection, EASTERNMOST, NORTHERNMOST, SOUTHERNMOST,
+        WESTERNMOST,
     };
 
     #[test]
@@ -178,15 +349,106 @@ mod tests {
     }
 
     #[test]
-    fn mesh1_new_err() {
-        assert!(Mesh1::new(String::from("7249")).is_err()); // 最北東端の1つ北側
-        assert!(Mesh1::new(String::from("7150")).is_err()); // 最北東端の1つ東側
-        assert!(Mesh1::new(String::from("2949")).is_err()); // 最南東端の1つ南側
-        assert!(Mesh1::new(String::from("3050")).is_err()); // 最南東端の1つ東側
-        assert!(Mesh1::new(String::from("2918")).is_err()); // 最南西端の1つ南側
-        assert!(Mesh1::new(String::from("3017")).is_err()); // 最南西端の1つ西側
-        assert!(Mesh1::new(String::from("7218")).is_err()); // 最北西端の1つ北側
-        assert!(Mesh1::new(String::from("7217")).is_err()); // 最北西端の1つ西側
+    fn mesh1_is_valid_ok() {
+        for code in ["5339", "7149", "3018"] {
+            assert!(Mesh1::is_valid(code), "{}", code);
+            assert!(Mesh1::new(String::from(code)).is_ok(), "{}", code);
+        }
+        for code in ["7249", "7150", "3017", "533", "53a9"] {
+            assert!(!Mesh1::is_valid(code), "{}", code);
+            assert!(Mesh1::new(String::from(code)).is_err(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh1_bytes_ok() {
+        let mesh = Mesh1::new(String::from("5339")).unwrap();
+        assert_eq!([0x14, 0xDB], mesh.to_bytes());
+        // 途中に0を含むメッシュコード
+        let codes = ["3018", "5339", "7149"];
+        for code in codes {
+            let mesh = Mesh1::new(code.to_string()).unwrap();
+            assert_eq!(
+                mesh,
+                Mesh1::from_bytes(mesh.to_bytes()).unwrap(),
+                "{}",
+                code
+            );
+        }
+        // バイト列はメッシュコードの順番に並ぶ
+        let bytes = codes.map(|code| Mesh1::new(code.to_string()).unwrap().to_bytes());
+        assert!(bytes.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn mesh1_from_bytes_err() {
+        assert!(Mesh1::from_bytes([0; 2]).is_err());
+        assert!(Mesh1::from_bytes([0xFF; 2]).is_err());
+    }
+
+    #[test]
+    fn mesh1_into_string_ok() {
+        let mesh = Mesh1::new(String::from("5339")).unwrap();
+        // `mesh`はムーブされるため、以降で使用できない
+        let code = String::from(mesh);
+        assert_eq!("5339", code);
+    }
+
+    #[test]
+    fn mesh1_code_len_ok() {
+        let mesh = Mesh1::new(String::from("5339")).unwrap();
+        assert_eq!(4, Mesh1::CODE_LEN);
+        assert_eq!(mesh.code().len(), Mesh1::CODE_LEN);
+        assert_eq!(MeshLevel::Mesh1.code_len(), Mesh1::CODE_LEN);
+    }
+
+    #[test]
+    fn validate_mesh1_code_national_corners() {
+        // (メッシュコード, 検証に成功するか)
+        let cases = vec![
+            ("7149", true),  // 最北東端
+            ("3049", true),  // 最南東端
+            ("3018", true),  // 最南西端
+            ("7118", true),  // 最北西端
+            ("7249", false), // 最北東端の1つ北側
+            ("7150", false), // 最北東端の1つ東側
+            ("2949", false), // 最南東端の1つ南側
+            ("3050", false), // 最南東端の1つ東側
+            ("2918", false), // 最南西端の1つ南側
+            ("3017", false), // 最南西端の1つ西側
+            ("7218", false), // 最北西端の1つ北側
+            ("7117", false), // 最北西端の1つ西側
+        ];
+        for (code, expected) in cases {
+            assert_eq!(expected, validate_mesh1_code(code).is_ok(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn validate_mesh1_code_non_digit_err() {
+        // 辞書順の比較では範囲内に見えるが、数字でないメッシュコード
+        let codes = vec!["5:39", "53:9", "5 39", "+539", "53.9", "５３39", ""];
+        for code in codes {
+            assert!(validate_mesh1_code(code).is_err(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh1_u16_ok() {
+        let codes = vec![(7149_u16, "7149"), (3018, "3018"), (5339, "5339")];
+        for (value, code) in codes {
+            let mesh = Mesh1::new(code.to_string()).unwrap();
+            assert_eq!(value, mesh.as_u16(), "{}", code);
+            assert_eq!(mesh, Mesh1::from_u16(value).unwrap(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh1_from_u16_err() {
+        let values = vec![0_u16, 18, 2918, 7218, 3017, 3050, 53390];
+        for value in values {
+            assert!(Mesh1::from_u16(value).is_err(), "{}", value);
+        }
     }
 
     #[test]
@@ -257,6 +519,9 @@ mod tests {
             Coordinate::new(NORTHERNMOST, WESTERNMOST - 1.0).unwrap(),
             Coordinate::new(SOUTHERNMOST - 1.0, WESTERNMOST).unwrap(),
             Coordinate::new(SOUTHERNMOST, EASTERNMOST + 1.0).unwrap(),
+            // 北端と東端の線上は範囲外
+            Coordinate::new(NORTHERNMOST, 139.0).unwrap(),
+            Coordinate::new(35.0, EASTERNMOST).unwrap(),
         ];
         for coord in data {
             assert!(Mesh1::from_coordinate(coord).is_err());
@@ -333,6 +598,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mesh1_center_extreme_ok() {
+        // 最北東端と最南西端の第1次地域区画
+        let inputs = vec![("7149", 47.66666667, 149.5), ("3018", 20.33333333, 118.5)];
+        for (code, lat, lon) in inputs {
+            let mesh = Mesh1::new(code.to_string()).unwrap();
+            let center = mesh.center();
+            assert!(eq_f64(lat, center.lat()), "{}", code);
+            assert!(eq_f64(lon, center.lon()), "{}", code);
+            for corner in mesh.corners() {
+                assert!(
+                    Coordinate::new(corner.lat(), corner.lon()).is_ok(),
+                    "{}: {:?}",
+                    code,
+                    corner
+                );
+            }
+        }
+    }
+
     #[test]
     fn mesh1_north_east_ok() {
         let mesh = Mesh1::new(String::from("3022")).unwrap();
@@ -423,6 +708,46 @@ mod tests {
         assert!(mesh.north_mesh().is_err());
     }
 
+    #[test]
+    fn mesh1_neighbor_mesh_at_limits_err() {
+        // 最北東端と最南西端の第1次地域区画から、範囲外に移動する
+        let north_east = Mesh1::new(String::from("7149")).unwrap();
+        assert!(matches!(
+            north_east.north_mesh(),
+            Err(GSJPError::InvalidMeshCode)
+        ));
+        assert!(matches!(
+            north_east.east_mesh(),
+            Err(GSJPError::InvalidMeshCode)
+        ));
+        let south_west = Mesh1::new(String::from("3018")).unwrap();
+        assert!(matches!(
+            south_west.south_mesh(),
+            Err(GSJPError::InvalidMeshCode)
+        ));
+        assert!(matches!(
+            south_west.west_mesh(),
+            Err(GSJPError::InvalidMeshCode)
+        ));
+    }
+
+    #[test]
+    fn mesh1_neighbor_mesh_two_digit_limits_err() {
+        // 検証を経ずに作成した、緯度と経度の部分が2桁の上限と下限の第1次地域区画
+        // 上限の99に1を加えると3桁になり、メッシュコードの検証でエラーになる
+        let max = Mesh1 {
+            code: String::from("9999"),
+        };
+        assert!(matches!(max.north_mesh(), Err(GSJPError::InvalidMeshCode)));
+        assert!(matches!(max.east_mesh(), Err(GSJPError::InvalidMeshCode)));
+        // 下限の0から1を引くと`u8`の下限を下回り、`checked_sub`でエラーになる
+        let min = Mesh1 {
+            code: String::from("0000"),
+        };
+        assert!(matches!(min.south_mesh(), Err(GSJPError::InvalidMeshCode)));
+        assert!(matches!(min.west_mesh(), Err(GSJPError::InvalidMeshCode)));
+    }
+
     #[test]
     fn mesh1_east_mesh_ok() {
         let mesh = Mesh1::new(String::from("3048")).unwrap();
@@ -462,6 +787,95 @@ mod tests {
         assert!(mesh.west_mesh().is_err());
     }
 
+    #[test]
+    fn mesh1_mesh_opt_some() {
+        let mesh = Mesh1::new(String::from("5339")).unwrap();
+        assert_eq!("5439", mesh.north_mesh_opt().unwrap().code());
+        assert_eq!("5340", mesh.east_mesh_opt().unwrap().code());
+        assert_eq!("5239", mesh.south_mesh_opt().unwrap().code());
+        assert_eq!("5338", mesh.west_mesh_opt().unwrap().code());
+    }
+
+    #[test]
+    fn mesh1_mesh_opt_none() {
+        // 最北東端
+        let mesh = Mesh1::new(String::from("7149")).unwrap();
+        assert!(mesh.north_mesh_opt().is_none());
+        assert!(mesh.east_mesh_opt().is_none());
+        assert!(mesh.south_mesh_opt().is_some());
+        assert!(mesh.west_mesh_opt().is_some());
+        // 最南西端
+        let mesh = Mesh1::new(String::from("3018")).unwrap();
+        assert!(mesh.north_mesh_opt().is_some());
+        assert!(mesh.east_mesh_opt().is_some());
+        assert!(mesh.south_mesh_opt().is_none());
+        assert!(mesh.west_mesh_opt().is_none());
+    }
+
+    /// エラーのメッセージが、範囲外になった方向を示すことを確認する。
+    fn assert_out_of_range(result: Result<Mesh1, GSJPError>, direction: &str) {
+        match result {
+            Err(GSJPError::OutOfRange(message)) => {
+                assert!(message.starts_with(direction), "{}", message)
+            }
+            result => panic!("{:?}", result),
+        }
+    }
+
+    #[test]
+    fn mesh1_diagonal_mesh_err_leg() {
+        // 北端にあるが、東西方向は内側にあるメッシュ
+        let mesh = Mesh1::new(String::from("7130")).unwrap();
+        assert_out_of_range(mesh.north_east_mesh(), "北");
+        assert_out_of_range(mesh.north_west_mesh(), "北");
+        assert_eq!("7031", mesh.south_east_mesh().unwrap().code());
+        // 東端にあるが、南北方向は内側にあるメッシュ
+        let mesh = Mesh1::new(String::from("5349")).unwrap();
+        assert_out_of_range(mesh.north_east_mesh(), "東");
+        assert_out_of_range(mesh.south_east_mesh(), "東");
+        assert_eq!("5448", mesh.north_west_mesh().unwrap().code());
+        // 南西端のメッシュは、南隣が先に範囲外になる
+        let mesh = Mesh1::new(String::from("3018")).unwrap();
+        assert_out_of_range(mesh.south_west_mesh(), "南");
+    }
+
+    #[test]
+    fn mesh1_neighbors_existing_ok() {
+        // 範囲の内部
+        let mesh = Mesh1::new(String::from("5339")).unwrap();
+        let neighbors = mesh.neighbors_existing();
+        let codes = neighbors.iter().map(|m| m.code()).collect::<Vec<_>>();
+        assert_eq!(
+            vec!["5439", "5440", "5340", "5240", "5239", "5238", "5338", "5438"],
+            codes
+        );
+        // 各方向の隣接メッシュと一致する
+        assert_eq!(mesh.north_mesh().unwrap(), neighbors[0]);
+        assert_eq!(mesh.north_east_mesh().unwrap(), neighbors[1]);
+        assert_eq!(mesh.east_mesh().unwrap(), neighbors[2]);
+        assert_eq!(mesh.south_west_mesh().unwrap(), neighbors[5]);
+        // 範囲の角
+        let inputs = vec![
+            ("3018", vec!["3118", "3119", "3019"]),
+            ("3049", vec!["3149", "3048", "3148"]),
+            ("7149", vec!["7049", "7048", "7148"]),
+            ("7118", vec!["7119", "7019", "7018"]),
+        ];
+        for (code, expected) in inputs {
+            let mesh = Mesh1::new(code.to_string()).unwrap();
+            let neighbors = mesh.neighbors_existing();
+            assert_eq!(
+                expected,
+                neighbors.iter().map(|m| m.code()).collect::<Vec<_>>(),
+                "{}",
+                code
+            );
+        }
+        // 範囲の辺
+        let mesh = Mesh1::new(String::from("3039")).unwrap();
+        assert_eq!(5, mesh.neighbors_existing().len());
+    }
+
     #[test]
     fn mesh1_is_neighbor_ok() {
         let mesh = Mesh1::new(String::from("3123")).unwrap();
@@ -539,4 +953,18 @@ mod tests {
             NeighborDirection::None
         );
     }
+
+    #[test]
+    fn mesh1_subdivide_to_ok() {
+        let mesh = Mesh1::new(String::from("5339")).unwrap();
+        let children = mesh.subdivide_to(MeshLevel::Mesh2).unwrap();
+        assert_eq!(8 * 8, children.len());
+        assert_eq!("533900", children[0].code());
+        assert_eq!("533977", children[63].code());
+        let children = mesh.subdivide_to(MeshLevel::Mesh3).unwrap();
+        assert_eq!(80 * 80, children.len());
+        assert_eq!("53390000", children[0].code());
+        assert_eq!("53397799", children[6399].code());
+        assert!(children.iter().all(|child| mesh.contains_mesh(child)));
+    }
 }
@@ -1,4 +1,11 @@
-use crate::mesh::{mesh1::validate_mesh1_code, Coordinate, GSJPError, Mesh, Mesh1};
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use crate::mesh::{
+    ancestor_code, cell_index, code_from_bytes, code_to_bytes, mesh1::validate_mesh1_code,
+    validate_code_len, validate_part, AnyMesh, Coordinate, GSJPError, Mesh, Mesh1, MeshLevel,
+};
 
 /// 第2次地域区画の南端と北端の緯度の差
 const MESH2_LAT_DIFF: f64 = 5.0 / 60.0; // 5分
@@ -9,19 +16,115 @@ const MESH2_LON_DIFF: f64 = 7.0 / 60.0 + 30.0 / 3600.0; // 7分30秒
 ///
 /// 第1次地域区画を南北に8等分、東西に8等分した区画を示す。
 /// 第2次地域区画の辺の長さは約10kmである。
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Mesh2 {
     code: String,
 }
 
 impl Mesh2 {
+    /// 第2次地域区画のメッシュコードの長さ
+    pub const CODE_LEN: usize = MeshLevel::Mesh2.code_len();
+
     /// 第2次地域区画を含む第1次地域区画を返す。
     ///
     /// # 戻り値
     ///
     /// 第1次地域区画
+    #[must_use]
     pub fn mesh1(&self) -> Mesh1 {
-        Mesh1::new(self.code[0..4].to_string()).unwrap()
+        Mesh1::new(ancestor_code(self, MeshLevel::Mesh1)).unwrap()
+    }
+
+    /// 第2次地域区画を含む上位のメッシュを、細かいレベルから順に返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 第2次地域区画を含む上位のメッシュ。最後の要素は第1次地域区画
+    #[must_use]
+    pub fn ancestors(&self) -> Vec<AnyMesh> {
+        vec![self.mesh1().into()]
+    }
+
+    /// 第2次地域区画を含む、1/50,000地形図の図葉の位置を返す。
+    ///
+    /// 第1次地域区画は1/200,000地勢図の図葉と、第2次地域区画は1/25,000地形図の図葉と範囲が一致する。
+    /// 1/50,000地形図の図葉は、1/200,000地勢図の図葉を南北に4等分、東西に4等分した範囲であり、
+    /// 南北に2個、東西に2個の第2次地域区画を含む。
+    ///
+    /// 図葉の位置は、第2次地域区画のメッシュコードと同様に、第1次地域区画の南西端から数える。
+    /// 1/200,000地勢図の図葉内で北西端から1から16までの番号を付ける図葉番号は、
+    /// `(3 - 南北方向の位置) * 4 + 東西方向の位置 + 1`で求められる。
+    /// 図葉名は、第1次地域区画と図葉の位置から、呼び出し元が一覧表を参照して求める。
+    ///
+    /// # 戻り値
+    ///
+    /// 第1次地域区画内の図葉の位置（南北方向の位置, 東西方向の位置）。いずれも0から3
+    #[must_use]
+    pub fn map_sheet_index(&self) -> (u8, u8) {
+        // メッシュコードは検証済みであるため、5桁目と6桁目は0から7の数字
+        let bytes = self.code.as_bytes();
+
+        ((bytes[4] - b'0') / 2, (bytes[5] - b'0') / 2)
+    }
+
+    /// 第1次地域区画と、第1次地域区画内の南北方向と東西方向の位置から第2次地域区画を作成する。
+    ///
+    /// 位置は、第1次地域区画の南端と西端から数えて0から7の範囲で指定する。
+    /// `index_in_parent`の逆の変換である。
+    ///
+    /// # 引数
+    ///
+    /// * `mesh1` - 第1次地域区画
+    /// * `lat_idx` - 第1次地域区画内の南北方向の位置
+    /// * `lon_idx` - 第1次地域区画内の東西方向の位置
+    ///
+    /// # 戻り値
+    ///
+    /// 第2次地域区画。位置が範囲外の場合はエラー
+    pub fn from_parts(mesh1: &Mesh1, lat_idx: u8, lon_idx: u8) -> Result<Self, GSJPError> {
+        validate_part("南北方向の位置", lat_idx, 0..=7)?;
+        validate_part("東西方向の位置", lon_idx, 0..=7)?;
+
+        Self::new(format!("{}{}{}", mesh1.code(), lat_idx, lon_idx))
+    }
+
+    /// 第2次地域区画を、3バイトのバイト列に変換する（形式は`code_to_bytes`を参照）。
+    ///
+    /// # 戻り値
+    ///
+    /// 3バイトのバイト列
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 3] {
+        code_to_bytes(self.as_u64())
+    }
+
+    /// `to_bytes`で変換したバイト列から第2次地域区画を作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `bytes` - ビッグエンディアンで整数のメッシュコードを格納した3バイトのバイト列
+    ///
+    /// # 戻り値
+    ///
+    /// 第2次地域区画
+    pub fn from_bytes(bytes: [u8; 3]) -> Result<Self, GSJPError> {
+        Self::from_u64(code_from_bytes(bytes))
+    }
+
+    /// メッシュコードが第2次地域区画のメッシュコードとして正しいか確認する。
+    ///
+    /// `new`と同様にメッシュコードを検証するが、第2次地域区画を作成しない。
+    ///
+    /// # 引数
+    ///
+    /// * `code` - 第2次地域区画のメッシュコード
+    ///
+    /// # 戻り値
+    ///
+    /// 正しい場合は`true`、正しくない場合は`false`
+    #[must_use]
+    pub fn is_valid(code: &str) -> bool {
+        validate_mesh2_code(code).is_ok()
     }
 }
 
@@ -34,8 +137,8 @@ impl Mesh for Mesh2 {
 
     fn from_coordinate(coord: Coordinate) -> Result<Self, GSJPError> {
         let mesh1 = Mesh1::from_coordinate(coord)?;
-        let lat_n = ((coord.lat() - mesh1.south()) / MESH2_LAT_DIFF) as u8;
-        let lon_n = ((coord.lon() - mesh1.west()) / MESH2_LON_DIFF) as u8;
+        let lat_n = cell_index(coord.lat() - mesh1.south(), MESH2_LAT_DIFF);
+        let lon_n = cell_index(coord.lon() - mesh1.west(), MESH2_LON_DIFF);
         let code = format!("{}{}{}", mesh1.code(), lat_n, lon_n);
 
         Self::new(code)
@@ -45,6 +148,10 @@ impl Mesh for Mesh2 {
         &self.code
     }
 
+    fn into_code(self) -> String {
+        self.code
+    }
+
     fn north(&self) -> f64 {
         self.south() + MESH2_LAT_DIFF
     }
@@ -122,6 +229,25 @@ impl Mesh for Mesh2 {
     }
 }
 
+impl PartialEq<str> for Mesh2 {
+    fn eq(&self, other: &str) -> bool {
+        self.code == other
+    }
+}
+
+impl PartialEq<&str> for Mesh2 {
+    fn eq(&self, other: &&str) -> bool {
+        self.code == *other
+    }
+}
+
+impl From<Mesh2> for String {
+    /// メッシュを消費して、メッシュコードを返す。
+    fn from(mesh: Mesh2) -> Self {
+        mesh.code
+    }
+}
+
 /// 第2次地域区画のメッシュコードを検証する。
 ///
 /// # 引数
@@ -132,18 +258,16 @@ impl Mesh for Mesh2 {
 ///
 /// `()`
 pub(crate) fn validate_mesh2_code(code: &str) -> Result<(), GSJPError> {
+    validate_code_len(code, Mesh2::CODE_LEN)?;
     // 上位4桁が第1次地域区画のメッシュコードであることを確認
-    if code.len() != 6 {
-        return Err(GSJPError::InvalidMeshCode);
-    }
     validate_mesh1_code(&code[0..4])?;
     // 第2次地域区画のメッシュコードの第2次地域区画部分について、緯度方向の値と経度方向の値を確認
-    let lat = &code.chars().nth(4).unwrap();
-    if !(&'0'..=&'7').contains(&lat) {
+    let lat = code.as_bytes()[4];
+    if !(b'0'..=b'7').contains(&lat) {
         return Err(GSJPError::InvalidMeshCode);
     }
-    let lon = &code.chars().nth(5).unwrap();
-    if !(&'0'..=&'7').contains(&lon) {
+    let lon = code.as_bytes()[5];
+    if !(b'0'..=b'7').contains(&lon) {
         return Err(GSJPError::InvalidMeshCode);
     }
 
@@ -154,7 +278,45 @@ pub(crate) fn validate_mesh2_code(code: &str) -> Result<(), GSJPError> {
 pub(crate) mod tests {
     use super::*;
     use crate::mesh::tests::{eq_f64, EPSILON};
-    use crate::mesh::{NeighborDirection, EASTERNMOST, NORTHERNMOST, SOUTHERNMOST, WESTERNMOST};
+    use crate::mesh::{
+        Mesh3, Mesh4, NeighborDirection, EASTERNMOST, NORTHERNMOST, SOUTHERNMOST, WESTERNMOST,
+    };
+
+    #[test]
+    fn mesh2_ancestors_ok() {
+        let code = "533935";
+        let mesh = Mesh2::new(String::from(code)).unwrap();
+        let ancestors = mesh.ancestors();
+        let expected = [&code[0..4]];
+        assert_eq!(
+            expected.to_vec(),
+            ancestors.iter().map(|mesh| mesh.code()).collect::<Vec<_>>()
+        );
+        assert_eq!(MeshLevel::Mesh1, ancestors.last().unwrap().level());
+    }
+
+    #[test]
+    fn mesh2_from_parts_ok() {
+        let mesh1 = Mesh1::new(String::from("5339")).unwrap();
+        let mesh = Mesh2::from_parts(&mesh1, 3, 5).unwrap();
+        assert_eq!("533935", mesh.code());
+        assert_eq!((3, 5), mesh.index_in_parent());
+        assert_eq!("533900", Mesh2::from_parts(&mesh1, 0, 0).unwrap().code());
+        assert_eq!("533977", Mesh2::from_parts(&mesh1, 7, 7).unwrap().code());
+    }
+
+    #[test]
+    fn mesh2_from_parts_err() {
+        let mesh1 = Mesh1::new(String::from("5339")).unwrap();
+        assert!(matches!(
+            Mesh2::from_parts(&mesh1, 8, 0),
+            Err(GSJPError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            Mesh2::from_parts(&mesh1, 0, 8),
+            Err(GSJPError::OutOfRange(_))
+        ));
+    }
 
     #[test]
     fn mesh2_new_ok() {
@@ -169,26 +331,57 @@ pub(crate) mod tests {
     }
 
     #[test]
-    fn mesh2_new_err() {
-        // 下位2桁の数値が不正
-        assert!(Mesh2::new(String::from("695480")).is_err(), "695480");
-        assert!(Mesh2::new(String::from("695408")).is_err(), "695408");
-        // 最北東端(714977)の1つ北側の第2次地域区画
-        assert!(Mesh2::new(String::from("724907")).is_err(), "724907");
-        // 最北東端の1つ東側の第2次地域区画
-        assert!(Mesh2::new(String::from("725070")).is_err(), "725070");
-        // 最南東端(304907)の1つ南側の第2次地域区画
-        assert!(Mesh2::new(String::from("294977")).is_err(), "304877");
-        // 最南東端の1つ東側の第2次地域区画
-        assert!(Mesh2::new(String::from("305000")).is_err(), "305000");
-        // 最南西端(301800)の1つ南側の第2次地域区画
-        assert!(Mesh2::new(String::from("291870")).is_err(), "291870");
-        // 最南西端の1つ西側の第2次地域区画
-        assert!(Mesh2::new(String::from("301707")).is_err(), "301707");
-        // 最北西端(711870)の1つ北側の第2次地域区画
-        assert!(Mesh2::new(String::from("721800")).is_err(), "721800");
-        // 最北西端の1つ西側の第2次地域区画
-        assert!(Mesh2::new(String::from("711777")).is_err(), "711777");
+    fn mesh2_is_valid_ok() {
+        for code in ["533935", "301800", "714977"] {
+            assert!(Mesh2::is_valid(code), "{}", code);
+            assert!(Mesh2::new(String::from(code)).is_ok(), "{}", code);
+        }
+        for code in ["695480", "695408", "724907", "53393", "5339３5"] {
+            assert!(!Mesh2::is_valid(code), "{}", code);
+            assert!(Mesh2::new(String::from(code)).is_err(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh2_bytes_ok() {
+        let mesh = Mesh2::new(String::from("533935")).unwrap();
+        assert_eq!([0x08, 0x25, 0xAF], mesh.to_bytes());
+        // 途中に0を含むメッシュコード
+        let codes = ["301800", "533935", "714977"];
+        for code in codes {
+            let mesh = Mesh2::new(code.to_string()).unwrap();
+            assert_eq!(
+                mesh,
+                Mesh2::from_bytes(mesh.to_bytes()).unwrap(),
+                "{}",
+                code
+            );
+        }
+        // バイト列はメッシュコードの順番に並ぶ
+        let bytes = codes.map(|code| Mesh2::new(code.to_string()).unwrap().to_bytes());
+        assert!(bytes.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn mesh2_from_bytes_err() {
+        assert!(Mesh2::from_bytes([0; 3]).is_err());
+        assert!(Mesh2::from_bytes([0xFF; 3]).is_err());
+    }
+
+    #[test]
+    fn mesh2_into_string_ok() {
+        let mesh = Mesh2::new(String::from("533935")).unwrap();
+        // `mesh`はムーブされるため、以降で使用できない
+        let code = String::from(mesh);
+        assert_eq!("533935", code);
+    }
+
+    #[test]
+    fn mesh2_code_len_ok() {
+        let mesh = Mesh2::new(String::from("533935")).unwrap();
+        assert_eq!(6, Mesh2::CODE_LEN);
+        assert_eq!(mesh.code().len(), Mesh2::CODE_LEN);
+        assert_eq!(MeshLevel::Mesh2.code_len(), Mesh2::CODE_LEN);
     }
 
     #[test]
@@ -555,6 +748,66 @@ pub(crate) mod tests {
         }
     }
 
+    #[test]
+    fn mesh2_contains_mesh_ok() {
+        let mesh = Mesh2::new(String::from("533935")).unwrap();
+        assert!(mesh.contains_mesh(&Mesh4::new(String::from("533935994")).unwrap()));
+        assert!(mesh.contains_mesh(&Mesh3::new(String::from("53393500")).unwrap()));
+        // 同じメッシュ
+        assert!(mesh.contains_mesh(&Mesh2::new(String::from("533935")).unwrap()));
+    }
+
+    #[test]
+    fn mesh2_contains_mesh_false() {
+        let mesh = Mesh2::new(String::from("533935")).unwrap();
+        // 東隣の第2次地域区画に含まれる2分の1地域メッシュ
+        assert!(!mesh.contains_mesh(&Mesh4::new(String::from("533936901")).unwrap()));
+        // 隣の第2次地域区画
+        assert!(!mesh.contains_mesh(&Mesh2::new(String::from("533936")).unwrap()));
+        // メッシュを含む第1次地域区画
+        assert!(!mesh.contains_mesh(&Mesh1::new(String::from("5339")).unwrap()));
+    }
+
+    #[test]
+    fn mesh2_map_sheet_index_ok() {
+        let inputs = vec![
+            // 第1次地域区画の南西端
+            ("533900", (0, 0)),
+            ("533911", (0, 0)),
+            // 東京タワーを含む第2次地域区画（1/50,000地形図「東京西南部」）
+            ("533935", (1, 2)),
+            ("533946", (2, 3)),
+            // 第1次地域区画の北東端
+            ("533977", (3, 3)),
+        ];
+        for (code, expected) in inputs {
+            let mesh = Mesh2::new(String::from(code)).unwrap();
+            assert_eq!(expected, mesh.map_sheet_index(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh2_is_same_cell_ok() {
+        let mesh3 = Mesh3::new(String::from("53393599")).unwrap();
+        let mesh2 = Mesh2::new(mesh3.code()[0..6].to_string()).unwrap();
+        // 一方が他方を含む
+        assert!(mesh3.is_same_cell(&mesh2));
+        assert!(mesh2.is_same_cell(&mesh3));
+        assert!(mesh2.is_same_cell(&Mesh1::new(String::from("5339")).unwrap()));
+        // 同じメッシュ
+        assert!(mesh2.is_same_cell(&Mesh2::new(String::from("533935")).unwrap()));
+    }
+
+    #[test]
+    fn mesh2_is_same_cell_false() {
+        let mesh2 = Mesh2::new(String::from("533935")).unwrap();
+        let mesh3 = Mesh3::new(String::from("53393699")).unwrap();
+        assert!(!mesh2.is_same_cell(&mesh3));
+        assert!(!mesh3.is_same_cell(&mesh2));
+        assert!(!mesh2.is_same_cell(&Mesh2::new(String::from("533936")).unwrap()));
+        assert!(!mesh2.is_same_cell(&Mesh1::new(String::from("5340")).unwrap()));
+    }
+
     #[test]
     fn mesh2_is_neighbor_none() {
         let inputs = vec![
@@ -0,0 +1,269 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use crate::mesh::{
+    cell_index, mesh2::validate_mesh2_code, validate_code_len, Coordinate, GSJPError, Mesh, Mesh2,
+};
+
+/// 2倍地域メッシュの南端と北端の緯度の差
+const MESH2X_LAT_DIFF: f64 = 1.0 / 60.0; // 1分
+/// 2倍地域メッシュの西端と東端の経度の差
+const MESH2X_LON_DIFF: f64 = 1.0 / 60.0 + 30.0 / 3600.0; // 1分30秒
+
+/// 2倍地域メッシュ（統合地域メッシュ）
+///
+/// 第2次地域区画を南北に5等分、東西に5等分した区画を示す。
+/// 2倍地域メッシュは基準地域メッシュを南北に2個、東西に2個まとめた区画で、辺の長さは約2kmである。
+///
+/// 2倍地域メッシュのメッシュコードは、第2次地域区画のメッシュコードに、2倍地域メッシュの南西端にある
+/// 基準地域メッシュの緯度方向と経度方向の番号（0、2、4、6または8）と、`5`を付けた9桁である。
+///
+/// 2倍地域メッシュのメッシュコードは、2分の1地域メッシュのメッシュコードと桁数が同じで、
+/// `MeshLevel`で区別できないため、`Mesh`トレイトを実装しない。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Mesh2x {
+    code: String,
+}
+
+impl Mesh2x {
+    /// 2倍地域メッシュのメッシュコードの長さ
+    pub const CODE_LEN: usize = 9;
+
+    /// 2倍地域メッシュを作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `code` - 2倍地域メッシュのメッシュコード
+    ///
+    /// # 戻り値
+    ///
+    /// 2倍地域メッシュ
+    pub fn new(code: String) -> Result<Self, GSJPError> {
+        validate_mesh2x_code(&code)?;
+
+        Ok(Self { code })
+    }
+
+    /// 座標を含む2倍地域メッシュを返す。
+    ///
+    /// # 引数
+    ///
+    /// * `coord` - 座標
+    ///
+    /// # 戻り値
+    ///
+    /// 座標を含む2倍地域メッシュ
+    pub fn from_coordinate(coord: Coordinate) -> Result<Self, GSJPError> {
+        let mesh2 = Mesh2::from_coordinate(coord)?;
+        let lat_n = cell_index(coord.lat() - mesh2.south(), MESH2X_LAT_DIFF);
+        let lon_n = cell_index(coord.lon() - mesh2.west(), MESH2X_LON_DIFF);
+
+        Self::new(format!("{}{}{}5", mesh2.code(), lat_n * 2, lon_n * 2))
+    }
+
+    /// 2倍地域メッシュのメッシュコードを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 2倍地域メッシュのメッシュコード
+    #[must_use]
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// 2倍地域メッシュを含む第2次地域区画を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 第2次地域区画
+    #[must_use]
+    pub fn mesh2(&self) -> Mesh2 {
+        Mesh2::new(self.code[0..6].to_string()).unwrap()
+    }
+
+    /// 第2次地域区画の南端と西端から数えた、2倍地域メッシュの位置を返す。
+    fn index(&self) -> (u8, u8) {
+        let bytes = self.code.as_bytes();
+
+        ((bytes[6] - b'0') / 2, (bytes[7] - b'0') / 2)
+    }
+
+    /// 2倍地域メッシュの北端の緯度を度単位で返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 2倍地域メッシュの北端の緯度
+    #[must_use]
+    pub fn north(&self) -> f64 {
+        self.south() + MESH2X_LAT_DIFF
+    }
+
+    /// 2倍地域メッシュの東端の経度を度単位で返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 2倍地域メッシュの東端の経度
+    #[must_use]
+    pub fn east(&self) -> f64 {
+        self.west() + MESH2X_LON_DIFF
+    }
+
+    /// 2倍地域メッシュの南端の緯度を度単位で返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 2倍地域メッシュの南端の緯度
+    #[must_use]
+    pub fn south(&self) -> f64 {
+        self.mesh2().south() + MESH2X_LAT_DIFF * self.index().0 as f64
+    }
+
+    /// 2倍地域メッシュの西端の経度を度単位で返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 2倍地域メッシュの西端の経度
+    #[must_use]
+    pub fn west(&self) -> f64 {
+        self.mesh2().west() + MESH2X_LON_DIFF * self.index().1 as f64
+    }
+
+    /// 2倍地域メッシュの中心の座標を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 2倍地域メッシュの中心の座標
+    #[must_use]
+    pub fn center(&self) -> Coordinate {
+        // 2倍地域メッシュは第2次地域区画に含まれるため、中心の座標は常に座標の範囲内にある
+        Coordinate::new_unchecked(
+            (self.north() + self.south()) / 2.0,
+            (self.east() + self.west()) / 2.0,
+        )
+    }
+
+    /// メッシュコードが2倍地域メッシュのメッシュコードとして正しいか確認する。
+    ///
+    /// `new`と同様にメッシュコードを検証するが、2倍地域メッシュを作成しない。
+    ///
+    /// # 引数
+    ///
+    /// * `code` - 2倍地域メッシュのメッシュコード
+    ///
+    /// # 戻り値
+    ///
+    /// 正しい場合は`true`、正しくない場合は`false`
+    #[must_use]
+    pub fn is_valid(code: &str) -> bool {
+        validate_mesh2x_code(code).is_ok()
+    }
+}
+
+/// 2倍地域メッシュのメッシュコードを検証する。
+///
+/// # 引数
+///
+/// * `code` - メッシュコード
+///
+/// # 戻り値
+///
+/// `()`
+fn validate_mesh2x_code(code: &str) -> Result<(), GSJPError> {
+    validate_code_len(code, Mesh2x::CODE_LEN)?;
+    // 上位6桁が第2次地域区画のメッシュコードであることを確認
+    validate_mesh2_code(&code[0..6])?;
+    // 緯度方向と経度方向の番号が偶数の数字で、最後の桁が5であることを確認
+    let bytes = code.as_bytes();
+    let is_even_digit = |b: u8| matches!(b, b'0' | b'2' | b'4' | b'6' | b'8');
+    if !is_even_digit(bytes[6]) || !is_even_digit(bytes[7]) || bytes[8] != b'5' {
+        return Err(GSJPError::InvalidMeshCode);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::tests::eq_f64;
+    use crate::mesh::Mesh3;
+
+    #[test]
+    fn mesh2x_new_ok() {
+        for code in [
+            "533935005",
+            "533935085",
+            "533935805",
+            "533935885",
+            "533935465",
+        ] {
+            assert!(Mesh2x::new(String::from(code)).is_ok(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh2x_new_err() {
+        let codes = [
+            "533935015", // 経度方向の番号が奇数
+            "533935105", // 緯度方向の番号が奇数
+            "533935001", // 最後の桁が5以外
+            "53393500",
+            "5339350055",
+            "533980005", // 第2次地域区画が不正
+            "53393500a",
+        ];
+        for code in codes {
+            assert!(Mesh2x::new(String::from(code)).is_err(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh2x_code_len_ok() {
+        let mesh = Mesh2x::new(String::from("533935005")).unwrap();
+        assert_eq!(9, Mesh2x::CODE_LEN);
+        assert_eq!(mesh.code().len(), Mesh2x::CODE_LEN);
+    }
+
+    #[test]
+    fn mesh2x_is_valid_ok() {
+        for code in ["533935005", "533935885"] {
+            assert!(Mesh2x::is_valid(code), "{}", code);
+            assert!(Mesh2x::new(String::from(code)).is_ok(), "{}", code);
+        }
+        for code in ["533935015", "533935001", "533980005"] {
+            assert!(!Mesh2x::is_valid(code), "{}", code);
+            assert!(Mesh2x::new(String::from(code)).is_err(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh2x_from_coordinate_ok() {
+        // 東京タワー
+        let coord = Coordinate::new(35.658581, 139.745433).unwrap();
+        let mesh = Mesh2x::from_coordinate(coord).unwrap();
+        assert_eq!("533935885", mesh.code());
+        assert_eq!("533935", mesh.mesh2().code());
+        assert!(mesh.south() <= coord.lat() && coord.lat() < mesh.north());
+        assert!(mesh.west() <= coord.lon() && coord.lon() < mesh.east());
+    }
+
+    #[test]
+    fn mesh2x_edges_ok() {
+        // 2倍地域メッシュは、基準地域メッシュを南北に2個、東西に2個まとめた区画
+        let inputs = vec![
+            ("533935005", "53393500", "53393511"),
+            ("533935465", "53393546", "53393557"),
+            ("533935885", "53393588", "53393599"),
+        ];
+        for (code, south_west, north_east) in inputs {
+            let mesh = Mesh2x::new(String::from(code)).unwrap();
+            let south_west = Mesh3::new(String::from(south_west)).unwrap();
+            let north_east = Mesh3::new(String::from(north_east)).unwrap();
+            assert!(eq_f64(south_west.south(), mesh.south()), "{}", code);
+            assert!(eq_f64(south_west.west(), mesh.west()), "{}", code);
+            assert!(eq_f64(north_east.north(), mesh.north()), "{}", code);
+            assert!(eq_f64(north_east.east(), mesh.east()), "{}", code);
+            assert_eq!(mesh, Mesh2x::from_coordinate(mesh.center()).unwrap());
+        }
+    }
+}
@@ -1,5 +1,12 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
 use crate::mesh::mesh2::validate_mesh2_code;
-use crate::mesh::{Coordinate, GSJPError, Mesh, Mesh1, Mesh2};
+use crate::mesh::{
+    ancestor_code, cell_index, code_from_bytes, code_to_bytes, validate_code_len, validate_part,
+    AnyMesh, Coordinate, GSJPError, Mesh, Mesh1, Mesh2, MeshLevel,
+};
 
 /// 地域基準メッシュの南端と北端の緯度の差
 const MESH3_LAT_DIFF: f64 = 30.0 / 3600.0; // 30秒
@@ -10,19 +17,23 @@ const MESH3_LON_DIFF: f64 = 45.0 / 3600.0; // 45秒
 ///
 /// 第2次地域区画を南北に10等分、東西に10等分した区画を示す。
 /// 基準地域メッシュの辺の長さは約1kmである。
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Mesh3 {
     code: String,
 }
 
 impl Mesh3 {
+    /// 基準地域メッシュのメッシュコードの長さ
+    pub const CODE_LEN: usize = MeshLevel::Mesh3.code_len();
+
     /// 基準地域メッシュを含む第1次地域区画を返す。
     ///
     /// # 戻り値
     ///
     /// 第1次地域区画
+    #[must_use]
     pub fn mesh1(&self) -> Mesh1 {
-        Mesh1::new(self.code[0..4].to_string()).unwrap()
+        Mesh1::new(ancestor_code(self, MeshLevel::Mesh1)).unwrap()
     }
 
     /// 基準地域メッシュを含む第2次地域区画を返す。
@@ -30,8 +41,79 @@ impl Mesh3 {
     /// # 戻り値
     ///
     /// 第2次地域区画
+    #[must_use]
     pub fn mesh2(&self) -> Mesh2 {
-        Mesh2::new(self.code[0..6].to_string()).unwrap()
+        Mesh2::new(ancestor_code(self, MeshLevel::Mesh2)).unwrap()
+    }
+
+    /// 基準地域メッシュを含む上位のメッシュを、細かいレベルから順に返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 基準地域メッシュを含む上位のメッシュ。最後の要素は第1次地域区画
+    #[must_use]
+    pub fn ancestors(&self) -> Vec<AnyMesh> {
+        vec![self.mesh2().into(), self.mesh1().into()]
+    }
+
+    /// 第2次地域区画と、第2次地域区画内の南北方向と東西方向の位置から基準地域メッシュを作成する。
+    ///
+    /// 位置は、第2次地域区画の南端と西端から数えて0から9の範囲で指定する。
+    /// `index_in_parent`の逆の変換である。
+    ///
+    /// # 引数
+    ///
+    /// * `mesh2` - 第2次地域区画
+    /// * `lat_idx` - 第2次地域区画内の南北方向の位置
+    /// * `lon_idx` - 第2次地域区画内の東西方向の位置
+    ///
+    /// # 戻り値
+    ///
+    /// 基準地域メッシュ。位置が範囲外の場合はエラー
+    pub fn from_parts(mesh2: &Mesh2, lat_idx: u8, lon_idx: u8) -> Result<Self, GSJPError> {
+        validate_part("南北方向の位置", lat_idx, 0..=9)?;
+        validate_part("東西方向の位置", lon_idx, 0..=9)?;
+
+        Self::new(format!("{}{}{}", mesh2.code(), lat_idx, lon_idx))
+    }
+
+    /// 基準地域メッシュを、4バイトのバイト列に変換する（形式は`code_to_bytes`を参照）。
+    ///
+    /// # 戻り値
+    ///
+    /// 4バイトのバイト列
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 4] {
+        code_to_bytes(self.as_u64())
+    }
+
+    /// `to_bytes`で変換したバイト列から基準地域メッシュを作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `bytes` - ビッグエンディアンで整数のメッシュコードを格納した4バイトのバイト列
+    ///
+    /// # 戻り値
+    ///
+    /// 基準地域メッシュ
+    pub fn from_bytes(bytes: [u8; 4]) -> Result<Self, GSJPError> {
+        Self::from_u64(code_from_bytes(bytes))
+    }
+
+    /// メッシュコードが基準地域メッシュのメッシュコードとして正しいか確認する。
+    ///
+    /// `new`と同様にメッシュコードを検証するが、基準地域メッシュを作成しない。
+    ///
+    /// # 引数
+    ///
+    /// * `code` - 基準地域メッシュのメッシュコード
+    ///
+    /// # 戻り値
+    ///
+    /// 正しい場合は`true`、正しくない場合は`false`
+    #[must_use]
+    pub fn is_valid(code: &str) -> bool {
+        validate_mesh3_code(code).is_ok()
     }
 }
 
@@ -44,8 +126,8 @@ impl Mesh for Mesh3 {
 
     fn from_coordinate(coord: Coordinate) -> Result<Self, GSJPError> {
         let mesh2 = Mesh2::from_coordinate(coord)?;
-        let lat_n = ((coord.lat() - mesh2.south()) / MESH3_LAT_DIFF) as u8;
-        let lon_n = ((coord.lon() - mesh2.west()) / MESH3_LON_DIFF) as u8;
+        let lat_n = cell_index(coord.lat() - mesh2.south(), MESH3_LAT_DIFF);
+        let lon_n = cell_index(coord.lon() - mesh2.west(), MESH3_LON_DIFF);
         let code = format!("{}{}{}", mesh2.code(), lat_n, lon_n);
 
         Self::new(code)
@@ -55,6 +137,10 @@ impl Mesh for Mesh3 {
         &self.code
     }
 
+    fn into_code(self) -> String {
+        self.code
+    }
+
     fn north(&self) -> f64 {
         self.south() + MESH3_LAT_DIFF
     }
@@ -132,19 +218,36 @@ impl Mesh for Mesh3 {
     }
 }
 
+impl PartialEq<str> for Mesh3 {
+    fn eq(&self, other: &str) -> bool {
+        self.code == other
+    }
+}
+
+impl PartialEq<&str> for Mesh3 {
+    fn eq(&self, other: &&str) -> bool {
+        self.code == *other
+    }
+}
+
+impl From<Mesh3> for String {
+    /// メッシュを消費して、メッシュコードを返す。
+    fn from(mesh: Mesh3) -> Self {
+        mesh.code
+    }
+}
+
 pub(crate) fn validate_mesh3_code(code: &str) -> Result<(), GSJPError> {
+    validate_code_len(code, Mesh3::CODE_LEN)?;
     // 上位6桁が第2次地域区画のメッシュコードであることを確認
-    if code.len() != 8 {
-        return Err(GSJPError::InvalidMeshCode);
-    }
     validate_mesh2_code(&code[0..6])?;
     // 基準地域メッシュのメッシュコードの基準地域メッシュ部分について、緯度方向の値と経度方向の値を確認
-    let lat = &code.chars().nth(6).unwrap();
-    if !(&'0'..=&'9').contains(&lat) {
+    let lat = code.as_bytes()[6];
+    if !lat.is_ascii_digit() {
         return Err(GSJPError::InvalidMeshCode);
     }
-    let lon = &code.chars().nth(7).unwrap();
-    if !(&'0'..=&'9').contains(&lon) {
+    let lon = code.as_bytes()[7];
+    if !lon.is_ascii_digit() {
         return Err(GSJPError::InvalidMeshCode);
     }
 
@@ -156,7 +259,58 @@ pub(crate) mod tests {
     use super::*;
     use crate::mesh::mesh2::tests::{mesh2_south, mesh2_west};
     use crate::mesh::tests::{eq_f64, EPSILON};
-    use crate::mesh::{NeighborDirection, EASTERNMOST, NORTHERNMOST, SOUTHERNMOST, WESTERNMOST};
+    use crate::mesh::{
+        EarthModel, MeshLevel, NeighborDirection, BOUNDARY_EPSILON, EASTERNMOST, NORTHERNMOST,
+        SOUTHERNMOST, WESTERNMOST,
+    };
+
+    #[test]
+    fn mesh3_ancestors_ok() {
+        let code = "53393599";
+        let mesh = Mesh3::new(String::from(code)).unwrap();
+        let ancestors = mesh.ancestors();
+        let expected = [&code[0..6], &code[0..4]];
+        assert_eq!(
+            expected.to_vec(),
+            ancestors.iter().map(|mesh| mesh.code()).collect::<Vec<_>>()
+        );
+        assert_eq!(MeshLevel::Mesh1, ancestors.last().unwrap().level());
+    }
+
+    #[test]
+    fn mesh3_from_parts_ok() {
+        // 基準地域メッシュ53393599内の座標
+        let coord = Coordinate::new(35.6625, 139.74).unwrap();
+        let expected = Mesh3::from_coordinate(coord).unwrap();
+        let mesh1 = Mesh1::new(String::from("5339")).unwrap();
+        let mesh2 = Mesh2::from_parts(&mesh1, 3, 5).unwrap();
+        let mesh = Mesh3::from_parts(&mesh2, 9, 9).unwrap();
+        assert_eq!(expected, mesh);
+        assert_eq!("53393599", mesh.code());
+        assert!(eq_f64(expected.north(), mesh.north()));
+        assert!(eq_f64(expected.east(), mesh.east()));
+        assert!(eq_f64(expected.south(), mesh.south()));
+        assert!(eq_f64(expected.west(), mesh.west()));
+        // 上位のメッシュ内の位置から作成したメッシュは、元のメッシュと一致する
+        let (lat_idx, lon_idx) = expected.index_in_parent();
+        assert_eq!(
+            expected,
+            Mesh3::from_parts(&expected.mesh2(), lat_idx, lon_idx).unwrap()
+        );
+    }
+
+    #[test]
+    fn mesh3_from_parts_err() {
+        let mesh2 = Mesh2::new(String::from("533935")).unwrap();
+        assert!(matches!(
+            Mesh3::from_parts(&mesh2, 10, 0),
+            Err(GSJPError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            Mesh3::from_parts(&mesh2, 0, 10),
+            Err(GSJPError::OutOfRange(_))
+        ));
+    }
 
     #[test]
     fn mesh3_new_ok() {
@@ -167,15 +321,73 @@ pub(crate) mod tests {
     }
 
     #[test]
-    fn mesh3_new_err() {
-        assert!(Mesh3::new(String::from("72490709")).is_err(), "72490709");
-        assert!(Mesh3::new(String::from("71507090")).is_err(), "71507090");
-        assert!(Mesh3::new(String::from("29497799")).is_err(), "29497799");
-        assert!(Mesh3::new(String::from("30500000")).is_err(), "30500000");
-        assert!(Mesh3::new(String::from("29187090")).is_err(), "29187090");
-        assert!(Mesh3::new(String::from("30170709")).is_err(), "30170709");
-        assert!(Mesh3::new(String::from("72180000")).is_err(), "72180000");
-        assert!(Mesh3::new(String::from("71170709")).is_err(), "71170709");
+    fn mesh3_is_valid_ok() {
+        for code in ["53393599", "30180000"] {
+            assert!(Mesh3::is_valid(code), "{}", code);
+            assert!(Mesh3::new(String::from(code)).is_ok(), "{}", code);
+        }
+        for code in ["53393a99", "53398099", "5339359", "533935990"] {
+            assert!(!Mesh3::is_valid(code), "{}", code);
+            assert!(Mesh3::new(String::from(code)).is_err(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh3_bytes_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        assert_eq!([0x03, 0x2E, 0xB8, 0xBF], mesh.to_bytes());
+        // 途中に0を含むメッシュコード
+        let codes = ["30180000", "53393599", "71497799"];
+        for code in codes {
+            let mesh = Mesh3::new(code.to_string()).unwrap();
+            assert_eq!(
+                mesh,
+                Mesh3::from_bytes(mesh.to_bytes()).unwrap(),
+                "{}",
+                code
+            );
+        }
+        // バイト列はメッシュコードの順番に並ぶ
+        let bytes = codes.map(|code| Mesh3::new(code.to_string()).unwrap().to_bytes());
+        assert!(bytes.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn mesh3_from_bytes_err() {
+        assert!(Mesh3::from_bytes([0; 4]).is_err());
+        assert!(Mesh3::from_bytes([0xFF; 4]).is_err());
+    }
+
+    #[test]
+    fn mesh3_into_string_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        // `mesh`はムーブされるため、以降で使用できない
+        let code = String::from(mesh);
+        assert_eq!("53393599", code);
+    }
+
+    #[test]
+    fn mesh3_code_len_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        assert_eq!(8, Mesh3::CODE_LEN);
+        assert_eq!(mesh.code().len(), Mesh3::CODE_LEN);
+        assert_eq!(MeshLevel::Mesh3.code_len(), Mesh3::CODE_LEN);
+    }
+
+    #[test]
+    fn mesh3_new_rejects_full_width_digits() {
+        // 全角数字を含むメッシュコード（先頭はバイト数が8で、文字の境界が区切りと一致しない）
+        let codes = ["53393５", "5339３599", "53393599５", "５３３９３５９９"];
+        for code in codes {
+            assert!(
+                matches!(
+                    Mesh3::new(String::from(code)),
+                    Err(GSJPError::InvalidMeshCode)
+                ),
+                "{}",
+                code
+            );
+        }
     }
 
     #[test]
@@ -296,6 +508,23 @@ pub(crate) mod tests {
         }
     }
 
+    #[test]
+    fn mesh3_from_coordinate_err_message() {
+        let inputs = vec![
+            (Coordinate::new(35.0, WESTERNMOST - 1.0).unwrap(), "経度"),
+            (Coordinate::new(35.0, EASTERNMOST + 1.0).unwrap(), "経度"),
+            (Coordinate::new(SOUTHERNMOST - 1.0, 139.0).unwrap(), "緯度"),
+        ];
+        for (coord, expected) in inputs {
+            match Mesh3::from_coordinate(coord) {
+                Err(GSJPError::OutOfRange(message)) => {
+                    assert!(message.starts_with(expected), "{}", message)
+                }
+                result => panic!("{:?}", result),
+            }
+        }
+    }
+
     fn mesh3_south(code: &str) -> f64 {
         mesh2_south(code)
             + MESH3_LAT_DIFF * code.chars().nth(6).unwrap().to_digit(10).unwrap() as f64
@@ -306,6 +535,12 @@ pub(crate) mod tests {
             + MESH3_LON_DIFF * code.chars().nth(7).unwrap().to_digit(10).unwrap() as f64
     }
 
+    #[test]
+    fn mesh3_code_formatted_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        assert_eq!("5339-35-99", mesh.code_formatted());
+    }
+
     #[test]
     fn mesh3_north_ok() {
         let code = "51354637";
@@ -463,6 +698,419 @@ pub(crate) mod tests {
         );
     }
 
+    #[test]
+    fn mesh3_from_coordinate_north_edge_ok() {
+        let codes = vec!["53393599", "53394509", "51354637", "30180000", "68483375"];
+        for code in codes {
+            let mesh = Mesh3::new(code.to_string()).unwrap();
+            let coord = Coordinate::new(mesh.north(), mesh.center().lon()).unwrap();
+            let actual = Mesh3::from_coordinate(coord).unwrap();
+            assert_eq!(mesh.north_mesh().unwrap(), actual, "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh3_from_coordinate_east_edge_ok() {
+        let codes = vec!["53393599", "53394509", "51354637", "30180000", "68483375"];
+        for code in codes {
+            let mesh = Mesh3::new(code.to_string()).unwrap();
+            let coord = Coordinate::new(mesh.center().lat(), mesh.east()).unwrap();
+            let actual = Mesh3::from_coordinate(coord).unwrap();
+            assert_eq!(mesh.east_mesh().unwrap(), actual, "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh3_from_coordinate_south_west_edge_ok() {
+        let codes = vec!["53393599", "53394509", "51354637", "30180000", "68483375"];
+        for code in codes {
+            let mesh = Mesh3::new(code.to_string()).unwrap();
+            let actual = Mesh3::from_coordinate(mesh.south_west()).unwrap();
+            assert_eq!(mesh, actual, "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh3_from_coordinate_near_edge_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let lon = mesh.center().lon();
+        let lat = mesh.center().lat();
+        let north = mesh.north_mesh().unwrap();
+        let east = mesh.east_mesh().unwrap();
+        let inputs = vec![
+            // 境界上の座標は北側のメッシュ
+            (mesh.north(), lon, &north),
+            // 境界の南側で`BOUNDARY_EPSILON`以内の座標は、境界上にあるとみなす
+            (mesh.north() - BOUNDARY_EPSILON / 2.0, lon, &north),
+            (lat, mesh.east() - BOUNDARY_EPSILON / 2.0, &east),
+            // 境界の南側で`BOUNDARY_EPSILON`より離れた座標は、南側のメッシュ
+            (mesh.north() - 1e-8, lon, &mesh),
+            (lat, mesh.east() - 1e-8, &mesh),
+            // 境界の北側の座標は、北側のメッシュ
+            (mesh.north() + 1e-8, lon, &north),
+        ];
+        for (lat, lon, expected) in inputs {
+            let coord = Coordinate::new(lat, lon).unwrap();
+            assert_eq!(
+                expected,
+                &Mesh3::from_coordinate(coord).unwrap(),
+                "{:?}",
+                coord
+            );
+        }
+    }
+
+    #[test]
+    fn mesh3_from_coordinate_eps_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let lon = mesh.center().lon();
+        let lat = mesh.center().lat();
+        let north = mesh.north_mesh().unwrap();
+        let east = mesh.east_mesh().unwrap();
+        let inputs = vec![
+            // 境界上の座標は北側のメッシュ
+            (mesh.north(), lon, &north),
+            // 境界の南側で許容誤差以内の座標は、北側または東側のメッシュ
+            (mesh.north() - 1e-8, lon, &north),
+            (lat, mesh.east() - 1e-8, &east),
+            // 境界の南側で許容誤差より離れた座標は、南側のメッシュ
+            (mesh.north() - 1e-6, lon, &mesh),
+            (lat, mesh.east() - 1e-6, &mesh),
+            // 南西端の近くの座標は、メッシュに含まれる
+            (mesh.south(), mesh.west(), &mesh),
+            (mesh.south() + 1e-8, mesh.west() + 1e-8, &mesh),
+        ];
+        for (lat, lon, expected) in inputs {
+            let coord = Coordinate::new(lat, lon).unwrap();
+            assert_eq!(
+                expected,
+                &Mesh3::from_coordinate_eps(coord, 1e-7).unwrap(),
+                "{:?}",
+                coord
+            );
+        }
+        // 許容誤差が`BOUNDARY_EPSILON`以下の場合は、`from_coordinate`と同じ
+        let coord = Coordinate::new(mesh.north() - 1e-8, lon).unwrap();
+        assert_eq!(mesh, Mesh3::from_coordinate_eps(coord, 0.0).unwrap());
+        // 範囲外の座標
+        let coord = Coordinate::new(10.0, lon).unwrap();
+        assert!(Mesh3::from_coordinate_eps(coord, 1e-7).is_err());
+    }
+
+    #[test]
+    fn mesh3_from_coordinate_eps_national_edge_ok() {
+        // 全国の範囲の北端と東端の近くの座標は、許容誤差以内でも範囲内のメッシュに含める
+        let coord = Coordinate::new(NORTHERNMOST - 5e-7, EASTERNMOST - 5e-7).unwrap();
+        let expected = Mesh3::from_coordinate(coord).unwrap();
+        assert_eq!("71497799", expected.code());
+        assert_eq!(expected, Mesh3::from_coordinate_eps(coord, 1e-6).unwrap());
+        // 北端の近くでも、東側の境界には許容誤差を適用する
+        let mesh = Mesh3::new(String::from("71497798")).unwrap();
+        let coord = Coordinate::new(NORTHERNMOST - 5e-7, mesh.east() - 5e-7).unwrap();
+        assert_eq!(
+            mesh.east_mesh().unwrap(),
+            Mesh3::from_coordinate_eps(coord, 1e-6).unwrap()
+        );
+    }
+
+    #[test]
+    fn mesh3_from_coordinate_eps_floor_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let north = mesh.north_mesh().unwrap();
+        let lon = mesh.center().lon();
+        let inputs = vec![
+            // `BOUNDARY_EPSILON`以内の座標は、許容誤差によらず北側のメッシュ
+            (mesh.north() - BOUNDARY_EPSILON / 2.0, &north),
+            // `BOUNDARY_EPSILON`より離れた座標は、南側のメッシュ
+            (mesh.north() - BOUNDARY_EPSILON * 10.0, &mesh),
+        ];
+        for eps in [0.0, BOUNDARY_EPSILON / 2.0, BOUNDARY_EPSILON] {
+            for (lat, expected) in &inputs {
+                let coord = Coordinate::new(*lat, lon).unwrap();
+                let actual = Mesh3::from_coordinate_eps(coord, eps).unwrap();
+                assert_eq!(*expected, &actual, "eps: {}, {:?}", eps, coord);
+                assert_eq!(Mesh3::from_coordinate(coord).unwrap(), actual);
+            }
+        }
+    }
+
+    #[test]
+    fn mesh3_new_lenient_ok() {
+        let codes = vec![
+            "53393599",
+            " 53393599 ",
+            "\t53393599\r\n",
+            "m53393599",
+            "M53393599",
+            "  m53393599 ",
+        ];
+        for code in codes {
+            let mesh = Mesh3::new_lenient(code);
+            assert!(mesh.is_ok(), "{:?}", code);
+            assert_eq!("53393599", mesh.unwrap().code(), "{:?}", code);
+        }
+    }
+
+    #[test]
+    fn mesh3_new_lenient_err() {
+        let codes = vec![
+            "",
+            "   ",
+            "mm53393599",
+            "m 53393599",
+            "5339 3599",
+            "53393599m",
+            "-53393599",
+            "m5339359",
+        ];
+        for code in codes {
+            assert!(Mesh3::new_lenient(code).is_err(), "{:?}", code);
+        }
+    }
+
+    #[test]
+    fn mesh3_eq_str_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        assert!(mesh == "53393599");
+        assert!(mesh == *"53393599");
+        assert_eq!(mesh, "53393599");
+        assert!(mesh != "53393598");
+        assert!(mesh != *"5339359");
+        assert_ne!(mesh, "533935991");
+    }
+
+    #[test]
+    fn mesh3_corners_ok() {
+        let mesh = Mesh3::new(String::from("51354637")).unwrap();
+        let corners = mesh.corners();
+        assert_eq!(
+            [
+                mesh.north_west(),
+                mesh.north_east(),
+                mesh.south_east(),
+                mesh.south_west()
+            ],
+            corners
+        );
+        // 最初の座標を末尾に追加すると、各辺が東西または南北の辺となる閉じたリングになる
+        let mut ring = corners.to_vec();
+        ring.push(corners[0]);
+        for pair in ring.windows(2) {
+            assert!(
+                eq_f64(pair[0].lat(), pair[1].lat()) || eq_f64(pair[0].lon(), pair[1].lon()),
+                "{:?}",
+                pair
+            );
+        }
+        assert_eq!(ring.first(), ring.last());
+    }
+
+    #[test]
+    fn mesh3_distance_to_edge_m_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        // 基準地域メッシュは東西方向より南北方向が短いため、中心から最も近い辺は北端と南端
+        let expected = mesh.width_m().min(mesh.height_m()) / 2.0;
+        let actual = mesh.distance_to_edge_m(&mesh.center());
+        assert!(
+            (expected - actual).abs() < 1e-6,
+            "expected: {}, actual: {}",
+            expected,
+            actual
+        );
+        // 辺上の座標
+        assert!(mesh.distance_to_edge_m(&mesh.south_west()).abs() < 1e-6);
+        // メッシュの外部の座標
+        let coord = Coordinate::new(mesh.north() + 0.01, mesh.east() + 0.01).unwrap();
+        assert_eq!(0.0, mesh.distance_to_edge_m(&coord));
+    }
+
+    #[test]
+    fn mesh3_offset_m_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        // メッシュの幅または高さだけ移動すると、隣のメッシュに移る
+        let (width, height) = (mesh.width_m(), mesh.height_m());
+        assert_eq!(
+            mesh.east_mesh().unwrap(),
+            mesh.offset_m(0.0, width).unwrap()
+        );
+        assert_eq!(
+            mesh.west_mesh().unwrap(),
+            mesh.offset_m(0.0, -width).unwrap()
+        );
+        assert_eq!(
+            mesh.north_mesh().unwrap(),
+            mesh.offset_m(height, 0.0).unwrap()
+        );
+        assert_eq!(
+            mesh.south_mesh().unwrap(),
+            mesh.offset_m(-height, 0.0).unwrap()
+        );
+        assert_eq!(
+            mesh.north_east_mesh().unwrap(),
+            mesh.offset_m(height, width).unwrap()
+        );
+        // メッシュの幅または高さの半分未満の移動では、同じメッシュのまま
+        assert_eq!(mesh, mesh.offset_m(0.0, 0.0).unwrap());
+        assert_eq!(mesh, mesh.offset_m(height * 0.4, -width * 0.4).unwrap());
+        // 約10km北東のメッシュ
+        let offset = mesh.offset_m(10_000.0, 10_000.0).unwrap();
+        let distance = mesh
+            .center()
+            .distance_m(&offset.center(), EarthModel::default());
+        assert!(
+            (distance - 10_000.0 * 2.0_f64.sqrt()).abs() < mesh.width_m(),
+            "{}",
+            distance
+        );
+    }
+
+    #[test]
+    fn mesh3_offset_m_err() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        // 標準地域メッシュの範囲外
+        assert!(mesh.offset_m(-2_000_000.0, 0.0).is_err());
+        assert!(mesh.offset_m(0.0, 2_000_000.0).is_err());
+        // 緯度が-90度から90度の範囲外
+        assert!(mesh.offset_m(10_000_000.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn mesh3_normalized_position_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let inputs = vec![
+            (mesh.center(), (0.5, 0.5), "中心"),
+            (mesh.south_west(), (0.0, 0.0), "南西端"),
+        ];
+        for (coord, (x, y), name) in inputs {
+            let (actual_x, actual_y) = mesh.normalized_position(&coord);
+            assert!(eq_f64(x, actual_x), "{}: {}", name, actual_x);
+            assert!(eq_f64(y, actual_y), "{}: {}", name, actual_y);
+        }
+        // 北東端のわずかに内側
+        let coord = Coordinate::new(mesh.north() - EPSILON, mesh.east() - EPSILON).unwrap();
+        let (x, y) = mesh.normalized_position(&coord);
+        assert!((0.99..1.0).contains(&x), "{}", x);
+        assert!((0.99..1.0).contains(&y), "{}", y);
+    }
+
+    #[test]
+    fn mesh3_normalized_position_outside() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let (x, y) = mesh.normalized_position(&mesh.north_east());
+        assert!(eq_f64(1.0, x), "{}", x);
+        assert!(eq_f64(1.0, y), "{}", y);
+        let coord = Coordinate::new(mesh.south() - 0.01, mesh.west() - 0.01).unwrap();
+        let (x, y) = mesh.normalized_position(&coord);
+        assert!(x < 0.0, "{}", x);
+        assert!(y < 0.0, "{}", y);
+    }
+
+    #[test]
+    fn mesh3_info_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let info = mesh.info();
+        assert_eq!(mesh.code(), info.code);
+        assert!(eq_f64(mesh.north(), info.north));
+        assert!(eq_f64(mesh.south(), info.south));
+        assert!(eq_f64(mesh.west(), info.west));
+        assert!(eq_f64(mesh.east(), info.east));
+        let center = mesh.center();
+        assert!(eq_f64(center.lat(), (info.north + info.south) / 2.0));
+        assert!(eq_f64(center.lon(), (info.east + info.west) / 2.0));
+        assert_eq!(mesh, Mesh3::from_info(&info).unwrap());
+    }
+
+    #[test]
+    fn mesh3_describe_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let description = mesh.describe();
+        assert!(description.contains("53393599"), "{}", description);
+        assert!(description.contains("Mesh3"), "{}", description);
+        for value in [mesh.north(), mesh.south(), mesh.east(), mesh.west()] {
+            let value = format!("{}", value);
+            assert!(description.contains(&value), "{}: {}", value, description);
+        }
+        assert_eq!(9, description.lines().count());
+    }
+
+    #[test]
+    fn mesh3_into_code_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let code = mesh.code().to_string();
+        assert_eq!(code, mesh.clone().into_code());
+        assert_eq!(mesh.info(), mesh.clone().into_info());
+        assert_eq!(code, AnyMesh::from(mesh).into_code());
+    }
+
+    #[test]
+    fn mesh3_center_xy_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let center = mesh.center();
+        // 経度をX座標、緯度をY座標とし、原点を移動する変換
+        let (x, y) = mesh.center_xy(|coord| (coord.lon() - 139.0, coord.lat() - 35.0));
+        assert!(eq_f64(center.lon() - 139.0, x), "{}", x);
+        assert!(eq_f64(center.lat() - 35.0, y), "{}", y);
+    }
+
+    #[test]
+    fn mesh3_index_in_parent_ok() {
+        let inputs = vec![
+            ("53393500", (0, 0)), // 南西端
+            ("53393509", (0, 9)), // 南東端
+            ("53393590", (9, 0)), // 北西端
+            ("53393599", (9, 9)), // 北東端
+            ("53393547", (4, 7)),
+        ];
+        for (code, expected) in inputs {
+            let mesh = Mesh3::new(String::from(code)).unwrap();
+            assert_eq!(expected, mesh.index_in_parent(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn index_in_parent_other_levels_ok() {
+        use crate::mesh::{Mesh1, Mesh4, Mesh6};
+
+        assert_eq!(
+            (0, 0),
+            Mesh1::new(String::from("3018")).unwrap().index_in_parent()
+        );
+        assert_eq!(
+            (41, 31),
+            Mesh1::new(String::from("7149")).unwrap().index_in_parent()
+        );
+        assert_eq!(
+            (3, 5),
+            Mesh2::new(String::from("533935"))
+                .unwrap()
+                .index_in_parent()
+        );
+        let inputs = vec![
+            ("533935991", (0, 0)),
+            ("533935992", (0, 1)),
+            ("533935993", (1, 0)),
+            ("533935994", (1, 1)),
+        ];
+        for (code, expected) in inputs {
+            let mesh = Mesh4::new(String::from(code)).unwrap();
+            assert_eq!(expected, mesh.index_in_parent(), "{}", code);
+        }
+        assert_eq!(
+            (1, 0),
+            Mesh6::new(String::from("53393599413"))
+                .unwrap()
+                .index_in_parent()
+        );
+    }
+
+    #[test]
+    fn mesh3_area_m2_ok() {
+        // 東京タワーを含む基準地域メッシュの面積は約1平方km
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let area = mesh.area_m2();
+        assert!((0.9e6..1.2e6).contains(&area), "actual: {}", area);
+    }
+
     #[test]
     #[rustfmt::skip]
     fn mesh3_north_mesh_ok() {
@@ -497,6 +1145,31 @@ pub(crate) mod tests {
         }
     }
 
+    #[test]
+    fn mesh3_neighbor_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        assert_eq!(
+            mesh.east_mesh().unwrap(),
+            mesh.neighbor(NeighborDirection::East).unwrap()
+        );
+        assert_eq!(
+            mesh.north_mesh().unwrap(),
+            mesh.neighbor(NeighborDirection::North).unwrap()
+        );
+        assert_eq!(
+            mesh.south_mesh().unwrap(),
+            mesh.neighbor(NeighborDirection::South).unwrap()
+        );
+        assert_eq!(
+            mesh.west_mesh().unwrap(),
+            mesh.neighbor(NeighborDirection::West).unwrap()
+        );
+        assert_eq!(mesh, mesh.neighbor(NeighborDirection::None).unwrap());
+        // 範囲外のメッシュ
+        let mesh = Mesh3::new(String::from("30180000")).unwrap();
+        assert!(mesh.neighbor(NeighborDirection::South).is_err());
+    }
+
     #[test]
     fn mesh3_east_mesh_err() {
         let mesh = Mesh3::new(String::from("30490709")).unwrap();
@@ -543,6 +1216,55 @@ pub(crate) mod tests {
         assert!(mesh.west_mesh().is_err());
     }
 
+    #[test]
+    fn mesh3_mesh_opt_none() {
+        // 最北東端
+        let mesh = Mesh3::new(String::from("71497799")).unwrap();
+        assert!(mesh.north_mesh_opt().is_none());
+        assert!(mesh.east_mesh_opt().is_none());
+        assert_eq!("71497798", mesh.west_mesh_opt().unwrap().code());
+        // 最南西端
+        let mesh = Mesh3::new(String::from("30180000")).unwrap();
+        assert!(mesh.south_mesh_opt().is_none());
+        assert!(mesh.west_mesh_opt().is_none());
+        assert_eq!("30180010", mesh.north_mesh_opt().unwrap().code());
+    }
+
+    #[test]
+    fn mesh3_north_mesh_opt_crosses_mesh1() {
+        // 第1次地域区画の北端にあるが、全国の範囲の北端ではない
+        let mesh = Mesh3::new(String::from("53397799")).unwrap();
+        assert_eq!("54390709", mesh.north_mesh_opt().unwrap().code());
+    }
+
+    #[test]
+    fn mesh3_offset_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        assert_eq!(mesh, mesh.offset(0, 0).unwrap());
+        assert_eq!(mesh.east_mesh().unwrap(), mesh.offset(1, 0).unwrap());
+        let expected = mesh
+            .south_mesh()
+            .unwrap()
+            .south_mesh()
+            .unwrap()
+            .south_mesh()
+            .unwrap();
+        assert_eq!(expected, mesh.offset(0, -3).unwrap());
+        assert_eq!(mesh.north_west_mesh().unwrap(), mesh.offset(-1, 1).unwrap());
+        // 第1次地域区画を越えて移動
+        assert_eq!("53403090", mesh.offset(21, 0).unwrap().code());
+    }
+
+    #[test]
+    fn mesh3_offset_err() {
+        let mesh = Mesh3::new(String::from("71497799")).unwrap();
+        assert!(mesh.offset(0, 1).is_err());
+        assert!(mesh.offset(1, 0).is_err());
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        assert!(mesh.offset(0, i64::MIN).is_err());
+        assert!(mesh.offset(i64::MAX, 0).is_err());
+    }
+
     #[test]
     fn mesh3_is_neighbor_ok() {
         let inputs = vec![
@@ -569,6 +1291,20 @@ pub(crate) mod tests {
         }
     }
 
+    #[test]
+    fn mesh3_quadkey_none() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        assert!(mesh.quadkey().is_none());
+    }
+
+    #[test]
+    fn mesh3_to_level_err() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        for level in [MeshLevel::Mesh4, MeshLevel::Mesh5, MeshLevel::Mesh6] {
+            assert!(mesh.to_level(level).is_err(), "{:?}", level);
+        }
+    }
+
     #[test]
     fn mesh3_is_neighbor_none() {
         let inputs = vec![
@@ -593,4 +1329,73 @@ pub(crate) mod tests {
             );
         }
     }
+
+    #[test]
+    fn mesh3_subdivide_to_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let inputs = vec![
+            (MeshLevel::Mesh4, 4),
+            (MeshLevel::Mesh5, 4 * 4),
+            (MeshLevel::Mesh6, 4 * 4 * 4),
+        ];
+        for (level, count) in inputs {
+            let children = mesh.subdivide_to(level).unwrap();
+            assert_eq!(count, children.len(), "{:?}", level);
+            let codes = children
+                .iter()
+                .map(|child| child.code())
+                .collect::<std::collections::HashSet<_>>();
+            assert_eq!(count, codes.len(), "{:?}", level);
+            for child in children {
+                assert_eq!(level, child.level());
+                assert!(mesh.contains_mesh(&child), "{}", child.code());
+            }
+        }
+    }
+
+    #[test]
+    fn mesh3_children_to_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        for (level, count) in [
+            (MeshLevel::Mesh4, 4),
+            (MeshLevel::Mesh5, 4 * 4),
+            (MeshLevel::Mesh6, 4 * 4 * 4),
+        ] {
+            assert_eq!(count, mesh.children_to(level).count(), "{:?}", level);
+            // 最初のメッシュは南西端のメッシュ
+            let first = mesh.children_to(level).next().unwrap();
+            assert!(first.south_west().approx_eq(&mesh.south_west(), EPSILON));
+            // subdivide_toと同じ順番
+            assert!(mesh
+                .children_to(level)
+                .eq(mesh.subdivide_to(level).unwrap()));
+        }
+        assert_eq!(
+            "53393599111",
+            mesh.children_to(MeshLevel::Mesh6).next().unwrap().code()
+        );
+        // 第1次地域区画から基準地域メッシュまで分割
+        let mesh1 = mesh.mesh1();
+        let mut children = mesh1.children_to(MeshLevel::Mesh3);
+        assert_eq!("53390000", children.next().unwrap().code());
+        assert_eq!("53390001", children.next().unwrap().code());
+        assert_eq!("53397799", children.last().unwrap().code());
+        assert_eq!(6400, mesh1.children_to(MeshLevel::Mesh3).count());
+        // メッシュより細かくないレベル
+        for level in [MeshLevel::Mesh1, MeshLevel::Mesh2, MeshLevel::Mesh3] {
+            assert_eq!(0, mesh.children_to(level).count(), "{:?}", level);
+        }
+    }
+
+    #[test]
+    fn mesh3_subdivide_to_err() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        for level in [MeshLevel::Mesh1, MeshLevel::Mesh2, MeshLevel::Mesh3] {
+            assert!(
+                matches!(mesh.subdivide_to(level), Err(GSJPError::InvalidMeshLevel)),
+                "{:?}",
+                level
+            );
+        }
+    }
 }
@@ -1,5 +1,13 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
 use crate::mesh::mesh3::validate_mesh3_code;
-use crate::mesh::{Coordinate, GSJPError, Mesh, Mesh1, Mesh2, Mesh3};
+use crate::mesh::{
+    ancestor_code, code_from_bytes, code_to_bytes, division_diagonal_code, from_alternate_code,
+    quadrant_of, to_alternate_code, validate_code_len, validate_part, AnyMesh, Coordinate,
+    GSJPError, Mesh, Mesh1, Mesh2, Mesh3, MeshLevel,
+};
 
 /// 2分の１地域メッシュの南端と北端の緯度の差
 const MESH4_LAT_DIFF: f64 = 15.0 / 3600.0; // 15秒
@@ -10,19 +18,33 @@ const MESH4_LON_DIFF: f64 = 22.5 / 3600.0; // 22.5秒
 ///
 /// 標準地域メッシュを南北に2等分、東西に2等分した区画を示す。
 /// 2分の1地域メッシュの辺の長さは約500mである。
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Mesh4 {
     code: String,
 }
 
 impl Mesh4 {
+    /// 2分の1地域メッシュのメッシュコードの長さ
+    pub const CODE_LEN: usize = MeshLevel::Mesh4.code_len();
+
+    /// 2分の1地域メッシュの分割番号を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 分割番号（1から4）
+    fn quadrant(&self) -> u8 {
+        // メッシュコードは検証済みであるため、最後の文字は1から4の数字である
+        self.code.as_bytes()[Self::CODE_LEN - 1] - b'0'
+    }
+
     /// 2分の1地域メッシュを含む第1次地域区画を返す。
     ///
     /// # 戻り値
     ///
     /// 第1次地域区画
+    #[must_use]
     pub fn mesh1(&self) -> Mesh1 {
-        Mesh1::new(self.code[0..4].to_string()).unwrap()
+        Mesh1::new(ancestor_code(self, MeshLevel::Mesh1)).unwrap()
     }
 
     /// 2分の1地域メッシュを含む第2次地域区画を返す。
@@ -30,8 +52,9 @@ impl Mesh4 {
     /// # 戻り値
     ///
     /// 第2次地域区画
+    #[must_use]
     pub fn mesh2(&self) -> Mesh2 {
-        Mesh2::new(self.code[0..6].to_string()).unwrap()
+        Mesh2::new(ancestor_code(self, MeshLevel::Mesh2)).unwrap()
     }
 
     /// 2分の1地域メッシュを含む基準地域メッシュを返す。
@@ -39,8 +62,103 @@ impl Mesh4 {
     /// # 戻り値
     ///
     /// 基準地域メッシュ
+    #[must_use]
     pub fn mesh3(&self) -> Mesh3 {
-        Mesh3::new(self.code[0..8].to_string()).unwrap()
+        Mesh3::new(ancestor_code(self, MeshLevel::Mesh3)).unwrap()
+    }
+
+    /// 2分の1地域メッシュを含む上位のメッシュを、細かいレベルから順に返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 2分の1地域メッシュを含む上位のメッシュ。最後の要素は第1次地域区画
+    #[must_use]
+    pub fn ancestors(&self) -> Vec<AnyMesh> {
+        vec![
+            self.mesh3().into(),
+            self.mesh2().into(),
+            self.mesh1().into(),
+        ]
+    }
+
+    /// 2分の1地域メッシュのメッシュコードを、代替の番号体系のメッシュコードに変換する。
+    ///
+    /// # 戻り値
+    ///
+    /// 代替の番号体系のメッシュコード
+    #[must_use]
+    pub fn to_alternate_code(&self) -> String {
+        to_alternate_code(&self.code)
+    }
+
+    /// 代替の番号体系のメッシュコードから2分の1地域メッシュを作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `code` - 代替の番号体系のメッシュコード
+    ///
+    /// # 戻り値
+    ///
+    /// 2分の1地域メッシュ
+    pub fn from_alternate_code(code: &str) -> Result<Self, GSJPError> {
+        Self::new(from_alternate_code(code, MeshLevel::Mesh4)?)
+    }
+
+    /// 基準地域メッシュと分割番号から2分の1地域メッシュを作成する。
+    ///
+    /// 分割番号は、基準地域メッシュを南北と東西に2等分した区画を、南西から1、南東が2、北西が3、北東が4で指定する。
+    ///
+    /// # 引数
+    ///
+    /// * `mesh3` - 基準地域メッシュ
+    /// * `quadrant` - 分割番号（1から4）
+    ///
+    /// # 戻り値
+    ///
+    /// 2分の1地域メッシュ。分割番号が範囲外の場合はエラー
+    pub fn from_parts(mesh3: &Mesh3, quadrant: u8) -> Result<Self, GSJPError> {
+        validate_part("分割番号", quadrant, 1..=4)?;
+
+        Self::new(format!("{}{}", mesh3.code(), quadrant))
+    }
+
+    /// 2分の1地域メッシュを、4バイトのバイト列に変換する（形式は`code_to_bytes`を参照）。
+    ///
+    /// # 戻り値
+    ///
+    /// 4バイトのバイト列
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 4] {
+        code_to_bytes(self.as_u64())
+    }
+
+    /// `to_bytes`で変換したバイト列から2分の1地域メッシュを作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `bytes` - ビッグエンディアンで整数のメッシュコードを格納した4バイトのバイト列
+    ///
+    /// # 戻り値
+    ///
+    /// 2分の1地域メッシュ
+    pub fn from_bytes(bytes: [u8; 4]) -> Result<Self, GSJPError> {
+        Self::from_u64(code_from_bytes(bytes))
+    }
+
+    /// メッシュコードが2分の1地域メッシュのメッシュコードとして正しいか確認する。
+    ///
+    /// `new`と同様にメッシュコードを検証するが、2分の1地域メッシュを作成しない。
+    ///
+    /// # 引数
+    ///
+    /// * `code` - 2分の1地域メッシュのメッシュコード
+    ///
+    /// # 戻り値
+    ///
+    /// 正しい場合は`true`、正しくない場合は`false`
+    #[must_use]
+    pub fn is_valid(code: &str) -> bool {
+        validate_mesh4_code(code).is_ok()
     }
 }
 
@@ -53,9 +171,13 @@ impl Mesh for Mesh4 {
 
     fn from_coordinate(coord: Coordinate) -> Result<Self, GSJPError> {
         let mesh3 = Mesh3::from_coordinate(coord)?;
-        let lat_n = ((coord.lat() - mesh3.south()) / MESH4_LAT_DIFF).floor() as u8;
-        let lon_n = ((coord.lon() - mesh3.west()) / MESH4_LON_DIFF).floor() as u8;
-        let num = 2 * lat_n + 1 + lon_n;
+        let num = quadrant_of(
+            mesh3.south(),
+            mesh3.west(),
+            MESH4_LAT_DIFF,
+            MESH4_LON_DIFF,
+            &coord,
+        );
         let code = format!("{}{}", mesh3.code(), num);
 
         Self::new(code)
@@ -65,6 +187,10 @@ impl Mesh for Mesh4 {
         &self.code
     }
 
+    fn into_code(self) -> String {
+        self.code
+    }
+
     fn north(&self) -> f64 {
         self.south() + MESH4_LAT_DIFF
     }
@@ -75,27 +201,27 @@ impl Mesh for Mesh4 {
 
     fn south(&self) -> f64 {
         let south = self.mesh3().south();
-        match self.code.chars().nth(8).unwrap() {
-            '1' | '2' => south,
-            '3' | '4' => south + MESH4_LAT_DIFF,
+        match self.quadrant() {
+            1 | 2 => south,
+            3 | 4 => south + MESH4_LAT_DIFF,
             _ => unreachable!(),
         }
     }
 
     fn west(&self) -> f64 {
         let west = self.mesh3().west();
-        match self.code.chars().nth(8).unwrap() {
-            '1' | '3' => west,
-            '2' | '4' => west + MESH4_LON_DIFF,
+        match self.quadrant() {
+            1 | 3 => west,
+            2 | 4 => west + MESH4_LON_DIFF,
             _ => unreachable!(),
         }
     }
 
     fn north_mesh(&self) -> Result<Self, GSJPError> {
-        let n = self.code.chars().nth(8).unwrap().to_digit(10).unwrap();
+        let n = self.quadrant();
         let code = match n {
             1 | 2 => {
-                format!("{}{}", &self.code[0..8], n + 2,)
+                format!("{}{}", ancestor_code(self, MeshLevel::Mesh3), n + 2,)
             }
             3 | 4 => {
                 let mesh3 = self.mesh3().north_mesh()?;
@@ -108,10 +234,10 @@ impl Mesh for Mesh4 {
     }
 
     fn east_mesh(&self) -> Result<Self, GSJPError> {
-        let n = self.code.chars().nth(8).unwrap().to_digit(10).unwrap();
+        let n = self.quadrant();
         let code = match n {
             1 | 3 => {
-                format!("{}{}", &self.code[0..8], n + 1,)
+                format!("{}{}", ancestor_code(self, MeshLevel::Mesh3), n + 1,)
             }
             2 | 4 => {
                 let mesh3 = self.mesh3().east_mesh()?;
@@ -124,14 +250,14 @@ impl Mesh for Mesh4 {
     }
 
     fn south_mesh(&self) -> Result<Self, GSJPError> {
-        let n = self.code.chars().nth(8).unwrap().to_digit(10).unwrap();
+        let n = self.quadrant();
         let code = match n {
             1 | 2 => {
                 let mesh3 = self.mesh3().south_mesh()?;
                 format!("{}{}", mesh3.code(), n + 2,)
             }
             3 | 4 => {
-                format!("{}{}", &self.code[0..8], n - 2,)
+                format!("{}{}", ancestor_code(self, MeshLevel::Mesh3), n - 2,)
             }
             _ => unreachable!(),
         };
@@ -140,31 +266,84 @@ impl Mesh for Mesh4 {
     }
 
     fn west_mesh(&self) -> Result<Self, GSJPError> {
-        let n = self.code.chars().nth(8).unwrap().to_digit(10).unwrap();
+        let n = self.quadrant();
         let code = match n {
             1 | 3 => {
                 let mesh3 = self.mesh3().west_mesh()?;
                 format!("{}{}", mesh3.code(), n + 1,)
             }
             2 | 4 => {
-                format!("{}{}", &self.code[0..8], n - 1,)
+                format!("{}{}", ancestor_code(self, MeshLevel::Mesh3), n - 1,)
             }
             _ => unreachable!(),
         };
 
         Self::new(code)
     }
+
+    fn north_east_mesh(&self) -> Result<Self, GSJPError> {
+        Self::new(division_diagonal_code(
+            &self.code,
+            &self.mesh3(),
+            true,
+            true,
+        )?)
+    }
+
+    fn south_east_mesh(&self) -> Result<Self, GSJPError> {
+        Self::new(division_diagonal_code(
+            &self.code,
+            &self.mesh3(),
+            false,
+            true,
+        )?)
+    }
+
+    fn south_west_mesh(&self) -> Result<Self, GSJPError> {
+        Self::new(division_diagonal_code(
+            &self.code,
+            &self.mesh3(),
+            false,
+            false,
+        )?)
+    }
+
+    fn north_west_mesh(&self) -> Result<Self, GSJPError> {
+        Self::new(division_diagonal_code(
+            &self.code,
+            &self.mesh3(),
+            true,
+            false,
+        )?)
+    }
+}
+
+impl PartialEq<str> for Mesh4 {
+    fn eq(&self, other: &str) -> bool {
+        self.code == other
+    }
+}
+
+impl PartialEq<&str> for Mesh4 {
+    fn eq(&self, other: &&str) -> bool {
+        self.code == *other
+    }
+}
+
+impl From<Mesh4> for String {
+    /// メッシュを消費して、メッシュコードを返す。
+    fn from(mesh: Mesh4) -> Self {
+        mesh.code
+    }
 }
 
 pub(crate) fn validate_mesh4_code(code: &str) -> Result<(), GSJPError> {
+    validate_code_len(code, Mesh4::CODE_LEN)?;
     // 上位8桁が標準地域メッシュのメッシュコードであることを確認
-    if code.len() != 9 {
-        return Err(GSJPError::InvalidMeshCode);
-    }
     validate_mesh3_code(&code[0..8])?;
     // 2分の1地域メッシュのメッシュコードの2分の1地域メッシュ部分について、緯度方向の値と経度方向の値を確認
-    let num = &code.chars().nth(8).unwrap();
-    if !(&'1'..=&'4').contains(&num) {
+    let num = code.as_bytes()[8];
+    if !(b'1'..=b'4').contains(&num) {
         return Err(GSJPError::InvalidMeshCode);
     }
 
@@ -177,6 +356,48 @@ mod tests {
     use crate::mesh::tests::{eq_f64, EPSILON};
     use crate::mesh::NeighborDirection;
 
+    #[test]
+    fn mesh4_ancestors_ok() {
+        let code = "533935993";
+        let mesh = Mesh4::new(String::from(code)).unwrap();
+        let ancestors = mesh.ancestors();
+        let expected = [&code[0..8], &code[0..6], &code[0..4]];
+        assert_eq!(
+            expected.to_vec(),
+            ancestors.iter().map(|mesh| mesh.code()).collect::<Vec<_>>()
+        );
+        assert_eq!(MeshLevel::Mesh1, ancestors.last().unwrap().level());
+    }
+
+    #[test]
+    fn mesh4_from_parts_ok() {
+        let mesh3 = Mesh3::new(String::from("53393599")).unwrap();
+        for quadrant in 1..=4 {
+            let mesh = Mesh4::from_parts(&mesh3, quadrant).unwrap();
+            assert_eq!(format!("53393599{}", quadrant), mesh.code());
+            assert_eq!(mesh3, mesh.mesh3());
+        }
+    }
+
+    #[test]
+    fn mesh4_from_parts_err() {
+        let mesh3 = Mesh3::new(String::from("53393599")).unwrap();
+        for quadrant in [0, 5] {
+            assert!(matches!(
+                Mesh4::from_parts(&mesh3, quadrant),
+                Err(GSJPError::OutOfRange(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn mesh4_quadrant_ok() {
+        for quadrant in 1..=4 {
+            let mesh = Mesh4::new(format!("53393599{}", quadrant)).unwrap();
+            assert_eq!(quadrant, mesh.quadrant());
+        }
+    }
+
     #[test]
     fn mesh4_new_ok() {
         let codes = vec!["533935991", "533935992", "533935993", "533935994"];
@@ -188,12 +409,57 @@ mod tests {
     }
 
     #[test]
-    fn mesh4_new_err() {
-        let codes = vec!["533935990", "533935995"];
+    fn mesh4_is_valid_ok() {
+        for code in ["533935991", "533935994"] {
+            assert!(Mesh4::is_valid(code), "{}", code);
+            assert!(Mesh4::new(String::from(code)).is_ok(), "{}", code);
+        }
+        for code in ["533935990", "533935995", "53393599"] {
+            assert!(!Mesh4::is_valid(code), "{}", code);
+            assert!(Mesh4::new(String::from(code)).is_err(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh4_bytes_ok() {
+        let mesh = Mesh4::new(String::from("533935994")).unwrap();
+        assert_eq!([0x1F, 0xD3, 0x37, 0x7A], mesh.to_bytes());
+        // 途中に0を含むメッシュコード
+        let codes = ["301800001", "533935994", "714977994"];
         for code in codes {
-            let mesh = Mesh4::new(code.to_string());
-            assert!(mesh.is_err(), "{}", code);
+            let mesh = Mesh4::new(code.to_string()).unwrap();
+            assert_eq!(
+                mesh,
+                Mesh4::from_bytes(mesh.to_bytes()).unwrap(),
+                "{}",
+                code
+            );
         }
+        // バイト列はメッシュコードの順番に並ぶ
+        let bytes = codes.map(|code| Mesh4::new(code.to_string()).unwrap().to_bytes());
+        assert!(bytes.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn mesh4_from_bytes_err() {
+        assert!(Mesh4::from_bytes([0; 4]).is_err());
+        assert!(Mesh4::from_bytes([0xFF; 4]).is_err());
+    }
+
+    #[test]
+    fn mesh4_into_string_ok() {
+        let mesh = Mesh4::new(String::from("533935994")).unwrap();
+        // `mesh`はムーブされるため、以降で使用できない
+        let code = String::from(mesh);
+        assert_eq!("533935994", code);
+    }
+
+    #[test]
+    fn mesh4_code_len_ok() {
+        let mesh = Mesh4::new(String::from("533935991")).unwrap();
+        assert_eq!(9, Mesh4::CODE_LEN);
+        assert_eq!(mesh.code().len(), Mesh4::CODE_LEN);
+        assert_eq!(MeshLevel::Mesh4.code_len(), Mesh4::CODE_LEN);
     }
 
     #[test]
@@ -223,6 +489,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mesh4_alternate_code_ok() {
+        let inputs = vec![
+            ("533935991", "5339359900"),
+            ("533935992", "5339359901"),
+            ("533935993", "5339359910"),
+            ("533935994", "5339359911"),
+        ];
+        for (code, alternate) in inputs {
+            let mesh = Mesh4::new(code.to_string()).unwrap();
+            assert_eq!(alternate, mesh.to_alternate_code(), "{}", code);
+            let mesh = Mesh4::from_alternate_code(alternate).unwrap();
+            assert_eq!(code, mesh.code(), "{}", alternate);
+        }
+    }
+
     #[test]
     fn mesh4_north_ok() {
         let south = 35.65833333;
@@ -1,5 +1,13 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
 use crate::mesh::mesh4::validate_mesh4_code;
-use crate::mesh::{Coordinate, GSJPError, Mesh, Mesh1, Mesh2, Mesh3, Mesh4};
+use crate::mesh::{
+    ancestor_code, code_from_bytes, code_to_bytes, division_diagonal_code, from_alternate_code,
+    quadrant_of, to_alternate_code, validate_code_len, validate_part, AnyMesh, Coordinate,
+    GSJPError, Mesh, Mesh1, Mesh2, Mesh3, Mesh4, MeshLevel,
+};
 
 /// 4分の１地域メッシュの南端と北端の緯度の差
 const MESH5_LAT_DIFF: f64 = 7.5 / 3600.0; // 7.5秒
@@ -10,19 +18,33 @@ const MESH5_LON_DIFF: f64 = 11.25 / 3600.0; // 11.25秒
 ///
 /// 2分の１地域メッシュを南北に2等分、東西に2等分した区画を示す。
 /// 4分の1地域メッシュの辺の長さは約250mである。
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Mesh5 {
     code: String,
 }
 
 impl Mesh5 {
+    /// 4分の1地域メッシュのメッシュコードの長さ
+    pub const CODE_LEN: usize = MeshLevel::Mesh5.code_len();
+
+    /// 4分の1地域メッシュの分割番号を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 分割番号（1から4）
+    fn quadrant(&self) -> u8 {
+        // メッシュコードは検証済みであるため、最後の文字は1から4の数字である
+        self.code.as_bytes()[Self::CODE_LEN - 1] - b'0'
+    }
+
     /// 4分の1地域メッシュを含む第1次地域区画を返す。
     ///
     /// # 戻り値
     ///
     /// 第1次地域区画
+    #[must_use]
     pub fn mesh1(&self) -> Mesh1 {
-        Mesh1::new(self.code[0..4].to_string()).unwrap()
+        Mesh1::new(ancestor_code(self, MeshLevel::Mesh1)).unwrap()
     }
 
     /// 4分の1地域メッシュを含む第2次地域区画を返す。
@@ -30,8 +52,9 @@ impl Mesh5 {
     /// # 戻り値
     ///
     /// 第2次地域区画
+    #[must_use]
     pub fn mesh2(&self) -> Mesh2 {
-        Mesh2::new(self.code[0..6].to_string()).unwrap()
+        Mesh2::new(ancestor_code(self, MeshLevel::Mesh2)).unwrap()
     }
 
     /// 4分の1地域メッシュを含む基準地域メッシュを返す。
@@ -39,8 +62,9 @@ impl Mesh5 {
     /// # 戻り値
     ///
     /// 基準地域メッシュ
+    #[must_use]
     pub fn mesh3(&self) -> Mesh3 {
-        Mesh3::new(self.code[0..8].to_string()).unwrap()
+        Mesh3::new(ancestor_code(self, MeshLevel::Mesh3)).unwrap()
     }
 
     /// 4分の1地域メッシュを含む2分の1地域メッシュを返す。
@@ -48,8 +72,104 @@ impl Mesh5 {
     /// # 戻り値
     ///
     /// 2分の1地域メッシュ
+    #[must_use]
     pub fn mesh4(&self) -> Mesh4 {
-        Mesh4::new(self.code[0..9].to_string()).unwrap()
+        Mesh4::new(ancestor_code(self, MeshLevel::Mesh4)).unwrap()
+    }
+
+    /// 4分の1地域メッシュを含む上位のメッシュを、細かいレベルから順に返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 4分の1地域メッシュを含む上位のメッシュ。最後の要素は第1次地域区画
+    #[must_use]
+    pub fn ancestors(&self) -> Vec<AnyMesh> {
+        vec![
+            self.mesh4().into(),
+            self.mesh3().into(),
+            self.mesh2().into(),
+            self.mesh1().into(),
+        ]
+    }
+
+    /// 4分の1地域メッシュのメッシュコードを、代替の番号体系のメッシュコードに変換する。
+    ///
+    /// # 戻り値
+    ///
+    /// 代替の番号体系のメッシュコード
+    #[must_use]
+    pub fn to_alternate_code(&self) -> String {
+        to_alternate_code(&self.code)
+    }
+
+    /// 代替の番号体系のメッシュコードから4分の1地域メッシュを作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `code` - 代替の番号体系のメッシュコード
+    ///
+    /// # 戻り値
+    ///
+    /// 4分の1地域メッシュ
+    pub fn from_alternate_code(code: &str) -> Result<Self, GSJPError> {
+        Self::new(from_alternate_code(code, MeshLevel::Mesh5)?)
+    }
+
+    /// 2分の1地域メッシュと分割番号から4分の1地域メッシュを作成する。
+    ///
+    /// 分割番号は、2分の1地域メッシュを南北と東西に2等分した区画を、南西から1、南東が2、北西が3、北東が4で指定する。
+    ///
+    /// # 引数
+    ///
+    /// * `mesh4` - 2分の1地域メッシュ
+    /// * `quadrant` - 分割番号（1から4）
+    ///
+    /// # 戻り値
+    ///
+    /// 4分の1地域メッシュ。分割番号が範囲外の場合はエラー
+    pub fn from_parts(mesh4: &Mesh4, quadrant: u8) -> Result<Self, GSJPError> {
+        validate_part("分割番号", quadrant, 1..=4)?;
+
+        Self::new(format!("{}{}", mesh4.code(), quadrant))
+    }
+
+    /// 4分の1地域メッシュを、5バイトのバイト列に変換する（形式は`code_to_bytes`を参照）。
+    ///
+    /// # 戻り値
+    ///
+    /// 5バイトのバイト列
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 5] {
+        code_to_bytes(self.as_u64())
+    }
+
+    /// `to_bytes`で変換したバイト列から4分の1地域メッシュを作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `bytes` - ビッグエンディアンで整数のメッシュコードを格納した5バイトのバイト列
+    ///
+    /// # 戻り値
+    ///
+    /// 4分の1地域メッシュ
+    pub fn from_bytes(bytes: [u8; 5]) -> Result<Self, GSJPError> {
+        Self::from_u64(code_from_bytes(bytes))
+    }
+
+    /// メッシュコードが4分の1地域メッシュのメッシュコードとして正しいか確認する。
+    ///
+    /// `new`と同様にメッシュコードを検証するが、4分の1地域メッシュを作成しない。
+    ///
+    /// # 引数
+    ///
+    /// * `code` - 4分の1地域メッシュのメッシュコード
+    ///
+    /// # 戻り値
+    ///
+    /// 正しい場合は`true`、正しくない場合は`false`
+    #[must_use]
+    pub fn is_valid(code: &str) -> bool {
+        validate_mesh5_code(code).is_ok()
     }
 }
 
@@ -62,9 +182,13 @@ impl Mesh for Mesh5 {
 
     fn from_coordinate(coord: Coordinate) -> Result<Self, GSJPError> {
         let mesh4 = Mesh4::from_coordinate(coord)?;
-        let lat_n = ((coord.lat() - mesh4.south()) / MESH5_LAT_DIFF).floor() as u8;
-        let lon_n = ((coord.lon() - mesh4.west()) / MESH5_LON_DIFF).floor() as u8;
-        let num = 2 * lat_n + 1 + lon_n;
+        let num = quadrant_of(
+            mesh4.south(),
+            mesh4.west(),
+            MESH5_LAT_DIFF,
+            MESH5_LON_DIFF,
+            &coord,
+        );
         let code = format!("{}{}", mesh4.code(), num);
 
         Self::new(code)
@@ -74,6 +198,10 @@ impl Mesh for Mesh5 {
         &self.code
     }
 
+    fn into_code(self) -> String {
+        self.code
+    }
+
     fn north(&self) -> f64 {
         self.south() + MESH5_LAT_DIFF
     }
@@ -84,27 +212,27 @@ impl Mesh for Mesh5 {
 
     fn south(&self) -> f64 {
         let south = self.mesh4().south();
-        match self.code.chars().nth(9).unwrap() {
-            '1' | '2' => south,
-            '3' | '4' => south + MESH5_LAT_DIFF,
+        match self.quadrant() {
+            1 | 2 => south,
+            3 | 4 => south + MESH5_LAT_DIFF,
             _ => unreachable!(),
         }
     }
 
     fn west(&self) -> f64 {
         let west = self.mesh4().west();
-        match self.code.chars().nth(9).unwrap() {
-            '1' | '3' => west,
-            '2' | '4' => west + MESH5_LON_DIFF,
+        match self.quadrant() {
+            1 | 3 => west,
+            2 | 4 => west + MESH5_LON_DIFF,
             _ => unreachable!(),
         }
     }
 
     fn north_mesh(&self) -> Result<Self, GSJPError> {
-        let n = self.code.chars().nth(9).unwrap().to_digit(10).unwrap();
+        let n = self.quadrant();
         let code = match n {
             1 | 2 => {
-                format!("{}{}", &self.code[0..9], n + 2,)
+                format!("{}{}", ancestor_code(self, MeshLevel::Mesh4), n + 2,)
             }
             3 | 4 => {
                 let mesh4 = self.mesh4().north_mesh()?;
@@ -117,10 +245,10 @@ impl Mesh for Mesh5 {
     }
 
     fn east_mesh(&self) -> Result<Self, GSJPError> {
-        let n = self.code.chars().nth(9).unwrap().to_digit(10).unwrap();
+        let n = self.quadrant();
         let code = match n {
             1 | 3 => {
-                format!("{}{}", &self.code[0..9], n + 1,)
+                format!("{}{}", ancestor_code(self, MeshLevel::Mesh4), n + 1,)
             }
             2 | 4 => {
                 let mesh4 = self.mesh4().east_mesh()?;
@@ -133,14 +261,14 @@ impl Mesh for Mesh5 {
     }
 
     fn south_mesh(&self) -> Result<Self, GSJPError> {
-        let n = self.code.chars().nth(9).unwrap().to_digit(10).unwrap();
+        let n = self.quadrant();
         let code = match n {
             1 | 2 => {
                 let mesh4 = self.mesh4().south_mesh()?;
                 format!("{}{}", mesh4.code(), n + 2,)
             }
             3 | 4 => {
-                format!("{}{}", &self.code[0..9], n - 2,)
+                format!("{}{}", ancestor_code(self, MeshLevel::Mesh4), n - 2,)
             }
             _ => unreachable!(),
         };
@@ -149,31 +277,84 @@ impl Mesh for Mesh5 {
     }
 
     fn west_mesh(&self) -> Result<Self, GSJPError> {
-        let n = self.code.chars().nth(9).unwrap().to_digit(10).unwrap();
+        let n = self.quadrant();
         let code = match n {
             1 | 3 => {
                 let mesh4 = self.mesh4().west_mesh()?;
                 format!("{}{}", mesh4.code(), n + 1,)
             }
             2 | 4 => {
-                format!("{}{}", &self.code[0..9], n - 1,)
+                format!("{}{}", ancestor_code(self, MeshLevel::Mesh4), n - 1,)
             }
             _ => unreachable!(),
         };
 
         Self::new(code)
     }
+
+    fn north_east_mesh(&self) -> Result<Self, GSJPError> {
+        Self::new(division_diagonal_code(
+            &self.code,
+            &self.mesh4(),
+            true,
+            true,
+        )?)
+    }
+
+    fn south_east_mesh(&self) -> Result<Self, GSJPError> {
+        Self::new(division_diagonal_code(
+            &self.code,
+            &self.mesh4(),
+            false,
+            true,
+        )?)
+    }
+
+    fn south_west_mesh(&self) -> Result<Self, GSJPError> {
+        Self::new(division_diagonal_code(
+            &self.code,
+            &self.mesh4(),
+            false,
+            false,
+        )?)
+    }
+
+    fn north_west_mesh(&self) -> Result<Self, GSJPError> {
+        Self::new(division_diagonal_code(
+            &self.code,
+            &self.mesh4(),
+            true,
+            false,
+        )?)
+    }
+}
+
+impl PartialEq<str> for Mesh5 {
+    fn eq(&self, other: &str) -> bool {
+        self.code == other
+    }
+}
+
+impl PartialEq<&str> for Mesh5 {
+    fn eq(&self, other: &&str) -> bool {
+        self.code == *other
+    }
+}
+
+impl From<Mesh5> for String {
+    /// メッシュを消費して、メッシュコードを返す。
+    fn from(mesh: Mesh5) -> Self {
+        mesh.code
+    }
 }
 
 pub(crate) fn validate_mesh5_code(code: &str) -> Result<(), GSJPError> {
+    validate_code_len(code, Mesh5::CODE_LEN)?;
     // 上位9桁が2分の1地域メッシュのメッシュコードであることを確認
-    if code.len() != 10 {
-        return Err(GSJPError::InvalidMeshCode);
-    }
     validate_mesh4_code(&code[0..9])?;
     // 4分の1地域メッシュのメッシュコードの4分の1地域メッシュ部分について、緯度方向の値と経度方向の値を確認
-    let num = &code.chars().nth(9).unwrap();
-    if !(&'1'..=&'4').contains(&num) {
+    let num = code.as_bytes()[9];
+    if !(b'1'..=b'4').contains(&num) {
         return Err(GSJPError::InvalidMeshCode);
     }
 
@@ -186,6 +367,48 @@ mod tests {
     use crate::mesh::tests::{eq_f64, EPSILON};
     use crate::mesh::NeighborDirection;
 
+    #[test]
+    fn mesh5_ancestors_ok() {
+        let code = "5339359934";
+        let mesh = Mesh5::new(String::from(code)).unwrap();
+        let ancestors = mesh.ancestors();
+        let expected = [&code[0..9], &code[0..8], &code[0..6], &code[0..4]];
+        assert_eq!(
+            expected.to_vec(),
+            ancestors.iter().map(|mesh| mesh.code()).collect::<Vec<_>>()
+        );
+        assert_eq!(MeshLevel::Mesh1, ancestors.last().unwrap().level());
+    }
+
+    #[test]
+    fn mesh5_from_parts_ok() {
+        let mesh4 = Mesh4::new(String::from("533935991")).unwrap();
+        for quadrant in 1..=4 {
+            let mesh = Mesh5::from_parts(&mesh4, quadrant).unwrap();
+            assert_eq!(format!("533935991{}", quadrant), mesh.code());
+            assert_eq!(mesh4, mesh.mesh4());
+        }
+    }
+
+    #[test]
+    fn mesh5_from_parts_err() {
+        let mesh4 = Mesh4::new(String::from("533935991")).unwrap();
+        for quadrant in [0, 5] {
+            assert!(matches!(
+                Mesh5::from_parts(&mesh4, quadrant),
+                Err(GSJPError::OutOfRange(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn mesh5_quadrant_ok() {
+        for quadrant in 1..=4 {
+            let mesh = Mesh5::new(format!("533935991{}", quadrant)).unwrap();
+            assert_eq!(quadrant, mesh.quadrant());
+        }
+    }
+
     #[test]
     fn mesh5_new_ok() {
         let codes = vec!["5339359911", "5339359912", "5339359913", "5339359914"];
@@ -197,12 +420,57 @@ mod tests {
     }
 
     #[test]
-    fn mesh5_new_err() {
-        let codes = vec!["5339359910", "5339359915"];
+    fn mesh5_is_valid_ok() {
+        for code in ["5339359911", "5339359944"] {
+            assert!(Mesh5::is_valid(code), "{}", code);
+            assert!(Mesh5::new(String::from(code)).is_ok(), "{}", code);
+        }
+        for code in ["5339359905", "5339359915", "533935991"] {
+            assert!(!Mesh5::is_valid(code), "{}", code);
+            assert!(Mesh5::new(String::from(code)).is_err(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh5_bytes_ok() {
+        let mesh = Mesh5::new(String::from("5339359944")).unwrap();
+        assert_eq!([0x01, 0x3E, 0x40, 0x2A, 0xC8], mesh.to_bytes());
+        // 途中に0を含むメッシュコード
+        let codes = ["3018000011", "5339359944", "7149779944"];
         for code in codes {
-            let mesh = Mesh5::new(code.to_string());
-            assert!(mesh.is_err(), "{}", code);
+            let mesh = Mesh5::new(code.to_string()).unwrap();
+            assert_eq!(
+                mesh,
+                Mesh5::from_bytes(mesh.to_bytes()).unwrap(),
+                "{}",
+                code
+            );
         }
+        // バイト列はメッシュコードの順番に並ぶ
+        let bytes = codes.map(|code| Mesh5::new(code.to_string()).unwrap().to_bytes());
+        assert!(bytes.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn mesh5_from_bytes_err() {
+        assert!(Mesh5::from_bytes([0; 5]).is_err());
+        assert!(Mesh5::from_bytes([0xFF; 5]).is_err());
+    }
+
+    #[test]
+    fn mesh5_into_string_ok() {
+        let mesh = Mesh5::new(String::from("5339359943")).unwrap();
+        // `mesh`はムーブされるため、以降で使用できない
+        let code = String::from(mesh);
+        assert_eq!("5339359943", code);
+    }
+
+    #[test]
+    fn mesh5_code_len_ok() {
+        let mesh = Mesh5::new(String::from("5339359911")).unwrap();
+        assert_eq!(10, Mesh5::CODE_LEN);
+        assert_eq!(mesh.code().len(), Mesh5::CODE_LEN);
+        assert_eq!(MeshLevel::Mesh5.code_len(), Mesh5::CODE_LEN);
     }
 
     #[test]
@@ -233,6 +501,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mesh5_alternate_code_ok() {
+        let inputs = vec![
+            // 南西
+            ("5339359911", "533935990000"),
+            // 南東
+            ("5339359912", "533935990001"),
+            // 北西
+            ("5339359913", "533935990010"),
+            // 北東
+            ("5339359914", "533935990011"),
+            // 2分の1地域メッシュの分割番号も変換
+            ("5339359942", "533935991101"),
+        ];
+        for (code, alternate) in inputs {
+            let mesh = Mesh5::new(code.to_string()).unwrap();
+            assert_eq!(alternate, mesh.to_alternate_code(), "{}", code);
+            let mesh = Mesh5::from_alternate_code(alternate).unwrap();
+            assert_eq!(code, mesh.code(), "{}", alternate);
+        }
+    }
+
+    #[test]
+    fn mesh5_from_alternate_code_err() {
+        let codes = vec![
+            "5339359911",
+            "53393599000",
+            "5339359900000",
+            "533935990002",
+            "533935992000",
+            "533935990a00",
+        ];
+        for code in codes {
+            assert!(Mesh5::from_alternate_code(code).is_err(), "{}", code);
+        }
+    }
+
     #[test]
     fn mesh5_north_ok() {
         let south = 35.65833333;
@@ -448,6 +753,114 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mesh5_diagonal_mesh_ok() {
+        // (メッシュコード, 北東隣, 南東隣, 南西隣, 北西隣)
+        let inputs = [
+            // 北東隣だけが同じ2分の1地域メッシュに含まれる
+            (
+                "5339359911",
+                "5339359914",
+                "5339358934",
+                "5339358844",
+                "5339359824",
+            ),
+            // 南西隣だけが同じ2分の1地域メッシュに含まれる
+            (
+                "5339359914",
+                "5339359941",
+                "5339359921",
+                "5339359911",
+                "5339359931",
+            ),
+            // 北東隣と北西隣が、北隣の基準地域メッシュに含まれる
+            (
+                "5339359934",
+                "5339450921",
+                "5339359941",
+                "5339359931",
+                "5339450911",
+            ),
+            // 北東隣、南東隣及び北西隣が、第1次地域区画の境界を越える
+            (
+                "5339779944",
+                "5440000011",
+                "5340709031",
+                "5339779941",
+                "5439070921",
+            ),
+        ];
+        for (code, north_east, south_east, south_west, north_west) in inputs {
+            let mesh = Mesh5::new(code.to_string()).unwrap();
+            assert_eq!(
+                north_east,
+                mesh.north_east_mesh().unwrap().code(),
+                "{}",
+                code
+            );
+            assert_eq!(
+                south_east,
+                mesh.south_east_mesh().unwrap().code(),
+                "{}",
+                code
+            );
+            assert_eq!(
+                south_west,
+                mesh.south_west_mesh().unwrap().code(),
+                "{}",
+                code
+            );
+            assert_eq!(
+                north_west,
+                mesh.north_west_mesh().unwrap().code(),
+                "{}",
+                code
+            );
+        }
+        // 北隣と東隣を経由した斜め隣と一致する
+        for m4 in 1..=4 {
+            for m5 in 1..=4 {
+                let mesh = Mesh5::new(format!("53393599{}{}", m4, m5)).unwrap();
+                let north = mesh.north_mesh().unwrap();
+                let south = mesh.south_mesh().unwrap();
+                assert_eq!(north.east_mesh().unwrap(), mesh.north_east_mesh().unwrap());
+                assert_eq!(south.east_mesh().unwrap(), mesh.south_east_mesh().unwrap());
+                assert_eq!(south.west_mesh().unwrap(), mesh.south_west_mesh().unwrap());
+                assert_eq!(north.west_mesh().unwrap(), mesh.north_west_mesh().unwrap());
+            }
+        }
+    }
+
+    /// エラーのメッセージが、範囲外になった方向を示すことを確認する。
+    fn assert_out_of_range(result: Result<Mesh5, GSJPError>, direction: &str) {
+        match result {
+            Err(GSJPError::OutOfRange(message)) => {
+                assert!(message.starts_with(direction), "{}", message)
+            }
+            result => panic!("{:?}", result),
+        }
+    }
+
+    #[test]
+    fn mesh5_diagonal_mesh_boundary() {
+        // 標準地域メッシュの範囲の北端にあるメッシュ
+        let mesh = Mesh5::new(String::from("7139709033")).unwrap();
+        assert_out_of_range(mesh.north_east_mesh(), "北");
+        assert_out_of_range(mesh.north_west_mesh(), "北");
+        assert_eq!("7139709032", mesh.south_east_mesh().unwrap().code());
+        // 標準地域メッシュの範囲の東端にあるメッシュ
+        let mesh = Mesh5::new(String::from("5349070924")).unwrap();
+        assert_out_of_range(mesh.north_east_mesh(), "東");
+        assert_out_of_range(mesh.south_east_mesh(), "東");
+        assert_eq!("5349070921", mesh.south_west_mesh().unwrap().code());
+        // 標準地域メッシュの範囲の南西端にあるメッシュは、南隣が先に範囲外になる
+        let mesh = Mesh5::new(String::from("3018000011")).unwrap();
+        assert_out_of_range(mesh.south_west_mesh(), "南");
+        assert_out_of_range(mesh.south_east_mesh(), "南");
+        assert_out_of_range(mesh.north_west_mesh(), "西");
+        assert_eq!("3018000014", mesh.north_east_mesh().unwrap().code());
+    }
+
     #[test]
     fn mesh5_east_mesh_ok() {
         let inputs = vec![
@@ -0,0 +1,262 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use crate::mesh::{
+    mesh2::validate_mesh2_code, quadrant_of, validate_code_len, Coordinate, GSJPError, Mesh, Mesh2,
+};
+
+/// 5倍地域メッシュの南端と北端の緯度の差
+const MESH5X_LAT_DIFF: f64 = 2.0 / 60.0 + 30.0 / 3600.0; // 2分30秒
+/// 5倍地域メッシュの西端と東端の経度の差
+const MESH5X_LON_DIFF: f64 = 3.0 / 60.0 + 45.0 / 3600.0; // 3分45秒
+
+/// 5倍地域メッシュ（統合地域メッシュ）
+///
+/// 第2次地域区画を南北に2等分、東西に2等分した区画を示す。
+/// 5倍地域メッシュは基準地域メッシュを南北に5個、東西に5個まとめた区画で、辺の長さは約5kmである。
+///
+/// 5倍地域メッシュのメッシュコードは、第2次地域区画のメッシュコードに分割番号を付けた7桁である。
+/// 分割番号は、南西が1、南東が2、北西が3、北東が4である。
+///
+/// 5倍地域メッシュのメッシュコードは、標準地域メッシュのいずれのレベルのメッシュコードとも桁数が異なり、
+/// `MeshLevel`で表現できないため、`Mesh`トレイトを実装しない。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Mesh5x {
+    code: String,
+}
+
+impl Mesh5x {
+    /// 5倍地域メッシュのメッシュコードの長さ
+    pub const CODE_LEN: usize = 7;
+
+    /// 5倍地域メッシュを作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `code` - 5倍地域メッシュのメッシュコード
+    ///
+    /// # 戻り値
+    ///
+    /// 5倍地域メッシュ
+    pub fn new(code: String) -> Result<Self, GSJPError> {
+        validate_mesh5x_code(&code)?;
+
+        Ok(Self { code })
+    }
+
+    /// 座標を含む5倍地域メッシュを返す。
+    ///
+    /// # 引数
+    ///
+    /// * `coord` - 座標
+    ///
+    /// # 戻り値
+    ///
+    /// 座標を含む5倍地域メッシュ
+    pub fn from_coordinate(coord: Coordinate) -> Result<Self, GSJPError> {
+        let mesh2 = Mesh2::from_coordinate(coord)?;
+        let n = quadrant_of(
+            mesh2.south(),
+            mesh2.west(),
+            MESH5X_LAT_DIFF,
+            MESH5X_LON_DIFF,
+            &coord,
+        );
+
+        Self::new(format!("{}{}", mesh2.code(), n))
+    }
+
+    /// 5倍地域メッシュのメッシュコードを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 5倍地域メッシュのメッシュコード
+    #[must_use]
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// 5倍地域メッシュを含む第2次地域区画を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 第2次地域区画
+    #[must_use]
+    pub fn mesh2(&self) -> Mesh2 {
+        Mesh2::new(self.code[0..6].to_string()).unwrap()
+    }
+
+    /// 5倍地域メッシュの分割番号を返す。
+    fn division(&self) -> u8 {
+        self.code.as_bytes()[6] - b'0'
+    }
+
+    /// 5倍地域メッシュの北端の緯度を度単位で返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 5倍地域メッシュの北端の緯度
+    #[must_use]
+    pub fn north(&self) -> f64 {
+        self.south() + MESH5X_LAT_DIFF
+    }
+
+    /// 5倍地域メッシュの東端の経度を度単位で返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 5倍地域メッシュの東端の経度
+    #[must_use]
+    pub fn east(&self) -> f64 {
+        self.west() + MESH5X_LON_DIFF
+    }
+
+    /// 5倍地域メッシュの南端の緯度を度単位で返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 5倍地域メッシュの南端の緯度
+    #[must_use]
+    pub fn south(&self) -> f64 {
+        let lat_n = ((self.division() - 1) / 2) as f64;
+
+        self.mesh2().south() + MESH5X_LAT_DIFF * lat_n
+    }
+
+    /// 5倍地域メッシュの西端の経度を度単位で返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 5倍地域メッシュの西端の経度
+    #[must_use]
+    pub fn west(&self) -> f64 {
+        let lon_n = ((self.division() - 1) % 2) as f64;
+
+        self.mesh2().west() + MESH5X_LON_DIFF * lon_n
+    }
+
+    /// 5倍地域メッシュの中心の座標を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 5倍地域メッシュの中心の座標
+    #[must_use]
+    pub fn center(&self) -> Coordinate {
+        // 5倍地域メッシュは第2次地域区画に含まれるため、中心の座標は常に座標の範囲内にある
+        Coordinate::new_unchecked(
+            (self.north() + self.south()) / 2.0,
+            (self.east() + self.west()) / 2.0,
+        )
+    }
+
+    /// メッシュコードが5倍地域メッシュのメッシュコードとして正しいか確認する。
+    ///
+    /// `new`と同様にメッシュコードを検証するが、5倍地域メッシュを作成しない。
+    ///
+    /// # 引数
+    ///
+    /// * `code` - 5倍地域メッシュのメッシュコード
+    ///
+    /// # 戻り値
+    ///
+    /// 正しい場合は`true`、正しくない場合は`false`
+    #[must_use]
+    pub fn is_valid(code: &str) -> bool {
+        validate_mesh5x_code(code).is_ok()
+    }
+}
+
+/// 5倍地域メッシュのメッシュコードを検証する。
+///
+/// # 引数
+///
+/// * `code` - メッシュコード
+///
+/// # 戻り値
+///
+/// `()`
+fn validate_mesh5x_code(code: &str) -> Result<(), GSJPError> {
+    validate_code_len(code, Mesh5x::CODE_LEN)?;
+    // 上位6桁が第2次地域区画のメッシュコードであることを確認
+    validate_mesh2_code(&code[0..6])?;
+    // 分割番号を確認
+    if !(b'1'..=b'4').contains(&code.as_bytes()[6]) {
+        return Err(GSJPError::InvalidMeshCode);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::tests::eq_f64;
+    use crate::mesh::Mesh3;
+
+    #[test]
+    fn mesh5x_new_ok() {
+        for code in ["5339351", "5339352", "5339353", "5339354"] {
+            assert!(Mesh5x::new(String::from(code)).is_ok(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh5x_new_err() {
+        for code in [
+            "5339350", "5339355", "533935", "53393511", "5339801", "533935a",
+        ] {
+            assert!(Mesh5x::new(String::from(code)).is_err(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh5x_code_len_ok() {
+        let mesh = Mesh5x::new(String::from("5339351")).unwrap();
+        assert_eq!(7, Mesh5x::CODE_LEN);
+        assert_eq!(mesh.code().len(), Mesh5x::CODE_LEN);
+    }
+
+    #[test]
+    fn mesh5x_is_valid_ok() {
+        for code in ["5339351", "5339354"] {
+            assert!(Mesh5x::is_valid(code), "{}", code);
+            assert!(Mesh5x::new(String::from(code)).is_ok(), "{}", code);
+        }
+        for code in ["5339350", "5339355", "533935a"] {
+            assert!(!Mesh5x::is_valid(code), "{}", code);
+            assert!(Mesh5x::new(String::from(code)).is_err(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh5x_from_coordinate_ok() {
+        // 東京タワー
+        let coord = Coordinate::new(35.658581, 139.745433).unwrap();
+        let mesh = Mesh5x::from_coordinate(coord).unwrap();
+        assert_eq!("5339354", mesh.code());
+        assert_eq!("533935", mesh.mesh2().code());
+        assert!(mesh.south() <= coord.lat() && coord.lat() < mesh.north());
+        assert!(mesh.west() <= coord.lon() && coord.lon() < mesh.east());
+    }
+
+    #[test]
+    fn mesh5x_edges_ok() {
+        // 5倍地域メッシュは、基準地域メッシュを南北に5個、東西に5個まとめた区画
+        let inputs = vec![
+            ("5339351", "53393500", "53393544"),
+            ("5339352", "53393505", "53393549"),
+            ("5339353", "53393550", "53393594"),
+            ("5339354", "53393555", "53393599"),
+        ];
+        for (code, south_west, north_east) in inputs {
+            let mesh = Mesh5x::new(String::from(code)).unwrap();
+            let south_west = Mesh3::new(String::from(south_west)).unwrap();
+            let north_east = Mesh3::new(String::from(north_east)).unwrap();
+            assert!(eq_f64(south_west.south(), mesh.south()), "{}", code);
+            assert!(eq_f64(south_west.west(), mesh.west()), "{}", code);
+            assert!(eq_f64(north_east.north(), mesh.north()), "{}", code);
+            assert!(eq_f64(north_east.east(), mesh.east()), "{}", code);
+            assert_eq!(mesh, Mesh5x::from_coordinate(mesh.center()).unwrap());
+        }
+    }
+}
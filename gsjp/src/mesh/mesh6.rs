@@ -1,5 +1,13 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
 use crate::mesh::mesh5::validate_mesh5_code;
-use crate::mesh::{Coordinate, GSJPError, Mesh, Mesh1, Mesh2, Mesh3, Mesh4, Mesh5};
+use crate::mesh::{
+    ancestor_code, code_from_bytes, code_to_bytes, division_diagonal_code, from_alternate_code,
+    quadrant_of, to_alternate_code, validate_code_len, validate_part, AnyMesh, Coordinate,
+    GSJPError, Mesh, Mesh1, Mesh2, Mesh3, Mesh4, Mesh5, MeshLevel,
+};
 
 /// 8分の１地域メッシュの南端と北端の緯度の差
 const MESH6_LAT_DIFF: f64 = 3.75 / 3600.0; // 3.75秒
@@ -10,19 +18,33 @@ const MESH6_LON_DIFF: f64 = 5.625 / 3600.0; // 5.625秒
 ///
 /// 4分の1地域メッシュを南北に2等分、東西に2等分した区画を示す。
 /// 8分の1地域メッシュの辺の長さは約125mである。
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Mesh6 {
     code: String,
 }
 
 impl Mesh6 {
+    /// 8分の1地域メッシュのメッシュコードの長さ
+    pub const CODE_LEN: usize = MeshLevel::Mesh6.code_len();
+
+    /// 8分の1地域メッシュの分割番号を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 分割番号（1から4）
+    fn quadrant(&self) -> u8 {
+        // メッシュコードは検証済みであるため、最後の文字は1から4の数字である
+        self.code.as_bytes()[Self::CODE_LEN - 1] - b'0'
+    }
+
     /// 8分の1地域メッシュを含む第1次地域区画を返す。
     ///
     /// # 戻り値
     ///
     /// 第1次地域区画
+    #[must_use]
     pub fn mesh1(&self) -> Mesh1 {
-        Mesh1::new(self.code[0..4].to_string()).unwrap()
+        Mesh1::new(ancestor_code(self, MeshLevel::Mesh1)).unwrap()
     }
 
     /// 8分の1地域メッシュを含む第2次地域区画を返す。
@@ -30,8 +52,9 @@ impl Mesh6 {
     /// # 戻り値
     ///
     /// 第2次地域区画
+    #[must_use]
     pub fn mesh2(&self) -> Mesh2 {
-        Mesh2::new(self.code[0..6].to_string()).unwrap()
+        Mesh2::new(ancestor_code(self, MeshLevel::Mesh2)).unwrap()
     }
 
     /// 8分の1地域メッシュを含む基準地域メッシュを返す。
@@ -39,8 +62,9 @@ impl Mesh6 {
     /// # 戻り値
     ///
     /// 基準地域メッシュ
+    #[must_use]
     pub fn mesh3(&self) -> Mesh3 {
-        Mesh3::new(self.code[0..8].to_string()).unwrap()
+        Mesh3::new(ancestor_code(self, MeshLevel::Mesh3)).unwrap()
     }
 
     /// 8分の1地域メッシュを含む2分の1地域メッシュを返す。
@@ -48,8 +72,9 @@ impl Mesh6 {
     /// # 戻り値
     ///
     /// 2分の1地域メッシュ
+    #[must_use]
     pub fn mesh4(&self) -> Mesh4 {
-        Mesh4::new(self.code[0..9].to_string()).unwrap()
+        Mesh4::new(ancestor_code(self, MeshLevel::Mesh4)).unwrap()
     }
 
     /// 8分の1地域メッシュを含む4分の1地域メッシュを返す。
@@ -57,8 +82,105 @@ impl Mesh6 {
     /// # 戻り値
     ///
     /// 4分の1地域メッシュ
+    #[must_use]
     pub fn mesh5(&self) -> Mesh5 {
-        Mesh5::new(self.code[0..10].to_string()).unwrap()
+        Mesh5::new(ancestor_code(self, MeshLevel::Mesh5)).unwrap()
+    }
+
+    /// 8分の1地域メッシュを含む上位のメッシュを、細かいレベルから順に返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 8分の1地域メッシュを含む上位のメッシュ。最後の要素は第1次地域区画
+    #[must_use]
+    pub fn ancestors(&self) -> Vec<AnyMesh> {
+        vec![
+            self.mesh5().into(),
+            self.mesh4().into(),
+            self.mesh3().into(),
+            self.mesh2().into(),
+            self.mesh1().into(),
+        ]
+    }
+
+    /// 8分の1地域メッシュのメッシュコードを、代替の番号体系のメッシュコードに変換する。
+    ///
+    /// # 戻り値
+    ///
+    /// 代替の番号体系のメッシュコード
+    #[must_use]
+    pub fn to_alternate_code(&self) -> String {
+        to_alternate_code(&self.code)
+    }
+
+    /// 代替の番号体系のメッシュコードから8分の1地域メッシュを作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `code` - 代替の番号体系のメッシュコード
+    ///
+    /// # 戻り値
+    ///
+    /// 8分の1地域メッシュ
+    pub fn from_alternate_code(code: &str) -> Result<Self, GSJPError> {
+        Self::new(from_alternate_code(code, MeshLevel::Mesh6)?)
+    }
+
+    /// 4分の1地域メッシュと分割番号から8分の1地域メッシュを作成する。
+    ///
+    /// 分割番号は、4分の1地域メッシュを南北と東西に2等分した区画を、南西から1、南東が2、北西が3、北東が4で指定する。
+    ///
+    /// # 引数
+    ///
+    /// * `mesh5` - 4分の1地域メッシュ
+    /// * `quadrant` - 分割番号（1から4）
+    ///
+    /// # 戻り値
+    ///
+    /// 8分の1地域メッシュ。分割番号が範囲外の場合はエラー
+    pub fn from_parts(mesh5: &Mesh5, quadrant: u8) -> Result<Self, GSJPError> {
+        validate_part("分割番号", quadrant, 1..=4)?;
+
+        Self::new(format!("{}{}", mesh5.code(), quadrant))
+    }
+
+    /// 8分の1地域メッシュを、5バイトのバイト列に変換する（形式は`code_to_bytes`を参照）。
+    ///
+    /// # 戻り値
+    ///
+    /// 5バイトのバイト列
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 5] {
+        code_to_bytes(self.as_u64())
+    }
+
+    /// `to_bytes`で変換したバイト列から8分の1地域メッシュを作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `bytes` - ビッグエンディアンで整数のメッシュコードを格納した5バイトのバイト列
+    ///
+    /// # 戻り値
+    ///
+    /// 8分の1地域メッシュ
+    pub fn from_bytes(bytes: [u8; 5]) -> Result<Self, GSJPError> {
+        Self::from_u64(code_from_bytes(bytes))
+    }
+
+    /// メッシュコードが8分の1地域メッシュのメッシュコードとして正しいか確認する。
+    ///
+    /// `new`と同様にメッシュコードを検証するが、8分の1地域メッシュを作成しない。
+    ///
+    /// # 引数
+    ///
+    /// * `code` - 8分の1地域メッシュのメッシュコード
+    ///
+    /// # 戻り値
+    ///
+    /// 正しい場合は`true`、正しくない場合は`false`
+    #[must_use]
+    pub fn is_valid(code: &str) -> bool {
+        validate_mesh6_code(code).is_ok()
     }
 }
 
@@ -71,9 +193,13 @@ impl Mesh for Mesh6 {
 
     fn from_coordinate(coord: Coordinate) -> Result<Self, GSJPError> {
         let mesh5 = Mesh5::from_coordinate(coord)?;
-        let lat_n = ((coord.lat() - mesh5.south()) / MESH6_LAT_DIFF).floor() as u8;
-        let lon_n = ((coord.lon() - mesh5.west()) / MESH6_LON_DIFF).floor() as u8;
-        let num = 2 * lat_n + 1 + lon_n;
+        let num = quadrant_of(
+            mesh5.south(),
+            mesh5.west(),
+            MESH6_LAT_DIFF,
+            MESH6_LON_DIFF,
+            &coord,
+        );
         let code = format!("{}{}", mesh5.code(), num);
 
         Self::new(code)
@@ -83,6 +209,10 @@ impl Mesh for Mesh6 {
         &self.code
     }
 
+    fn into_code(self) -> String {
+        self.code
+    }
+
     fn north(&self) -> f64 {
         self.south() + MESH6_LAT_DIFF
     }
@@ -93,27 +223,27 @@ impl Mesh for Mesh6 {
 
     fn south(&self) -> f64 {
         let south = self.mesh5().south();
-        match self.code.chars().nth(10).unwrap() {
-            '1' | '2' => south,
-            '3' | '4' => south + MESH6_LAT_DIFF,
+        match self.quadrant() {
+            1 | 2 => south,
+            3 | 4 => south + MESH6_LAT_DIFF,
             _ => unreachable!(),
         }
     }
 
     fn west(&self) -> f64 {
         let west = self.mesh5().west();
-        match self.code.chars().nth(10).unwrap() {
-            '1' | '3' => west,
-            '2' | '4' => west + MESH6_LON_DIFF,
+        match self.quadrant() {
+            1 | 3 => west,
+            2 | 4 => west + MESH6_LON_DIFF,
             _ => unreachable!(),
         }
     }
 
     fn north_mesh(&self) -> Result<Self, GSJPError> {
-        let n = self.code.chars().nth(10).unwrap().to_digit(10).unwrap();
+        let n = self.quadrant();
         let code = match n {
             1 | 2 => {
-                format!("{}{}", &self.code[0..10], n + 2,)
+                format!("{}{}", ancestor_code(self, MeshLevel::Mesh5), n + 2,)
             }
             3 | 4 => {
                 let mesh5 = self.mesh5().north_mesh()?;
@@ -126,10 +256,10 @@ impl Mesh for Mesh6 {
     }
 
     fn east_mesh(&self) -> Result<Self, GSJPError> {
-        let n = self.code.chars().nth(10).unwrap().to_digit(10).unwrap();
+        let n = self.quadrant();
         let code = match n {
             1 | 3 => {
-                format!("{}{}", &self.code[0..10], n + 1,)
+                format!("{}{}", ancestor_code(self, MeshLevel::Mesh5), n + 1,)
             }
             2 | 4 => {
                 let mesh5 = self.mesh5().east_mesh()?;
@@ -142,14 +272,14 @@ impl Mesh for Mesh6 {
     }
 
     fn south_mesh(&self) -> Result<Self, GSJPError> {
-        let n = self.code.chars().nth(10).unwrap().to_digit(10).unwrap();
+        let n = self.quadrant();
         let code = match n {
             1 | 2 => {
                 let mesh5 = self.mesh5().south_mesh()?;
                 format!("{}{}", mesh5.code(), n + 2,)
             }
             3 | 4 => {
-                format!("{}{}", &self.code[0..10], n - 2,)
+                format!("{}{}", ancestor_code(self, MeshLevel::Mesh5), n - 2,)
             }
             _ => unreachable!(),
         };
@@ -158,31 +288,84 @@ impl Mesh for Mesh6 {
     }
 
     fn west_mesh(&self) -> Result<Self, GSJPError> {
-        let n = self.code.chars().nth(10).unwrap().to_digit(10).unwrap();
+        let n = self.quadrant();
         let code = match n {
             1 | 3 => {
                 let mesh5 = self.mesh5().west_mesh()?;
                 format!("{}{}", mesh5.code(), n + 1,)
             }
             2 | 4 => {
-                format!("{}{}", &self.code[0..10], n - 1,)
+                format!("{}{}", ancestor_code(self, MeshLevel::Mesh5), n - 1,)
             }
             _ => unreachable!(),
         };
 
         Self::new(code)
     }
+
+    fn north_east_mesh(&self) -> Result<Self, GSJPError> {
+        Self::new(division_diagonal_code(
+            &self.code,
+            &self.mesh5(),
+            true,
+            true,
+        )?)
+    }
+
+    fn south_east_mesh(&self) -> Result<Self, GSJPError> {
+        Self::new(division_diagonal_code(
+            &self.code,
+            &self.mesh5(),
+            false,
+            true,
+        )?)
+    }
+
+    fn south_west_mesh(&self) -> Result<Self, GSJPError> {
+        Self::new(division_diagonal_code(
+            &self.code,
+            &self.mesh5(),
+            false,
+            false,
+        )?)
+    }
+
+    fn north_west_mesh(&self) -> Result<Self, GSJPError> {
+        Self::new(division_diagonal_code(
+            &self.code,
+            &self.mesh5(),
+            true,
+            false,
+        )?)
+    }
+}
+
+impl PartialEq<str> for Mesh6 {
+    fn eq(&self, other: &str) -> bool {
+        self.code == other
+    }
+}
+
+impl PartialEq<&str> for Mesh6 {
+    fn eq(&self, other: &&str) -> bool {
+        self.code == *other
+    }
+}
+
+impl From<Mesh6> for String {
+    /// メッシュを消費して、メッシュコードを返す。
+    fn from(mesh: Mesh6) -> Self {
+        mesh.code
+    }
 }
 
 pub(crate) fn validate_mesh6_code(code: &str) -> Result<(), GSJPError> {
+    validate_code_len(code, Mesh6::CODE_LEN)?;
     // 上位10桁が2分の1地域メッシュのメッシュコードであることを確認
-    if code.len() != 11 {
-        return Err(GSJPError::InvalidMeshCode);
-    }
     validate_mesh5_code(&code[0..10])?;
     // 8分の1地域メッシュのメッシュコードの8分の1地域メッシュ部分について、緯度方向の値と経度方向の値を確認
-    let num = &code.chars().nth(10).unwrap();
-    if !(&'1'..=&'4').contains(&num) {
+    let num = code.as_bytes()[10];
+    if !(b'1'..=b'4').contains(&num) {
         return Err(GSJPError::InvalidMeshCode);
     }
 
@@ -193,7 +376,55 @@ pub(crate) fn validate_mesh6_code(code: &str) -> Result<(), GSJPError> {
 mod tests {
     use super::*;
     use crate::mesh::tests::{eq_f64, EPSILON};
-    use crate::mesh::NeighborDirection;
+    use crate::mesh::{MeshLevel, NeighborDirection};
+
+    #[test]
+    fn mesh6_ancestors_ok() {
+        let code = "53393599342";
+        let mesh = Mesh6::new(String::from(code)).unwrap();
+        let ancestors = mesh.ancestors();
+        let expected = [
+            &code[0..10],
+            &code[0..9],
+            &code[0..8],
+            &code[0..6],
+            &code[0..4],
+        ];
+        assert_eq!(
+            expected.to_vec(),
+            ancestors.iter().map(|mesh| mesh.code()).collect::<Vec<_>>()
+        );
+        assert_eq!(MeshLevel::Mesh1, ancestors.last().unwrap().level());
+    }
+
+    #[test]
+    fn mesh6_from_parts_ok() {
+        let mesh5 = Mesh5::new(String::from("5339359912")).unwrap();
+        for quadrant in 1..=4 {
+            let mesh = Mesh6::from_parts(&mesh5, quadrant).unwrap();
+            assert_eq!(format!("5339359912{}", quadrant), mesh.code());
+            assert_eq!(mesh5, mesh.mesh5());
+        }
+    }
+
+    #[test]
+    fn mesh6_from_parts_err() {
+        let mesh5 = Mesh5::new(String::from("5339359912")).unwrap();
+        for quadrant in [0, 5] {
+            assert!(matches!(
+                Mesh6::from_parts(&mesh5, quadrant),
+                Err(GSJPError::OutOfRange(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn mesh6_quadrant_ok() {
+        for quadrant in 1..=4 {
+            let mesh = Mesh6::new(format!("5339359912{}", quadrant)).unwrap();
+            assert_eq!(quadrant, mesh.quadrant());
+        }
+    }
 
     #[test]
     fn mesh6_new_ok() {
@@ -206,12 +437,57 @@ mod tests {
     }
 
     #[test]
-    fn mesh6_new_err() {
-        let codes = vec!["53393599110", "53393599115"];
+    fn mesh6_is_valid_ok() {
+        for code in ["53393599111", "53393599444"] {
+            assert!(Mesh6::is_valid(code), "{}", code);
+            assert!(Mesh6::new(String::from(code)).is_ok(), "{}", code);
+        }
+        for code in ["53393599110", "53393599115", "5339359911"] {
+            assert!(!Mesh6::is_valid(code), "{}", code);
+            assert!(Mesh6::new(String::from(code)).is_err(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn mesh6_bytes_ok() {
+        let mesh = Mesh6::new(String::from("53393599444")).unwrap();
+        assert_eq!([0x0C, 0x6E, 0x81, 0xAB, 0xD4], mesh.to_bytes());
+        // 途中に0を含むメッシュコード
+        let codes = ["30180000111", "53393599444", "71497799444"];
         for code in codes {
-            let mesh = Mesh6::new(code.to_string());
-            assert!(mesh.is_err(), "{}", code);
+            let mesh = Mesh6::new(code.to_string()).unwrap();
+            assert_eq!(
+                mesh,
+                Mesh6::from_bytes(mesh.to_bytes()).unwrap(),
+                "{}",
+                code
+            );
         }
+        // バイト列はメッシュコードの順番に並ぶ
+        let bytes = codes.map(|code| Mesh6::new(code.to_string()).unwrap().to_bytes());
+        assert!(bytes.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn mesh6_from_bytes_err() {
+        assert!(Mesh6::from_bytes([0; 5]).is_err());
+        assert!(Mesh6::from_bytes([0xFF; 5]).is_err());
+    }
+
+    #[test]
+    fn mesh6_into_string_ok() {
+        let mesh = Mesh6::new(String::from("53393599432")).unwrap();
+        // `mesh`はムーブされるため、以降で使用できない
+        let code = String::from(mesh);
+        assert_eq!("53393599432", code);
+    }
+
+    #[test]
+    fn mesh6_code_len_ok() {
+        let mesh = Mesh6::new(String::from("53393599111")).unwrap();
+        assert_eq!(11, Mesh6::CODE_LEN);
+        assert_eq!(mesh.code().len(), Mesh6::CODE_LEN);
+        assert_eq!(MeshLevel::Mesh6.code_len(), Mesh6::CODE_LEN);
     }
 
     #[test]
@@ -242,6 +518,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mesh6_from_coordinate_err_message() {
+        // 経度のみが範囲外
+        let coord = Coordinate::new(35.0, 117.5).unwrap();
+        match Mesh6::from_coordinate(coord) {
+            Err(GSJPError::OutOfRange(message)) => {
+                assert!(message.contains("経度"), "{}", message);
+                assert!(!message.contains("緯度"), "{}", message);
+            }
+            result => panic!("{:?}", result),
+        }
+    }
+
+    #[test]
+    fn mesh6_alternate_code_ok() {
+        let code = "53393599143";
+        let alternate = "53393599001110";
+        let mesh = Mesh6::new(code.to_string()).unwrap();
+        assert_eq!(alternate, mesh.to_alternate_code());
+        let mesh = Mesh6::from_alternate_code(alternate).unwrap();
+        assert_eq!(code, mesh.code());
+    }
+
+    #[test]
+    fn mesh6_code_formatted_ok() {
+        let mesh = Mesh6::new(String::from("53393599111")).unwrap();
+        assert_eq!("5339-35-99-1-1-1", mesh.code_formatted());
+    }
+
+    #[test]
+    fn mesh6_u64_ok() {
+        let mesh = Mesh6::new(String::from("30180000111")).unwrap();
+        assert_eq!(30180000111, mesh.as_u64());
+        assert_eq!(mesh, Mesh6::from_u64(30180000111).unwrap());
+        assert!(Mesh6::from_u64(53393599).is_err());
+    }
+
     #[test]
     fn mesh6_north_ok() {
         let south = 35.65833333;
@@ -349,6 +662,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mesh6_center_extreme_ok() {
+        // 最北東端の8分の1地域メッシュ
+        let mesh = Mesh6::new(String::from("71497799444")).unwrap();
+        let center = mesh.center();
+        let expected = 48.0 - MESH6_LAT_DIFF / 2.0;
+        assert!(
+            eq_f64(expected, center.lat()),
+            "expected: {}, actual: {}",
+            expected,
+            center.lat()
+        );
+        let expected = 150.0 - MESH6_LON_DIFF / 2.0;
+        assert!(
+            eq_f64(expected, center.lon()),
+            "expected: {}, actual: {}",
+            expected,
+            center.lon()
+        );
+        let north_east = mesh.north_east();
+        assert!(eq_f64(48.0, north_east.lat()));
+        assert!(eq_f64(150.0, north_east.lon()));
+    }
+
     #[test]
     fn mesh6_north_east_ok() {
         let code = String::from("53393599111");
@@ -531,6 +868,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mesh6_parent_mesh_ok() {
+        let code = "53393599342";
+        let mesh = Mesh6::new(String::from(code)).unwrap();
+        assert_eq!(&code[0..4], mesh.mesh1().code());
+        assert_eq!(&code[0..6], mesh.mesh2().code());
+        assert_eq!(&code[0..8], mesh.mesh3().code());
+        assert_eq!(&code[0..9], mesh.mesh4().code());
+        assert_eq!(&code[0..10], mesh.mesh5().code());
+    }
+
+    #[test]
+    fn mesh6_quadkey_ok() {
+        let mesh = Mesh6::new(String::from("53393599342")).unwrap();
+        let key = mesh.quadkey().unwrap();
+        assert_eq!("342", key);
+        let restored = Mesh6::from_quadkey(&mesh.mesh3(), &key).unwrap();
+        assert_eq!(mesh, restored);
+    }
+
+    #[test]
+    fn mesh6_to_level_ok() {
+        let mesh = Mesh6::new(String::from("53393599342")).unwrap();
+        let inputs = vec![
+            (MeshLevel::Mesh1, "5339"),
+            (MeshLevel::Mesh2, "533935"),
+            (MeshLevel::Mesh3, "53393599"),
+            (MeshLevel::Mesh4, "533935993"),
+            (MeshLevel::Mesh5, "5339359934"),
+            (MeshLevel::Mesh6, "53393599342"),
+        ];
+        for (level, expected) in inputs {
+            let parent = mesh.to_level(level).unwrap();
+            assert_eq!(level, parent.level(), "{:?}", level);
+            assert_eq!(expected, parent.code(), "{:?}", level);
+        }
+    }
+
     #[test]
     fn mesh6_is_neighbor_none() {
         let inputs = vec![
@@ -0,0 +1,213 @@
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+
+use crate::mesh::{Coordinate, GSJPError, Mesh};
+
+/// 重複のないメッシュの集合
+///
+/// メッシュは、メッシュコードの順番で格納する。
+/// 座標のイテレーターから`collect`すると、座標を含むメッシュの集合を作成する。
+/// このとき、標準地域メッシュの範囲外の座標は無視する。
+/// 範囲外の座標をエラーとする場合は、`MeshSet::try_from_coordinates`を使用する。
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeshSet<M: Mesh> {
+    /// メッシュコードをキーとしたメッシュ
+    meshes: BTreeMap<String, M>,
+}
+
+impl<M: Mesh> MeshSet<M> {
+    /// 空のメッシュの集合を作成する。
+    ///
+    /// # 戻り値
+    ///
+    /// 空のメッシュの集合
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            meshes: BTreeMap::new(),
+        }
+    }
+
+    /// 座標を含むメッシュの集合を作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `coords` - 座標
+    ///
+    /// # 戻り値
+    ///
+    /// 座標を含むメッシュの集合。標準地域メッシュの範囲外の座標がある場合は、最初の範囲外の座標のエラー
+    pub fn try_from_coordinates(
+        coords: impl IntoIterator<Item = Coordinate>,
+    ) -> Result<Self, GSJPError> {
+        let mut set = Self::new();
+        for coord in coords {
+            set.insert(M::from_coordinate(coord)?);
+        }
+
+        Ok(set)
+    }
+
+    /// メッシュを追加する。
+    ///
+    /// # 引数
+    ///
+    /// * `mesh` - メッシュ
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュを追加した場合は`true`、既に同じメッシュがある場合は`false`
+    pub fn insert(&mut self, mesh: M) -> bool {
+        if self.meshes.contains_key(mesh.code()) {
+            return false;
+        }
+        self.meshes.insert(mesh.code().to_string(), mesh);
+
+        true
+    }
+
+    /// メッシュが集合に含まれるか確認する。
+    ///
+    /// # 引数
+    ///
+    /// * `mesh` - メッシュ
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュが含まれる場合は`true`、含まれない場合は`false`
+    #[must_use]
+    pub fn contains(&self, mesh: &M) -> bool {
+        self.meshes.contains_key(mesh.code())
+    }
+
+    /// メッシュの数を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュの数
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.meshes.len()
+    }
+
+    /// 集合が空か確認する。
+    ///
+    /// # 戻り値
+    ///
+    /// 空の場合は`true`、そうでない場合は`false`
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.meshes.is_empty()
+    }
+
+    /// メッシュをメッシュコードの順番で返すイテレーターを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュのイテレーター
+    pub fn iter(&self) -> impl Iterator<Item = &M> {
+        self.meshes.values()
+    }
+}
+
+impl<M: Mesh> Default for MeshSet<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: Mesh> FromIterator<Coordinate> for MeshSet<M> {
+    /// 座標を含むメッシュの集合を作成する。
+    ///
+    /// 標準地域メッシュの範囲外の座標は無視する。
+    fn from_iter<I: IntoIterator<Item = Coordinate>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for mesh in iter
+            .into_iter()
+            .filter_map(|coord| M::from_coordinate(coord).ok())
+        {
+            set.insert(mesh);
+        }
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::{Mesh1, Mesh3};
+
+    fn coords() -> Vec<Coordinate> {
+        vec![
+            // 東京タワー（53393599）
+            Coordinate::new(35.658581, 139.745433).unwrap(),
+            // 東京タワーと同じ基準地域メッシュ
+            Coordinate::new(35.6590, 139.7460).unwrap(),
+            // 東京スカイツリー（53394654）
+            Coordinate::new(35.710063, 139.8107).unwrap(),
+            // 大阪城（52350422）
+            Coordinate::new(34.687315, 135.526201).unwrap(),
+            // 東京タワーと同じ基準地域メッシュ
+            Coordinate::new(35.6584, 139.7450).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn mesh_set_from_iter_ok() {
+        let set = coords().into_iter().collect::<MeshSet<Mesh3>>();
+        assert_eq!(3, set.len());
+        assert!(!set.is_empty());
+        assert!(set.contains(&Mesh3::new(String::from("53393599")).unwrap()));
+        assert!(!set.contains(&Mesh3::new(String::from("53393598")).unwrap()));
+        // メッシュコードの順番
+        let codes = set.iter().map(|mesh| mesh.code()).collect::<Vec<_>>();
+        let expected = [
+            Mesh3::from_coordinate(coords()[3]).unwrap(),
+            Mesh3::from_coordinate(coords()[0]).unwrap(),
+            Mesh3::from_coordinate(coords()[2]).unwrap(),
+        ];
+        assert_eq!(
+            expected.iter().map(|mesh| mesh.code()).collect::<Vec<_>>(),
+            codes
+        );
+        // 第1次地域区画
+        let set = coords().into_iter().collect::<MeshSet<Mesh1>>();
+        assert_eq!(2, set.len());
+    }
+
+    #[test]
+    fn mesh_set_from_iter_skips_out_of_range() {
+        let mut coords = coords();
+        coords.push(Coordinate::new(10.0, 139.745433).unwrap());
+        let set = coords.into_iter().collect::<MeshSet<Mesh3>>();
+        assert_eq!(3, set.len());
+        // 空の集合
+        let set = Vec::<Coordinate>::new()
+            .into_iter()
+            .collect::<MeshSet<Mesh3>>();
+        assert!(set.is_empty());
+        assert_eq!(MeshSet::default(), set);
+    }
+
+    #[test]
+    fn mesh_set_try_from_coordinates_ok() {
+        let set = MeshSet::<Mesh3>::try_from_coordinates(coords()).unwrap();
+        assert_eq!(coords().into_iter().collect::<MeshSet<Mesh3>>(), set);
+    }
+
+    #[test]
+    fn mesh_set_try_from_coordinates_err() {
+        let mut coords = coords();
+        coords.insert(1, Coordinate::new(10.0, 139.745433).unwrap());
+        assert!(MeshSet::<Mesh3>::try_from_coordinates(coords).is_err());
+    }
+
+    #[test]
+    fn mesh_set_insert_ok() {
+        let mut set = MeshSet::new();
+        assert!(set.insert(Mesh3::new(String::from("53393599")).unwrap()));
+        assert!(!set.insert(Mesh3::new(String::from("53393599")).unwrap()));
+        assert_eq!(1, set.len());
+    }
+}
@@ -1,18 +1,64 @@
-use std::borrow::Cow;
+//! 地域メッシュ
+//!
+//! # 代替の番号体系
+//!
+//! 分割地域メッシュのメッシュコードは、基準地域メッシュのメッシュコードに、分割番号（1から4）を
+//! 1桁ずつ連結する。
+//! 一部のデータセットで使用される代替の番号体系では、分割番号を、南北方向の位置（南が0、北が1）と
+//! 東西方向の位置（西が0、東が1）を連結した2桁の番号で表す。
+//! `Mesh4`、`Mesh5`及び`Mesh6`の`to_alternate_code`と`from_alternate_code`で相互に変換する。
+//!
+//! | 分割番号 | 位置 | 代替の番号 |
+//! |----------|------|------------|
+//! | 1        | 南西 | 00         |
+//! | 2        | 南東 | 01         |
+//! | 3        | 北西 | 10         |
+//! | 4        | 北東 | 11         |
 
+use alloc::borrow::Cow;
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::ops::RangeInclusive;
+
+use crate::math;
+
+mod any_mesh;
+mod grid;
 mod mesh1;
 mod mesh2;
+mod mesh2x;
 mod mesh3;
 mod mesh4;
 mod mesh5;
+mod mesh5x;
 mod mesh6;
+mod mesh_set;
+#[cfg(feature = "mvt")]
+mod mvt;
 
+pub use any_mesh::{
+    from_official_display, mesh_dedup, parent_code, parse_expecting, parse_tagged, validate_codes,
+    AnyMesh,
+};
+#[cfg(feature = "rayon")]
+pub use grid::par_meshes_in_bbox;
+pub use grid::{
+    meshes_along_line, meshes_crossing_polygon, meshes_in_bbox, meshes_within_distance,
+    range_query, snap_bbox_to_grid, total_mesh_count, CodeGrid, MeshGrid, MeshRange,
+};
 pub use mesh1::Mesh1;
 pub use mesh2::Mesh2;
+pub use mesh2x::Mesh2x;
 pub use mesh3::Mesh3;
 pub use mesh4::Mesh4;
 pub use mesh5::Mesh5;
+pub use mesh5x::Mesh5x;
 pub use mesh6::Mesh6;
+pub use mesh_set::MeshSet;
+#[cfg(feature = "mvt")]
+pub use mvt::{MvtFeature, TileCoord, MVT_EXTENT};
 
 /// メッシュの北端の緯度（度単位: GRIB2範囲）
 pub const NORTHERNMOST: f64 = 48.0;
@@ -23,7 +69,75 @@ pub const EASTERNMOST: f64 = 150.0;
 /// メッシュの西端の経度（度単位: GRIB2範囲）
 pub const WESTERNMOST: f64 = 118.0;
 
+/// 地球の平均半径（m単位）
+///
+/// 国際測地学協会（IUGG）が定義する、GRS80楕円体の平均半径である。
+pub const EARTH_RADIUS: f64 = 6_371_008.8;
+
+/// WGS84楕円体の表面積と等しい表面積を持つ球の半径（m単位）
+pub const WGS84_AUTHALIC_RADIUS: f64 = 6_371_007.181;
+
+/// 距離を計算するときに地球とみなす球
+///
+/// 既定値は、半径`EARTH_RADIUS`の球（`EarthModel::SphereMean`）である。
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum EarthModel {
+    /// 半径`EARTH_RADIUS`の球
+    #[default]
+    SphereMean,
+    /// 半径`WGS84_AUTHALIC_RADIUS`の球
+    Wgs84Authalic,
+    /// 指定した半径（m単位）の球
+    Custom(f64),
+}
+
+impl EarthModel {
+    /// 球の半径を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 球の半径（m単位）
+    #[must_use]
+    pub const fn radius(&self) -> f64 {
+        match self {
+            EarthModel::SphereMean => EARTH_RADIUS,
+            EarthModel::Wgs84Authalic => WGS84_AUTHALIC_RADIUS,
+            EarthModel::Custom(radius) => *radius,
+        }
+    }
+}
+
+/// 座標の測地系
+///
+/// 標準地域メッシュは、日本測地系2011（JGD2011）の緯度と経度で定義されている。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Datum {
+    /// 日本測地系2011（JGD2011）
+    Jgd2011,
+    /// 旧日本測地系（Tokyo Datum）
+    Tokyo,
+}
+
+/// 座標がメッシュの境界上にあるとみなす許容誤差（度単位、約0.01mm）
+///
+/// `Mesh::from_coordinate`は、境界の南側または西側でこの許容誤差以内にある座標を、
+/// 境界上にあるとみなして北側または東側のメッシュに含める。
+pub const BOUNDARY_EPSILON: f64 = 1e-10;
+
 /// メッシュトレイト
+///
+/// メッシュの範囲は、南端と西端を含み、北端と東端を含まない半開区間である。
+/// このため、メッシュの北端または東端上の座標は、北または東に隣接するメッシュに含まれる。
+///
+/// メッシュは`Send`と`Sync`を実装するため、スレッド間で受け渡しや共有ができる。
+///
+/// ```
+/// use gsjp::mesh::{Mesh, Mesh3};
+///
+/// let mesh = Mesh3::new(String::from("53393599")).unwrap();
+/// let handle = std::thread::spawn(move || mesh.north_mesh().unwrap());
+/// assert_eq!("53394509", handle.join().unwrap().code());
+/// ```
 pub trait Mesh: Sized {
     /// メッシュを作成する。
     ///
@@ -36,8 +150,65 @@ pub trait Mesh: Sized {
     /// メッシュ
     fn new(code: String) -> Result<Self, GSJPError>;
 
+    /// メッシュコードを整数で返す。
+    ///
+    /// メッシュコードの先頭2桁は第1次地域区画の緯度部分（30から71）であり0で始まらないため、
+    /// 整数に変換してもメッシュコードの桁は失われない。
+    ///
+    /// # 戻り値
+    ///
+    /// 整数のメッシュコード
+    #[must_use]
+    fn as_u64(&self) -> u64 {
+        // メッシュコードは検証済みで、すべて数字
+        self.code().parse().unwrap()
+    }
+
+    /// 整数のメッシュコードからメッシュを作成する。
+    ///
+    /// 整数を10進数の文字列に変換して、`new`と同様にメッシュコードを検証する。
+    /// 0で始まるメッシュコードはないため、0で埋める必要はない。
+    ///
+    /// # 引数
+    ///
+    /// * `code` - 整数のメッシュコード
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュ
+    fn from_u64(code: u64) -> Result<Self, GSJPError> {
+        Self::new(code.to_string())
+    }
+
+    /// 前後の空白や接頭辞を含むメッシュコードからメッシュを作成する。
+    ///
+    /// メッシュコードの前後にあるASCIIの空白文字（スペース、タブ、改行など）を取り除いた後で、
+    /// 先頭の1文字がASCIIのアルファベットの場合は、その1文字を接頭辞とみなして取り除く。
+    /// それ以外の文字は取り除かずに、`new`と同様にメッシュコードを検証する。
+    ///
+    /// # 引数
+    ///
+    /// * `code` - メッシュコード
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュ
+    fn new_lenient(code: &str) -> Result<Self, GSJPError> {
+        let code = code.trim_matches(|c: char| c.is_ascii_whitespace());
+        let code = match code.chars().next() {
+            Some(c) if c.is_ascii_alphabetic() => &code[1..],
+            _ => code,
+        };
+
+        Self::new(code.to_string())
+    }
+
     /// 指定された座標を含むメッシュを作成する。
     ///
+    /// メッシュの境界上の座標は、北側または東側のメッシュに含める。
+    /// 浮動小数点数の誤差で境界上の座標が南側または西側のメッシュに含まれないように、
+    /// 境界から`BOUNDARY_EPSILON`以内の座標は境界上にあるとみなす。
+    ///
     /// # 引数
     ///
     /// * `coord` - 座標
@@ -47,18 +218,108 @@ pub trait Mesh: Sized {
     /// メッシュ
     fn from_coordinate(coord: Coordinate) -> Result<Self, GSJPError>;
 
+    /// 指定された許容誤差で、座標を含むメッシュを作成する。
+    ///
+    /// 境界の南側または西側で`eps`以内にある座標を、境界上にあるとみなして北側または東側のメッシュに含める。
+    /// 境界の北側または東側にある座標は、境界からの距離によらず北側または東側のメッシュに含める。
+    /// 座標の計算過程の誤差が`BOUNDARY_EPSILON`より大きい場合に、誤差に合わせた許容誤差を指定する。
+    /// `eps`には、メッシュの辺の長さより十分に小さい0以上の値を指定する。
+    /// `eps`が`BOUNDARY_EPSILON`より小さい場合は`BOUNDARY_EPSILON`を許容誤差とするため、
+    /// `from_coordinate`と同じメッシュを返す。
+    /// 全国の範囲の北端または東端から`eps`以内の座標は、北側または東側にメッシュがないため、
+    /// その方向には許容誤差を適用しない。
+    ///
+    /// # 引数
+    ///
+    /// * `coord` - 座標
+    /// * `eps` - 境界上にあるとみなす許容誤差（度単位）
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュ
+    fn from_coordinate_eps(coord: Coordinate, eps: f64) -> Result<Self, GSJPError> {
+        debug_assert!(0.0 <= eps, "許容誤差({})が負です。", eps);
+        contains_coordinate(&coord)?;
+        // `from_coordinate`は`BOUNDARY_EPSILON`の許容誤差を適用するため、残りの許容誤差だけ
+        // 座標を北東に移動して、境界の南側または西側で許容誤差以内の座標を境界の北側または東側に移す
+        let shift = (eps - BOUNDARY_EPSILON).max(0.0);
+        // 全国の範囲の北端と東端の近くでは、北側と東側にメッシュがないため移動しない
+        let lat = coord.lat() + shift;
+        let lat = if lat < NORTHERNMOST { lat } else { coord.lat() };
+        let lon = coord.lon() + shift;
+        let lon = if lon < EASTERNMOST { lon } else { coord.lon() };
+
+        Self::from_coordinate(Coordinate::new(lat, lon)?)
+    }
+
     /// メッシュコードを返す。
     ///
     /// # 戻り値
     ///
     /// メッシュコード
+    #[must_use]
     fn code(&self) -> &str;
 
+    /// メッシュを消費して、メッシュコードを返す。
+    ///
+    /// `code`と異なり、メッシュが保持するメッシュコードを複製せずに返す。
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュコード
+    #[must_use]
+    fn into_code(self) -> String;
+
+    /// メッシュコードを、レベルの区切りごとにハイフンで区切った文字列で返す。
+    ///
+    /// メッシュコードは、第1次地域区画（4桁）、第2次地域区画（2桁）、基準地域メッシュ（2桁）、
+    /// 分割地域メッシュの分割番号（1桁ずつ）に区切る。
+    ///
+    /// | レベル | 例 |
+    /// |--------|----|
+    /// | 第1次地域区画 | `5339` |
+    /// | 第2次地域区画 | `5339-35` |
+    /// | 基準地域メッシュ | `5339-35-99` |
+    /// | 2分の1地域メッシュ | `5339-35-99-1` |
+    /// | 4分の1地域メッシュ | `5339-35-99-1-1` |
+    /// | 8分の1地域メッシュ | `5339-35-99-1-1-1` |
+    ///
+    /// # 戻り値
+    ///
+    /// ハイフンで区切ったメッシュコード
+    #[must_use]
+    fn code_formatted(&self) -> String {
+        let code = self.code();
+        let mut parts = vec![&code[0..4]];
+        let mut start = 4;
+        while start < code.len() {
+            // 第2次地域区画と基準地域メッシュは2桁、分割地域メッシュは1桁
+            let len = if start < 8 { 2 } else { 1 };
+            parts.push(&code[start..start + len]);
+            start += len;
+        }
+
+        parts.join("-")
+    }
+
+    /// メッシュコードを、公的な文書で使用される、ハイフンで区切った表記で返す。
+    ///
+    /// 表記は`code_formatted`と同じで、`from_official_display`でメッシュに戻せる。
+    ///
+    /// # 戻り値
+    ///
+    /// ハイフンで区切ったメッシュコード
+    #[must_use]
+    fn to_official_display(&self) -> String {
+        self.code_formatted()
+    }
+
     /// メッシュの北端を度単位で返す。
     ///
     /// # 戻り値
     ///
     /// メッシュの北端の緯度
+    #[must_use]
     fn north(&self) -> f64;
 
     /// メッシュの東端を度単位で返す。
@@ -66,6 +327,7 @@ pub trait Mesh: Sized {
     /// # 戻り値
     ///
     /// メッシュの東端の経度
+    #[must_use]
     fn east(&self) -> f64;
 
     /// メッシュの南端を度単位で返す。
@@ -73,6 +335,7 @@ pub trait Mesh: Sized {
     /// # 戻り値
     ///
     /// メッシュの南端の緯度
+    #[must_use]
     fn south(&self) -> f64;
 
     /// メッシュの西端を度単位で返す。
@@ -80,6 +343,7 @@ pub trait Mesh: Sized {
     /// # 戻り値
     ///
     /// メッシュの西端の経度
+    #[must_use]
     fn west(&self) -> f64;
 
     /// メッシュの中心の座標を返す。
@@ -87,246 +351,1694 @@ pub trait Mesh: Sized {
     /// # 戻り値
     ///
     /// メッシュの中心の座標
+    #[must_use]
     fn center(&self) -> Coordinate {
         let lat = (self.north() + self.south()) / 2.0;
         let lon = (self.east() + self.west()) / 2.0;
 
-        Coordinate::new(lat, lon).unwrap()
+        // 検証済みのメッシュは北緯20度から48度、東経118度から150度の範囲に収まるため、
+        // メッシュの中心の緯度と経度は常に座標の範囲内にある
+        Coordinate::new_unchecked(lat, lon)
     }
 
-    /// メッシュの北東端の座標を返す。
+    /// メッシュの中心の座標を、平面直角座標系などの座標に変換して返す。
+    ///
+    /// 投影法の実装をクレートに含めないように、座標の変換は呼び出し元が指定する。
+    ///
+    /// # 引数
+    ///
+    /// * `transform` - 緯度と経度の座標を、X座標とY座標に変換する関数
     ///
     /// # 戻り値
     ///
-    /// メッシュの北東端の座標
-    fn north_east(&self) -> Coordinate {
-        Coordinate::new(self.north(), self.east()).unwrap()
+    /// 変換したメッシュの中心の座標（X座標, Y座標）
+    #[must_use]
+    fn center_xy(&self, transform: impl Fn(Coordinate) -> (f64, f64)) -> (f64, f64) {
+        transform(self.center())
     }
 
-    /// メッシュの南東端の座標を返す。
+    /// メッシュの東西方向の長さをm単位で返す。
+    ///
+    /// 地球を半径`EARTH_RADIUS`の球とみなし、メッシュの中心の緯度で計算した近似値である。
     ///
     /// # 戻り値
     ///
-    /// メッシュの南東端の座標
-    fn south_east(&self) -> Coordinate {
-        Coordinate::new(self.south(), self.east()).unwrap()
+    /// メッシュの東西方向の長さ（m単位）
+    #[must_use]
+    fn width_m(&self) -> f64 {
+        self.width_m_with(EarthModel::default())
     }
 
-    /// メッシュの南西端の座標を返す。
+    /// 指定した球で、メッシュの東西方向の長さをm単位で返す。
+    ///
+    /// # 引数
+    ///
+    /// * `model` - 地球とみなす球
     ///
     /// # 戻り値
     ///
-    /// メッシュの南西端の座標
-    fn south_west(&self) -> Coordinate {
-        Coordinate::new(self.south(), self.west()).unwrap()
+    /// メッシュの東西方向の長さ（m単位）
+    #[must_use]
+    fn width_m_with(&self, model: EarthModel) -> f64 {
+        let lat = self.center().lat().to_radians();
+
+        (self.east() - self.west()).to_radians() * model.radius() * math::cos(lat)
     }
 
-    /// メッシュの北西端の座標を返す。
+    /// メッシュの南北方向の長さをm単位で返す。
+    ///
+    /// 地球を半径`EARTH_RADIUS`の球とみなして計算した近似値である。
     ///
     /// # 戻り値
     ///
-    /// メッシュの北西端の座標
-    fn north_west(&self) -> Coordinate {
-        Coordinate::new(self.north(), self.west()).unwrap()
+    /// メッシュの南北方向の長さ（m単位）
+    #[must_use]
+    fn height_m(&self) -> f64 {
+        self.height_m_with(EarthModel::default())
     }
 
-    /// 北隣のメッシュを返す。
+    /// 指定した球で、メッシュの南北方向の長さをm単位で返す。
     ///
-    /// # 戻り値
+    /// # 引数
     ///
-    /// 北隣のメッシュ
-    fn north_mesh(&self) -> Result<Self, GSJPError>;
-
-    /// 東隣のメッシュを返す。
+    /// * `model` - 地球とみなす球
     ///
     /// # 戻り値
     ///
-    /// 東隣のメッシュ
-    fn east_mesh(&self) -> Result<Self, GSJPError>;
+    /// メッシュの南北方向の長さ（m単位）
+    #[must_use]
+    fn height_m_with(&self, model: EarthModel) -> f64 {
+        (self.north() - self.south()).to_radians() * model.radius()
+    }
 
-    /// 南隣のメッシュを返す。
+    /// メッシュの面積を平方m単位で返す。
+    ///
+    /// メッシュを長方形とみなし、`width_m`と`height_m`の積で計算した近似値である。
+    /// メッシュは南北で東西方向の長さが異なるが、その差は考慮しない。
     ///
     /// # 戻り値
     ///
-    /// 南隣のメッシュ
-    fn south_mesh(&self) -> Result<Self, GSJPError>;
+    /// メッシュの面積（平方m単位）
+    #[must_use]
+    fn area_m2(&self) -> f64 {
+        self.width_m() * self.height_m()
+    }
 
-    /// 西隣のメッシュを返す。
+    /// 座標からメッシュの最も近い辺までの距離をm単位で返す。
+    ///
+    /// メッシュの中心の緯度で経度の差を距離に換算する、平面近似による値である。
+    /// 座標がメッシュの外部にある場合は0を返す。
+    ///
+    /// # 引数
+    ///
+    /// * `coord` - 座標
     ///
     /// # 戻り値
     ///
-    /// 西隣のメッシュ
-    fn west_mesh(&self) -> Result<Self, GSJPError>;
+    /// 座標からメッシュの最も近い辺までの距離（m単位）
+    #[must_use]
+    fn distance_to_edge_m(&self, coord: &Coordinate) -> f64 {
+        self.distance_to_edge_m_with(coord, EarthModel::default())
+    }
 
-    /// 北東隣のメッシュを返す。
+    /// 指定した球で、座標からメッシュの最も近い辺までの距離をm単位で返す。
+    ///
+    /// # 引数
+    ///
+    /// * `coord` - 座標
+    /// * `model` - 地球とみなす球
     ///
     /// # 戻り値
     ///
-    /// 北東隣のメッシュ
-    fn north_east_mesh(&self) -> Result<Self, GSJPError> {
-        self.north_mesh()?.east_mesh()
+    /// 座標からメッシュの最も近い辺までの距離（m単位）
+    #[must_use]
+    fn distance_to_edge_m_with(&self, coord: &Coordinate, model: EarthModel) -> f64 {
+        let lat_m = 1.0_f64.to_radians() * model.radius();
+        let lon_m = lat_m * math::cos(self.center().lat().to_radians());
+        let distances = [
+            (self.north() - coord.lat()) * lat_m,
+            (coord.lat() - self.south()) * lat_m,
+            (self.east() - coord.lon()) * lon_m,
+            (coord.lon() - self.west()) * lon_m,
+        ];
+
+        distances.into_iter().fold(f64::INFINITY, f64::min).max(0.0)
     }
 
-    /// 南東隣のメッシュを返す。
+    /// メッシュの中心から、南北方向と東西方向にm単位で移動した座標を含むメッシュを返す。
+    ///
+    /// 地球を半径`EARTH_RADIUS`の球とみなし、メッシュの中心の緯度で距離を緯度と経度の差に換算する、
+    /// 平面近似による値である。このため、移動する距離が長いほど誤差が大きくなる。
+    ///
+    /// # 引数
+    ///
+    /// * `north_m` - 北方向に移動する距離（m単位）。南方向に移動する場合は負の値
+    /// * `east_m` - 東方向に移動する距離（m単位）。西方向に移動する場合は負の値
     ///
     /// # 戻り値
     ///
-    /// 南東隣のメッシュ
-    fn south_east_mesh(&self) -> Result<Self, GSJPError> {
-        self.south_mesh()?.east_mesh()
+    /// 移動した座標を含むメッシュ。移動した座標が標準地域メッシュの範囲外の場合はエラー
+    fn offset_m(&self, north_m: f64, east_m: f64) -> Result<Self, GSJPError> {
+        self.offset_m_with(north_m, east_m, EarthModel::default())
     }
 
-    /// 南西隣のメッシュを返す。
+    /// 指定した球で、メッシュの中心から南北方向と東西方向にm単位で移動した座標を含むメッシュを返す。
+    ///
+    /// # 引数
+    ///
+    /// * `north_m` - 北方向に移動する距離（m単位）。南方向に移動する場合は負の値
+    /// * `east_m` - 東方向に移動する距離（m単位）。西方向に移動する場合は負の値
+    /// * `model` - 地球とみなす球
     ///
     /// # 戻り値
     ///
-    /// 南西隣のメッシュ
-    fn south_west_mesh(&self) -> Result<Self, GSJPError> {
-        self.south_mesh()?.west_mesh()
+    /// 移動した座標を含むメッシュ。移動した座標が標準地域メッシュの範囲外の場合はエラー
+    fn offset_m_with(
+        &self,
+        north_m: f64,
+        east_m: f64,
+        model: EarthModel,
+    ) -> Result<Self, GSJPError> {
+        let center = self.center();
+        let lat_m = 1.0_f64.to_radians() * model.radius();
+        let lon_m = lat_m * math::cos(center.lat().to_radians());
+        let coord = Coordinate::new(
+            center.lat() + north_m / lat_m,
+            center.lon() + east_m / lon_m,
+        )?;
+
+        Self::from_coordinate(coord)
     }
 
-    /// 北西隣のメッシュを返す。
+    /// メッシュ内の座標の相対的な位置を返す。
+    ///
+    /// メッシュの南西端を`(0.0, 0.0)`、北東端を`(1.0, 1.0)`として、座標の位置を返す。
+    /// メッシュに含まれる座標の場合、それぞれの値は0以上1未満である。
+    /// メッシュの外部にある座標は制限せず、0未満または1以上の値を返す。
+    ///
+    /// # 引数
+    ///
+    /// * `coord` - 座標
     ///
     /// # 戻り値
     ///
-    /// 北西隣のメッシュ
-    fn north_west_mesh(&self) -> Result<Self, GSJPError> {
-        self.north_mesh()?.west_mesh()
+    /// 座標の相対的な位置（経度方向の位置, 緯度方向の位置）
+    #[must_use]
+    fn normalized_position(&self, coord: &Coordinate) -> (f64, f64) {
+        (
+            (coord.lon() - self.west()) / (self.east() - self.west()),
+            (coord.lat() - self.south()) / (self.north() - self.south()),
+        )
     }
 
-    /// メッシュが隣り合っているか確認する。
+    /// 座標がメッシュに含まれるか確認する。
     ///
-    /// 北東、南東、南西及び北西隣のメッシュは隣り合っていないと判定する。
+    /// メッシュの範囲は、南端と西端を含み、北端と東端を含まない半開区間である。
+    /// `from_coordinate`と同様に、境界の南側または西側で`BOUNDARY_EPSILON`以内の座標は境界上にあるとみなす。
+    /// このため、`from_coordinate`で作成したメッシュは、常に作成に使用した座標を含む。
     ///
     /// # 引数
     ///
-    /// * `mesh` - 隣り合っているか確認するメッシュ。
+    /// * `coord` - 座標
     ///
     /// # 戻り値
     ///
-    /// メッシュが隣り合っているかを示す`NeighborDirection`列挙型。
-    fn is_neighboring(&self, mesh: &Self) -> Result<NeighborDirection, GSJPError> {
-        if self.north_mesh()?.code() == mesh.code() {
-            return Ok(NeighborDirection::North);
-        } else if self.east_mesh()?.code() == mesh.code() {
-            return Ok(NeighborDirection::East);
-        } else if self.south_mesh()?.code() == mesh.code() {
-            return Ok(NeighborDirection::South);
-        } else if self.west_mesh()?.code() == mesh.code() {
-            return Ok(NeighborDirection::West);
-        }
+    /// 座標がメッシュに含まれる場合は`true`、含まれない場合は`false`
+    #[must_use]
+    fn contains(&self, coord: &Coordinate) -> bool {
+        let lat = coord.lat() + BOUNDARY_EPSILON;
+        let lon = coord.lon() + BOUNDARY_EPSILON;
 
-        Ok(NeighborDirection::None)
+        self.south() <= lat && lat < self.north() && self.west() <= lon && lon < self.east()
     }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-/// 隣にあるメッシュの方向
-pub enum NeighborDirection {
-    /// 隣り合っていない
-    None,
-    /// 北側に隣り合っている
-    North,
-    /// 東側に隣り合っている
-    East,
-    /// 南側に隣り合っている
-    South,
-    /// 西側に隣り合っている
-    West,
-}
 
-/// 座標
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
-pub struct Coordinate {
-    /// 緯度（度単位）
-    lat: f64,
-    /// 経度（度単位）
-    lon: f64,
-}
+    // メッシュの頂点は、メッシュの中心と同様に常に座標の範囲内にあるため、
+    // 北東端、南東端、南西端及び北西端の座標は検証せずに作成する
 
-impl Coordinate {
-    /// 緯度と経度から座標を作成する。
+    /// メッシュの北東端の座標を返す。
     ///
-    /// # 引数
+    /// # 戻り値
     ///
-    /// * `lat` - 緯度（度単位）
-    /// * `lon` - 経度（度単位）
+    /// メッシュの北東端の座標
+    #[must_use]
+    fn north_east(&self) -> Coordinate {
+        Coordinate::new_unchecked(self.north(), self.east())
+    }
+
+    /// メッシュの南東端の座標を返す。
     ///
     /// # 戻り値
     ///
-    /// 座標
-    pub fn new(lat: f64, lon: f64) -> Result<Self, GSJPError> {
-        let lat = validate_lat(lat)?;
-        let lon = validate_lon(lon)?;
+    /// メッシュの南東端の座標
+    #[must_use]
+    fn south_east(&self) -> Coordinate {
+        Coordinate::new_unchecked(self.south(), self.east())
+    }
 
-        Ok(Self { lat, lon })
+    /// メッシュの南西端の座標を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュの南西端の座標
+    #[must_use]
+    fn south_west(&self) -> Coordinate {
+        Coordinate::new_unchecked(self.south(), self.west())
     }
 
-    /// 座標の緯度を度単位で返す。
+    /// メッシュの北西端の座標を返す。
     ///
     /// # 戻り値
     ///
-    /// 座標の緯度
-    pub fn lat(self) -> f64 {
-        self.lat
+    /// メッシュの北西端の座標
+    #[must_use]
+    fn north_west(&self) -> Coordinate {
+        Coordinate::new_unchecked(self.north(), self.west())
     }
 
-    /// 座標の経度を度単位で返す。
+    /// メッシュの四隅の座標を返す。
+    ///
+    /// 四隅の座標は、北西端、北東端、南東端、南西端の順（時計回り）に格納する。
     ///
     /// # 戻り値
     ///
-    /// 座標の経度
-    pub fn lon(self) -> f64 {
-        self.lon
+    /// メッシュの四隅の座標
+    #[must_use]
+    fn corners(&self) -> [Coordinate; 4] {
+        [
+            self.north_west(),
+            self.north_east(),
+            self.south_east(),
+            self.south_west(),
+        ]
     }
-}
 
-/// GSJPエラー
-#[derive(thiserror::Error, Debug)]
-pub enum GSJPError {
-    /// 座標が範囲外
-    #[error("{0}")]
-    OutOfRange(Cow<'static, str>),
-    /// メッシュコードが不正
-    #[error("メッシュコードが不正です。")]
-    InvalidMeshCode,
-}
+    /// メッシュを`geo`クレートのポリゴンに変換する。
+    ///
+    /// ポリゴンの座標は（経度, 緯度）の順で、外側のリングを南西端から反時計回りに閉じる。
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュのポリゴン
+    #[cfg(feature = "geo")]
+    #[must_use]
+    fn to_geo_polygon(&self) -> geo::Polygon<f64> {
+        let ring = [
+            self.south_west(),
+            self.south_east(),
+            self.north_east(),
+            self.north_west(),
+            self.south_west(),
+        ]
+        .into_iter()
+        .map(geo::Coord::from)
+        .collect();
 
-/// 緯度を検証する。
-///
-/// # 引数
-///
-/// * `lat` - 緯度（度単位）
+        geo::Polygon::new(geo::LineString::new(ring), vec![])
+    }
+
+    /// メッシュを、Webメルカトル投影のタイルのMVT形式の地物に変換する。
+    ///
+    /// メッシュの四隅の座標をタイル内の座標に投影して、タイルの範囲に切り抜く。
+    /// Webメルカトル投影でもメッシュの辺は東西方向と南北方向に平行であるため、
+    /// 切り抜いたポリゴンは常に4つの頂点を持つ。
+    ///
+    /// # 引数
+    ///
+    /// * `tile` - タイルの座標
+    ///
+    /// # 戻り値
+    ///
+    /// MVT形式の地物。メッシュがタイルと重ならない場合は`None`
+    #[cfg(feature = "mvt")]
+    #[must_use]
+    fn to_mvt_feature(&self, tile: &TileCoord) -> Option<MvtFeature> {
+        // 北西端、北東端、南東端、南西端の順で、タイル内の座標では時計回りになる
+        let ring = self.corners().map(|coord| mvt::project(&coord, tile));
+        let extent = f64::from(MVT_EXTENT);
+        let ((left, top), (right, bottom)) = (ring[0], ring[2]);
+        if right <= 0.0 || extent <= left || bottom <= 0.0 || extent <= top {
+            return None;
+        }
+        let clip = |value: f64| math::round(value.clamp(0.0, extent)) as i32;
+
+        Some(MvtFeature {
+            code: self.code().to_string(),
+            ring: ring.iter().map(|&(x, y)| (clip(x), clip(y))).collect(),
+        })
+    }
+
+    /// メッシュのメッシュコードと各端の緯度と経度を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュの情報
+    #[must_use]
+    fn info(&self) -> MeshInfo {
+        MeshInfo {
+            code: self.code().to_string(),
+            north: self.north(),
+            south: self.south(),
+            west: self.west(),
+            east: self.east(),
+        }
+    }
+
+    /// メッシュを消費して、メッシュのメッシュコードと各端の緯度と経度を返す。
+    ///
+    /// `info`と異なり、メッシュコードを複製しない。
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュの情報
+    #[must_use]
+    fn into_info(self) -> MeshInfo {
+        let (north, south, west, east) = (self.north(), self.south(), self.west(), self.east());
+
+        MeshInfo {
+            code: self.into_code(),
+            north,
+            south,
+            west,
+            east,
+        }
+    }
+
+    /// メッシュのメッシュコード、レベル、各端の緯度と経度、中心の座標、幅と高さを、
+    /// 人が読める複数行の文字列で返す。
+    ///
+    /// 診断用の出力であり、文字列の形式は変更されることがある。
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュを説明する文字列
+    #[must_use]
+    fn describe(&self) -> String {
+        let center = self.center();
+        format!(
+            "メッシュコード: {}\nレベル: {}\n北端: {}\n南端: {}\n東端: {}\n西端: {}\n中心: ({}, {})\n幅: 約{:.1}m\n高さ: 約{:.1}m",
+            self.code(),
+            self.level().name(),
+            self.north(),
+            self.south(),
+            self.east(),
+            self.west(),
+            center.lat(),
+            center.lon(),
+            self.width_m(),
+            self.height_m(),
+        )
+    }
+
+    /// メッシュの情報からメッシュを作成する。
+    ///
+    /// メッシュの情報のメッシュコードからメッシュを作成し、各端の緯度と経度は使用しない。
+    ///
+    /// # 引数
+    ///
+    /// * `info` - メッシュの情報
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュ
+    fn from_info(info: &MeshInfo) -> Result<Self, GSJPError> {
+        Self::new(info.code.clone())
+    }
+
+    /// 北隣のメッシュを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 北隣のメッシュ
+    fn north_mesh(&self) -> Result<Self, GSJPError>;
+
+    /// 東隣のメッシュを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 東隣のメッシュ
+    fn east_mesh(&self) -> Result<Self, GSJPError>;
+
+    /// 南隣のメッシュを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 南隣のメッシュ
+    fn south_mesh(&self) -> Result<Self, GSJPError>;
+
+    /// 西隣のメッシュを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 西隣のメッシュ
+    fn west_mesh(&self) -> Result<Self, GSJPError>;
+
+    /// 指定された方向に隣り合うメッシュを返す。
+    ///
+    /// 方向を実行時に決定する場合に使用する。
+    /// `NeighborDirection::None`を指定した場合は、メッシュと同じメッシュを返す。
+    ///
+    /// # 引数
+    ///
+    /// * `dir` - 隣り合う方向
+    ///
+    /// # 戻り値
+    ///
+    /// 指定された方向に隣り合うメッシュ
+    fn neighbor(&self, dir: NeighborDirection) -> Result<Self, GSJPError> {
+        match dir {
+            NeighborDirection::None => Self::new(self.code().to_string()),
+            NeighborDirection::North => self.north_mesh(),
+            NeighborDirection::East => self.east_mesh(),
+            NeighborDirection::South => self.south_mesh(),
+            NeighborDirection::West => self.west_mesh(),
+        }
+    }
+
+    /// 北東隣のメッシュを返す。
+    ///
+    /// 北隣のメッシュの東隣のメッシュを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 北東隣のメッシュ。北隣または東隣のメッシュが範囲外の場合は、範囲外になった方向を示すエラー
+    fn north_east_mesh(&self) -> Result<Self, GSJPError> {
+        let north = self
+            .north_mesh_opt()
+            .ok_or_else(|| neighbor_out_of_range("北"))?;
+
+        north
+            .east_mesh_opt()
+            .ok_or_else(|| neighbor_out_of_range("東"))
+    }
+
+    /// 南東隣のメッシュを返す。
+    ///
+    /// 南隣のメッシュの東隣のメッシュを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 南東隣のメッシュ。南隣または東隣のメッシュが範囲外の場合は、範囲外になった方向を示すエラー
+    fn south_east_mesh(&self) -> Result<Self, GSJPError> {
+        let south = self
+            .south_mesh_opt()
+            .ok_or_else(|| neighbor_out_of_range("南"))?;
+
+        south
+            .east_mesh_opt()
+            .ok_or_else(|| neighbor_out_of_range("東"))
+    }
+
+    /// 南西隣のメッシュを返す。
+    ///
+    /// 南隣のメッシュの西隣のメッシュを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 南西隣のメッシュ。南隣または西隣のメッシュが範囲外の場合は、範囲外になった方向を示すエラー
+    fn south_west_mesh(&self) -> Result<Self, GSJPError> {
+        let south = self
+            .south_mesh_opt()
+            .ok_or_else(|| neighbor_out_of_range("南"))?;
+
+        south
+            .west_mesh_opt()
+            .ok_or_else(|| neighbor_out_of_range("西"))
+    }
+
+    /// 北西隣のメッシュを返す。
+    ///
+    /// 北隣のメッシュの西隣のメッシュを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 北西隣のメッシュ。北隣または西隣のメッシュが範囲外の場合は、範囲外になった方向を示すエラー
+    fn north_west_mesh(&self) -> Result<Self, GSJPError> {
+        let north = self
+            .north_mesh_opt()
+            .ok_or_else(|| neighbor_out_of_range("北"))?;
+
+        north
+            .west_mesh_opt()
+            .ok_or_else(|| neighbor_out_of_range("西"))
+    }
+
+    /// 北隣のメッシュを返す。
+    ///
+    /// メッシュが標準地域メッシュの範囲の北端にあり、北隣のメッシュが存在しない場合は`None`を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 北隣のメッシュ。北隣のメッシュが範囲外の場合は`None`
+    fn north_mesh_opt(&self) -> Option<Self> {
+        if self.north() >= NORTHERNMOST - BOUNDARY_EPSILON {
+            return None;
+        }
+
+        // 範囲内の隣のメッシュは常に作成できる
+        self.north_mesh().ok()
+    }
+
+    /// 東隣のメッシュを返す。
+    ///
+    /// メッシュが標準地域メッシュの範囲の東端にあり、東隣のメッシュが存在しない場合は`None`を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 東隣のメッシュ。東隣のメッシュが範囲外の場合は`None`
+    fn east_mesh_opt(&self) -> Option<Self> {
+        if self.east() >= EASTERNMOST - BOUNDARY_EPSILON {
+            return None;
+        }
+
+        // 範囲内の隣のメッシュは常に作成できる
+        self.east_mesh().ok()
+    }
+
+    /// 南隣のメッシュを返す。
+    ///
+    /// メッシュが標準地域メッシュの範囲の南端にあり、南隣のメッシュが存在しない場合は`None`を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 南隣のメッシュ。南隣のメッシュが範囲外の場合は`None`
+    fn south_mesh_opt(&self) -> Option<Self> {
+        if self.south() <= SOUTHERNMOST + BOUNDARY_EPSILON {
+            return None;
+        }
+
+        // 範囲内の隣のメッシュは常に作成できる
+        self.south_mesh().ok()
+    }
+
+    /// 西隣のメッシュを返す。
+    ///
+    /// メッシュが標準地域メッシュの範囲の西端にあり、西隣のメッシュが存在しない場合は`None`を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 西隣のメッシュ。西隣のメッシュが範囲外の場合は`None`
+    fn west_mesh_opt(&self) -> Option<Self> {
+        if self.west() <= WESTERNMOST + BOUNDARY_EPSILON {
+            return None;
+        }
+
+        // 範囲内の隣のメッシュは常に作成できる
+        self.west_mesh().ok()
+    }
+
+    /// 指定された数だけ東西方向と南北方向に移動した位置のメッシュを返す。
+    ///
+    /// # 引数
+    ///
+    /// * `east` - 東方向に移動するメッシュの数。負の場合は西方向に移動する。
+    /// * `north` - 北方向に移動するメッシュの数。負の場合は南方向に移動する。
+    ///
+    /// # 戻り値
+    ///
+    /// 移動した位置のメッシュ。移動した位置が標準地域メッシュの範囲外の場合はエラー
+    fn offset(&self, east: i64, north: i64) -> Result<Self, GSJPError>
+    where
+        Self: Clone,
+    {
+        // 範囲外に移動する場合に、メッシュを1つずつ移動する前にエラーを返す
+        let center = self.center();
+        let lat = center.lat() + (self.north() - self.south()) * north as f64;
+        let lon = center.lon() + (self.east() - self.west()) * east as f64;
+        if !(SOUTHERNMOST..NORTHERNMOST).contains(&lat)
+            || !(WESTERNMOST..EASTERNMOST).contains(&lon)
+        {
+            return Err(GSJPError::OutOfRange(
+                "移動した位置のメッシュが範囲外です。".into(),
+            ));
+        }
+
+        let mut mesh = self.clone();
+        for _ in 0..east.unsigned_abs() {
+            mesh = if 0 < east {
+                mesh.east_mesh()?
+            } else {
+                mesh.west_mesh()?
+            };
+        }
+        for _ in 0..north.unsigned_abs() {
+            mesh = if 0 < north {
+                mesh.north_mesh()?
+            } else {
+                mesh.south_mesh()?
+            };
+        }
+
+        Ok(mesh)
+    }
+
+    /// メッシュが隣り合っているか確認する。
+    ///
+    /// 北東、南東、南西及び北西隣のメッシュは隣り合っていないと判定する。
+    ///
+    /// # 引数
+    ///
+    /// * `mesh` - 隣り合っているか確認するメッシュ。
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュが隣り合っているかを示す`NeighborDirection`列挙型。
+    fn is_neighboring(&self, mesh: &Self) -> Result<NeighborDirection, GSJPError> {
+        if self.north_mesh()?.code() == mesh.code() {
+            return Ok(NeighborDirection::North);
+        } else if self.east_mesh()?.code() == mesh.code() {
+            return Ok(NeighborDirection::East);
+        } else if self.south_mesh()?.code() == mesh.code() {
+            return Ok(NeighborDirection::South);
+        } else if self.west_mesh()?.code() == mesh.code() {
+            return Ok(NeighborDirection::West);
+        }
+
+        Ok(NeighborDirection::None)
+    }
+
+    /// メッシュのクアッドキーを返す。
+    ///
+    /// 2分の1地域メッシュ、4分の1地域メッシュ及び8分の1地域メッシュは、上位のメッシュを
+    /// 南北及び東西に2等分して分割番号（1から4）を付与するため、基準地域メッシュより
+    /// 下位の分割番号を連結した文字列をクアッドキーとする。
+    ///
+    /// # 戻り値
+    ///
+    /// クアッドキー。第1次地域区画、第2次地域区画及び基準地域メッシュの場合は`None`
+    #[must_use]
+    fn quadkey(&self) -> Option<String> {
+        // 基準地域メッシュのメッシュコードは8桁
+        let code = self.code();
+        if code.len() <= 8 {
+            return None;
+        }
+
+        Some(code[8..].to_string())
+    }
+
+    /// 基準地域メッシュとクアッドキーからメッシュを作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `mesh3` - メッシュを含む基準地域メッシュ
+    /// * `key` - クアッドキー
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュ
+    fn from_quadkey(mesh3: &Mesh3, key: &str) -> Result<Self, GSJPError> {
+        Self::new(format!("{}{}", mesh3.code(), key))
+    }
+
+    /// メッシュのレベルを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュのレベル
+    #[must_use]
+    fn level(&self) -> MeshLevel {
+        // メッシュコードは検証済みであるため、メッシュコードの長さからレベルを決定できる
+        MeshLevel::from_code_len(self.code().len()).unwrap()
+    }
+
+    /// メッシュのレベルの名前を前置したメッシュコードを返す。
+    ///
+    /// `Mesh3:53393599`のように、メッシュのレベルの名前とメッシュコードを`:`で連結する。
+    /// `parse_tagged`で、メッシュに戻せる。
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュのレベルの名前を前置したメッシュコード
+    #[must_use]
+    fn tagged_code(&self) -> String {
+        format!("{}:{}", self.level().name(), self.code())
+    }
+
+    /// 上位のメッシュ内の、メッシュの南北方向と東西方向の位置を返す。
+    ///
+    /// 位置は、上位のメッシュの南端と西端から数えて0から始まる。
+    ///
+    /// | メッシュ | 上位のメッシュ | 位置 |
+    /// |----------|----------------|------|
+    /// | 第1次地域区画 | 全国の範囲 | 南北0から41、東西0から31 |
+    /// | 第2次地域区画 | 第1次地域区画 | 0から7 |
+    /// | 基準地域メッシュ | 第2次地域区画 | 0から9 |
+    /// | 分割地域メッシュ | 上位のメッシュ | 0または1 |
+    ///
+    /// # 戻り値
+    ///
+    /// 上位のメッシュ内の位置（南北方向の位置, 東西方向の位置）
+    #[must_use]
+    fn index_in_parent(&self) -> (u8, u8) {
+        // メッシュコードは検証済みであるため、数字として解析できる
+        let digit = |i: usize| self.code().as_bytes()[i] - b'0';
+        match self.level() {
+            MeshLevel::Mesh1 => (10 * digit(0) + digit(1) - 30, 10 * digit(2) + digit(3) - 18),
+            MeshLevel::Mesh2 => (digit(4), digit(5)),
+            MeshLevel::Mesh3 => (digit(6), digit(7)),
+            MeshLevel::Mesh4 | MeshLevel::Mesh5 | MeshLevel::Mesh6 => {
+                let division = digit(self.code().len() - 1) - 1;
+                (division / 2, division % 2)
+            }
+        }
+    }
+
+    /// メッシュの位置を表すZ階数曲線（モートン順序）のインデックスを返す。
+    ///
+    /// 全国の範囲の南端と西端から数えた、メッシュのレベルでの南北方向の位置（行）と東西方向の位置（列）の
+    /// ビットを交互に並べて、空間的に近いメッシュのインデックスが近くなるようにする。
+    ///
+    /// | ビット | 内容 |
+    /// |--------|------|
+    /// | 60から63 | メッシュのレベル（第1次地域区画が1、8分の1地域メッシュが6） |
+    /// | 0から59 | 行と列のビットを交互に並べた値（偶数ビットが列、奇数ビットが行） |
+    ///
+    /// 行と列の位置は、8分の1地域メッシュでも15ビットに収まる。
+    /// 上位のビットにレベルを格納するため、インデックスは同じレベルのメッシュ同士で比較でき、
+    /// 異なるレベルのメッシュのインデックスが一致することはない。
+    ///
+    /// # 戻り値
+    ///
+    /// Z階数曲線のインデックス
+    #[must_use]
+    fn z_index(&self) -> u64 {
+        // メッシュコードは検証済みであるため、数字として解析できる
+        let code = self.code().as_bytes();
+        let digit = |i: usize| u64::from(code[i] - b'0');
+        let mut row = 10 * digit(0) + digit(1) - 30;
+        let mut col = 10 * digit(2) + digit(3) - 18;
+        let level = self.level();
+        if MeshLevel::Mesh2 <= level {
+            row = row * 8 + digit(4);
+            col = col * 8 + digit(5);
+        }
+        if MeshLevel::Mesh3 <= level {
+            row = row * 10 + digit(6);
+            col = col * 10 + digit(7);
+        }
+        // 分割地域メッシュの分割番号は、南西が1、南東が2、北西が3、北東が4
+        for i in MeshLevel::Mesh3.code_len()..code.len() {
+            let division = digit(i) - 1;
+            row = row * 2 + division / 2;
+            col = col * 2 + division % 2;
+        }
+        let interleaved = (0..30).fold(0_u64, |acc, bit| {
+            acc | ((col >> bit) & 1) << (2 * bit) | ((row >> bit) & 1) << (2 * bit + 1)
+        });
+
+        (level as u64 + 1) << 60 | interleaved
+    }
+
+    /// 指定されたレベルで、メッシュを含むメッシュを返す。
+    ///
+    /// メッシュと同じレベルを指定した場合は、メッシュと同じメッシュを返す。
+    ///
+    /// # 引数
+    ///
+    /// * `level` - メッシュのレベル
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュを含むメッシュ。メッシュより細かいレベルを指定した場合はエラー
+    fn to_level(&self, level: MeshLevel) -> Result<AnyMesh, GSJPError> {
+        if self.level() < level {
+            return Err(GSJPError::InvalidMeshLevel);
+        }
+
+        AnyMesh::new(self.code()[0..level.code_len()].to_string())
+    }
+
+    /// メッシュが別のメッシュを含むか確認する。
+    ///
+    /// 標準地域メッシュのメッシュコードは階層的であり、メッシュを含む上位のメッシュのメッシュコードは、
+    /// メッシュのメッシュコードの先頭と一致する。
+    /// このため、メッシュコードの前方一致で判定する。
+    ///
+    /// 同じレベルのメッシュの場合は、同じメッシュである場合に`true`を返す。
+    /// メッシュより細かいレベルのメッシュは、別のメッシュを含まないため`false`を返す。
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 別のメッシュ
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュが別のメッシュを含む場合は`true`、含まない場合は`false`
+    #[must_use]
+    fn contains_mesh(&self, other: &impl Mesh) -> bool {
+        if other.level() < self.level() {
+            return false;
+        }
+
+        other.code().starts_with(self.code())
+    }
+
+    /// メッシュと別のメッシュが、レベルを問わず同じ場所のメッシュであるか確認する。
+    ///
+    /// 短い方のメッシュコードの長さまで、2つのメッシュコードが一致する場合に`true`を返す。
+    /// つまり、一方のメッシュが他方のメッシュを含む場合、または同じメッシュである場合に`true`を返す。
+    /// `contains_mesh`と異なり、メッシュと別のメッシュを入れ替えても結果は変わらない。
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 別のメッシュ
+    ///
+    /// # 戻り値
+    ///
+    /// 同じ場所のメッシュである場合は`true`、そうでない場合は`false`
+    #[must_use]
+    fn is_same_cell(&self, other: &impl Mesh) -> bool {
+        self.contains_mesh(other) || other.contains_mesh(self)
+    }
+
+    /// メッシュを指定されたレベルまで分割したメッシュを返す。
+    ///
+    /// メッシュは、南から北、西から東の順に、上位のメッシュごとにまとめて返す。
+    /// 例えば、基準地域メッシュを8分の1地域メッシュまで分割すると、64個のメッシュを返す。
+    ///
+    /// # 引数
+    ///
+    /// * `level` - 分割後のメッシュのレベル
+    ///
+    /// # 戻り値
+    ///
+    /// 分割したメッシュ。メッシュより細かいレベルを指定しなかった場合はエラー
+    fn subdivide_to(&self, level: MeshLevel) -> Result<Vec<AnyMesh>, GSJPError> {
+        if level <= self.level() {
+            return Err(GSJPError::InvalidMeshLevel);
+        }
+
+        let mut codes = vec![self.code().to_string()];
+        while codes[0].len() < level.code_len() {
+            // 上位のメッシュのメッシュコードに付ける、下位のメッシュの番号
+            let suffixes: Vec<String> = match MeshLevel::from_code_len(codes[0].len()).unwrap() {
+                MeshLevel::Mesh1 => (0..64).map(|i| format!("{}{}", i / 8, i % 8)).collect(),
+                MeshLevel::Mesh2 => (0..100).map(|i| format!("{:02}", i)).collect(),
+                _ => ["1", "2", "3", "4"].map(String::from).to_vec(),
+            };
+            codes = codes
+                .iter()
+                .flat_map(|code| {
+                    suffixes
+                        .iter()
+                        .map(move |suffix| format!("{}{}", code, suffix))
+                })
+                .collect::<Vec<_>>();
+        }
+
+        // 分割したメッシュのメッシュコードは、上位のメッシュのメッシュコードに有効な番号を付けたものである
+        Ok(codes
+            .into_iter()
+            .map(|code| AnyMesh::new(code).unwrap())
+            .collect())
+    }
+
+    /// メッシュを指定されたレベルまで分割したメッシュを、1つずつ作成するイテレーターを返す。
+    ///
+    /// `subdivide_to`と同じ順番でメッシュを返すが、分割したメッシュをまとめて作成しないため、
+    /// 細かいレベルまで分割しても使用するメモリは増えない。
+    /// 最初に返すメッシュは、メッシュの南西端のメッシュである。
+    ///
+    /// # 引数
+    ///
+    /// * `level` - 分割後のメッシュのレベル
+    ///
+    /// # 戻り値
+    ///
+    /// 分割したメッシュを返すイテレーター。メッシュより細かいレベルを指定しなかった場合は、
+    /// メッシュを返さないイテレーター
+    fn children_to(&self, level: MeshLevel) -> impl Iterator<Item = AnyMesh> {
+        const LEVELS: [MeshLevel; 5] = [
+            MeshLevel::Mesh2,
+            MeshLevel::Mesh3,
+            MeshLevel::Mesh4,
+            MeshLevel::Mesh5,
+            MeshLevel::Mesh6,
+        ];
+        // 上位のメッシュ1つに含まれる、指定されたレベルのメッシュの数
+        let cells = |level: MeshLevel| match level {
+            MeshLevel::Mesh1 => unreachable!(),
+            MeshLevel::Mesh2 => 64,
+            MeshLevel::Mesh3 => 100,
+            _ => 4,
+        };
+        let levels = LEVELS
+            .into_iter()
+            .filter(|l| self.level() < *l && *l <= level)
+            .collect::<Vec<_>>();
+        let count = if levels.is_empty() {
+            0
+        } else {
+            levels.iter().map(|&l| cells(l)).product::<usize>()
+        };
+        let code = self.code().to_string();
+
+        (0..count).map(move |i| {
+            // 分割したメッシュの位置を、各レベルの番号に分解して、メッシュコードに付ける
+            let mut child = String::with_capacity(level.code_len());
+            child.push_str(&code);
+            let mut rest = count;
+            let mut push_digit = |d: usize| child.push(char::from(b'0' + d as u8));
+            for &l in &levels {
+                rest /= cells(l);
+                let n = i / rest % cells(l);
+                match l {
+                    MeshLevel::Mesh2 => {
+                        push_digit(n / 8);
+                        push_digit(n % 8);
+                    }
+                    MeshLevel::Mesh3 => {
+                        push_digit(n / 10);
+                        push_digit(n % 10);
+                    }
+                    _ => push_digit(n + 1),
+                }
+            }
+
+            // 分割したメッシュのメッシュコードは、上位のメッシュのメッシュコードに有効な番号を付けたものである
+            AnyMesh::new(child).unwrap()
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// 隣にあるメッシュの方向
+pub enum NeighborDirection {
+    /// 隣り合っていない
+    None,
+    /// 北側に隣り合っている
+    North,
+    /// 東側に隣り合っている
+    East,
+    /// 南側に隣り合っている
+    South,
+    /// 西側に隣り合っている
+    West,
+}
+
+/// メッシュのレベル
+///
+/// 粗いレベルほど小さくなるように順序付ける。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MeshLevel {
+    /// 第1次地域区画
+    Mesh1,
+    /// 第2次地域区画
+    Mesh2,
+    /// 基準地域メッシュ（第3次地域区画）
+    Mesh3,
+    /// 2分の1地域メッシュ（分割地域メッシュ）
+    Mesh4,
+    /// 4分の1地域メッシュ（分割地域メッシュ）
+    Mesh5,
+    /// 8分の1地域メッシュ（分割地域メッシュ）
+    Mesh6,
+}
+
+impl MeshLevel {
+    /// メッシュコードの長さを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュコードの長さ
+    #[must_use]
+    pub const fn code_len(self) -> usize {
+        match self {
+            MeshLevel::Mesh1 => 4,
+            MeshLevel::Mesh2 => 6,
+            MeshLevel::Mesh3 => 8,
+            MeshLevel::Mesh4 => 9,
+            MeshLevel::Mesh5 => 10,
+            MeshLevel::Mesh6 => 11,
+        }
+    }
+
+    /// メッシュコードの長さからメッシュのレベルを返す。
+    ///
+    /// # 引数
+    ///
+    /// * `len` - メッシュコードの長さ
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュのレベル。該当するレベルがない場合は`None`
+    #[must_use]
+    pub const fn from_code_len(len: usize) -> Option<Self> {
+        match len {
+            4 => Some(MeshLevel::Mesh1),
+            6 => Some(MeshLevel::Mesh2),
+            8 => Some(MeshLevel::Mesh3),
+            9 => Some(MeshLevel::Mesh4),
+            10 => Some(MeshLevel::Mesh5),
+            11 => Some(MeshLevel::Mesh6),
+            _ => None,
+        }
+    }
+
+    /// メッシュのレベルの名前を返す。
+    ///
+    /// 名前は、列挙子の名前（`Mesh1`から`Mesh6`）と同じである。
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュのレベルの名前
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            MeshLevel::Mesh1 => "Mesh1",
+            MeshLevel::Mesh2 => "Mesh2",
+            MeshLevel::Mesh3 => "Mesh3",
+            MeshLevel::Mesh4 => "Mesh4",
+            MeshLevel::Mesh5 => "Mesh5",
+            MeshLevel::Mesh6 => "Mesh6",
+        }
+    }
+
+    /// メッシュのレベルの名前からメッシュのレベルを返す。
+    ///
+    /// # 引数
+    ///
+    /// * `name` - メッシュのレベルの名前（`Mesh1`から`Mesh6`）
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュのレベル。該当するレベルがない場合は`None`
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Mesh1" => Some(MeshLevel::Mesh1),
+            "Mesh2" => Some(MeshLevel::Mesh2),
+            "Mesh3" => Some(MeshLevel::Mesh3),
+            "Mesh4" => Some(MeshLevel::Mesh4),
+            "Mesh5" => Some(MeshLevel::Mesh5),
+            "Mesh6" => Some(MeshLevel::Mesh6),
+            _ => None,
+        }
+    }
+}
+
+/// 座標
+///
+/// 導出した`==`は緯度と経度を厳密に比較するため、浮動小数点数の誤差を許容して比較する場合は
+/// `approx_eq`を使用する。
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Coordinate {
+    /// 緯度（度単位）
+    lat: f64,
+    /// 経度（度単位）
+    lon: f64,
+}
+
+impl Coordinate {
+    /// 緯度と経度から座標を作成する。
+    ///
+    /// 汎用的な座標として、緯度が-90度から90度、経度が-180度から180度の範囲であれば作成できる。
+    /// このため、`-179.0`のような日付変更線の西側の経度など、標準地域メッシュの範囲外の座標も作成でき、
+    /// その座標からメッシュを作成する時点でエラーになる。
+    /// 標準地域メッシュの範囲内の座標だけを作成する場合は、`new_strict`を使用する。
+    ///
+    /// # 引数
+    ///
+    /// * `lat` - 緯度（度単位）
+    /// * `lon` - 経度（度単位）
+    ///
+    /// # 戻り値
+    ///
+    /// 座標
+    pub fn new(lat: f64, lon: f64) -> Result<Self, GSJPError> {
+        let lat = validate_lat(lat)?;
+        let lon = validate_lon(lon)?;
+
+        Ok(Self { lat, lon })
+    }
+
+    /// 緯度と経度から、標準地域メッシュの範囲内の座標を作成する。
+    ///
+    /// 座標が標準地域メッシュの範囲内でない場合はエラーとする。範囲は`contains_coordinate`と同じで、
+    /// 北端の緯度`NORTHERNMOST`と東端の経度`EASTERNMOST`は含まない。
+    ///
+    /// # 引数
+    ///
+    /// * `lat` - 緯度（度単位）
+    /// * `lon` - 経度（度単位）
+    ///
+    /// # 戻り値
+    ///
+    /// 座標
+    pub fn new_strict(lat: f64, lon: f64) -> Result<Self, GSJPError> {
+        let coord = Self::new(lat, lon)?;
+        contains_coordinate(&coord)?;
+
+        Ok(coord)
+    }
+
+    /// 緯度と経度を検証せずに座標を作成する。
+    ///
+    /// 緯度と経度が範囲内であることが分かっている、性能が重要な処理で使用する。
+    /// 呼び出し元は、緯度が-90度から90度、経度が-180度から180度の範囲内であることを保証しなければならない。
+    /// 範囲外の値を渡した場合、デバッグビルドではパニックし、リリースビルドでは範囲外の座標を作成する。
+    /// 範囲外の座標を使用した場合の結果は保証しない。
+    ///
+    /// # 引数
+    ///
+    /// * `lat` - 緯度（度単位）
+    /// * `lon` - 経度（度単位）
+    ///
+    /// # 戻り値
+    ///
+    /// 座標
+    pub fn new_unchecked(lat: f64, lon: f64) -> Self {
+        debug_assert!((-90.0..=90.0).contains(&lat), "緯度が範囲外です({})。", lat);
+        debug_assert!(
+            (-180.0..=180.0).contains(&lon),
+            "経度が範囲外です({})。",
+            lon
+        );
+
+        Self { lat, lon }
+    }
+
+    /// 緯度と経度から定数として使用できる座標を作成する。
+    ///
+    /// `const`文脈で使用できるように、緯度または経度が範囲外の場合はパニックする。
+    /// `const`文脈で範囲外の値を渡した場合は、コンパイルエラーになる。
+    ///
+    /// # 引数
+    ///
+    /// * `lat` - 緯度（度単位）
+    /// * `lon` - 経度（度単位）
+    ///
+    /// # 戻り値
+    ///
+    /// 座標
+    pub const fn new_const(lat: f64, lon: f64) -> Self {
+        if !(-90.0 <= lat && lat <= 90.0) {
+            panic!("緯度が範囲外です。");
+        }
+        if !(-180.0 <= lon && lon <= 180.0) {
+            panic!("経度が範囲外です。");
+        }
+
+        Self { lat, lon }
+    }
+
+    /// 座標の緯度を度単位で返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 座標の緯度
+    #[must_use]
+    pub const fn lat(self) -> f64 {
+        self.lat
+    }
+
+    /// 座標の経度を度単位で返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 座標の経度
+    #[must_use]
+    pub const fn lon(self) -> f64 {
+        self.lon
+    }
+
+    /// 度分秒形式の緯度と経度から座標を作成する。
+    ///
+    /// 度分秒形式は`35°39′31″N`のように、度、分及び秒の値の後に記号を付け、最後に半球を示す文字を付ける。
+    /// 分の記号には`′`または`'`、秒の記号には`″`または`"`を使用できる。
+    /// 分と秒は省略でき、秒には小数を指定できる。
+    /// 半球を示す文字は、緯度は`N`または`S`、経度は`E`または`W`で、`S`と`W`の場合は負の値とする。
+    /// 半球を示す文字を省略した場合は、`N`または`E`とみなす。
+    ///
+    /// # 引数
+    ///
+    /// * `lat_dms` - 度分秒形式の緯度
+    /// * `lon_dms` - 度分秒形式の経度
+    ///
+    /// # 戻り値
+    ///
+    /// 座標
+    pub fn from_dms(lat_dms: &str, lon_dms: &str) -> Result<Self, GSJPError> {
+        let lat = parse_dms(lat_dms, 'N', 'S')?;
+        let lon = parse_dms(lon_dms, 'E', 'W')?;
+
+        Self::new(lat, lon)
+    }
+
+    /// 座標を度分秒形式の緯度と経度に変換する。
+    ///
+    /// 秒は小数点以下2桁に丸める。
+    ///
+    /// # 戻り値
+    ///
+    /// 度分秒形式の緯度と経度（`35°39′30.89″N`, `139°44′43.56″E`）
+    #[must_use]
+    pub fn to_dms(self) -> (String, String) {
+        (
+            format_dms(self.lat, 'N', 'S'),
+            format_dms(self.lon, 'E', 'W'),
+        )
+    }
+
+    /// 座標と別の座標の間を線形補間した座標を返す。
+    ///
+    /// 緯度と経度をそれぞれ度単位で線形補間するため、測地線上の座標ではない。
+    /// 標準地域メッシュを扱う程度の距離であれば、測地線との差は小さい。
+    /// `t`に0から1の範囲外の値を指定した場合は、2つの座標を結ぶ直線の延長上の座標を返す。
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 別の座標
+    /// * `t` - 補間の割合（0のとき座標、1のとき別の座標）
+    ///
+    /// # 戻り値
+    ///
+    /// 補間した座標。補間した座標の緯度または経度が範囲外の場合はエラー
+    pub fn lerp(self, other: Coordinate, t: f64) -> Result<Self, GSJPError> {
+        Self::new(
+            self.lat + (other.lat - self.lat) * t,
+            self.lon + (other.lon - self.lon) * t,
+        )
+    }
+
+    /// 座標と別の座標の中点を返す。
+    ///
+    /// `lerp`と同様に、緯度と経度をそれぞれ度単位で平均する。
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 別の座標
+    ///
+    /// # 戻り値
+    ///
+    /// 中点の座標
+    #[must_use]
+    pub fn midpoint(self, other: Coordinate) -> Self {
+        // 範囲内の2つの座標の中点は、常に範囲内にある
+        Self::new_unchecked((self.lat + other.lat) / 2.0, (self.lon + other.lon) / 2.0)
+    }
+
+    /// 別の座標までの大円距離をm単位で返す。
+    ///
+    /// 地球を指定した球とみなし、ハーバーサイン公式で計算する。
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 別の座標
+    /// * `model` - 地球とみなす球
+    ///
+    /// # 戻り値
+    ///
+    /// 別の座標までの大円距離（m単位）
+    #[must_use]
+    pub fn distance_m(&self, other: &Coordinate, model: EarthModel) -> f64 {
+        let (lat1, lat2) = (self.lat.to_radians(), other.lat.to_radians());
+        let sin_dlat = math::sin((lat2 - lat1) / 2.0);
+        let sin_dlon = math::sin((other.lon - self.lon).to_radians() / 2.0);
+        let h = sin_dlat * sin_dlat + math::cos(lat1) * math::cos(lat2) * sin_dlon * sin_dlon;
+
+        2.0 * model.radius() * math::asin(math::sqrt(h.min(1.0)))
+    }
+
+    /// 誤差を許容して、別の座標と等しいか確認する。
+    ///
+    /// 緯度の差と経度の差の絶対値が、いずれも`epsilon`未満の場合に等しいとみなす。
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 別の座標
+    /// * `epsilon` - 許容する誤差（度単位）
+    ///
+    /// # 戻り値
+    ///
+    /// 別の座標と等しいとみなせる場合は`true`、そうでない場合は`false`
+    #[must_use]
+    pub fn approx_eq(&self, other: &Coordinate, epsilon: f64) -> bool {
+        (self.lat - other.lat).abs() < epsilon && (self.lon - other.lon).abs() < epsilon
+    }
+
+    /// 指定した測地系の座標を、日本測地系2011（JGD2011）の座標に変換する。
+    ///
+    /// 旧日本測地系の座標は、緯度と経度の1次式で近似する簡易的な変換式で変換する。
+    /// 変換の誤差は数m程度であり、測量などの精密な変換には、国土地理院の地域毎の変換パラメータを使用する。
+    /// また、日本測地系2000と日本測地系2011の差（東日本の一部で最大数m）は考慮しない。
+    ///
+    /// # 引数
+    ///
+    /// * `from` - 座標の測地系
+    ///
+    /// # 戻り値
+    ///
+    /// 日本測地系2011の座標。変換した座標が範囲外の場合はエラー
+    pub fn to_jgd2011(&self, from: Datum) -> Result<Self, GSJPError> {
+        match from {
+            Datum::Jgd2011 => Ok(*self),
+            Datum::Tokyo => {
+                let lat = self.lat - 0.00010695 * self.lat + 0.000017464 * self.lon + 0.0046017;
+                let lon = self.lon - 0.000046038 * self.lat - 0.000083043 * self.lon + 0.010040;
+
+                Self::new(lat, lon)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "geo")]
+impl From<Coordinate> for geo::Coord<f64> {
+    /// 座標を、x座標を経度、y座標を緯度とする`geo`クレートの座標に変換する。
+    fn from(coord: Coordinate) -> Self {
+        geo::Coord {
+            x: coord.lon,
+            y: coord.lat,
+        }
+    }
+}
+
+/// 度分秒形式の角度を度単位の角度に変換する。
+///
+/// # 引数
+///
+/// * `dms` - 度分秒形式の角度
+/// * `positive` - 正の値を示す半球の文字
+/// * `negative` - 負の値を示す半球の文字
+///
+/// # 戻り値
+///
+/// 度単位の角度
+fn parse_dms(dms: &str, positive: char, negative: char) -> Result<f64, GSJPError> {
+    let dms = dms.trim();
+    let (body, sign) = match dms.chars().last() {
+        Some(c) if c == positive => (&dms[..dms.len() - 1], 1.0),
+        Some(c) if c == negative => (&dms[..dms.len() - 1], -1.0),
+        _ => (dms, 1.0),
+    };
+
+    // 度、分及び秒の値を、それぞれの記号の前から取り出す
+    let symbols: [&[char]; 3] = [&['°'], &['′', '\''], &['″', '"']];
+    let mut values = [0.0; 3];
+    let mut rest = body.trim_end();
+    for (i, symbols) in symbols.iter().enumerate() {
+        if rest.is_empty() && 0 < i {
+            break;
+        }
+        let pos = rest
+            .find(|c| symbols.contains(&c))
+            .ok_or(GSJPError::InvalidDms)?;
+        values[i] = parse_dms_value(rest[..pos].trim())?;
+        let symbol_len = rest[pos..].chars().next().unwrap().len_utf8();
+        rest = rest[pos + symbol_len..].trim_start();
+    }
+    if !rest.is_empty() {
+        return Err(GSJPError::InvalidDms);
+    }
+    let [degrees, minutes, seconds] = values;
+    if 60.0 <= minutes || 60.0 <= seconds {
+        return Err(GSJPError::InvalidDms);
+    }
+
+    Ok(sign * (degrees + minutes / 60.0 + seconds / 3600.0))
+}
+
+/// 度分秒形式の度、分または秒の値を変換する。
+///
+/// 符号や指数表記を受け付けないように、数字と小数点のみを許可する。
+fn parse_dms_value(value: &str) -> Result<f64, GSJPError> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+        return Err(GSJPError::InvalidDms);
+    }
+
+    value.parse::<f64>().map_err(|_| GSJPError::InvalidDms)
+}
+
+/// 度単位の角度を度分秒形式に変換する。
+///
+/// # 引数
+///
+/// * `value` - 度単位の角度
+/// * `positive` - 正の値を示す半球の文字
+/// * `negative` - 負の値を示す半球の文字
+///
+/// # 戻り値
+///
+/// 度分秒形式の角度
+fn format_dms(value: f64, positive: char, negative: char) -> String {
+    let hemisphere = if value < 0.0 { negative } else { positive };
+    // 秒の繰り上がりを正しく扱うため、100分の1秒単位の整数に丸めてから分割する
+    let hundredths = math::round(value.abs() * 360_000.0) as u64;
+    let degrees = hundredths / 360_000;
+    let minutes = hundredths / 6_000 % 60;
+    let seconds = (hundredths % 6_000) as f64 / 100.0;
+
+    format!("{}°{}′{:.2}″{}", degrees, minutes, seconds, hemisphere)
+}
+
+/// メッシュの情報
+///
+/// メッシュのメッシュコードと各端の緯度と経度を保持する。
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeshInfo {
+    /// メッシュコード
+    pub code: String,
+    /// メッシュの北端の緯度（度単位）
+    pub north: f64,
+    /// メッシュの南端の緯度（度単位）
+    pub south: f64,
+    /// メッシュの西端の経度（度単位）
+    pub west: f64,
+    /// メッシュの東端の経度（度単位）
+    pub east: f64,
+}
+
+/// GSJPエラー
+#[derive(thiserror::Error, Debug)]
+pub enum GSJPError {
+    /// 座標が範囲外
+    #[error("{0}")]
+    OutOfRange(Cow<'static, str>),
+    /// メッシュコードが不正
+    #[error("メッシュコードが不正です。")]
+    InvalidMeshCode,
+    /// メッシュのレベルが不正
+    #[error("メッシュのレベルが不正です。")]
+    InvalidMeshLevel,
+    /// メッシュのレベルが期待したレベルと一致しない
+    #[error("メッシュのレベル({actual:?})が期待したレベル({expected:?})と一致しません。")]
+    MeshLevelMismatch {
+        /// 期待したメッシュのレベル
+        expected: MeshLevel,
+        /// メッシュコードから判定したメッシュのレベル
+        actual: MeshLevel,
+    },
+    /// 度分秒形式が不正
+    #[error("度分秒形式が不正です。")]
+    InvalidDms,
+}
+
+/// 緯度を検証する。
+///
+/// # 引数
+///
+/// * `lat` - 緯度（度単位）
+///
+/// # 戻り値
+///
+/// 緯度（度単位）
+fn validate_lat(lat: f64) -> Result<f64, GSJPError> {
+    if !(-90.0..=90.0).contains(&lat) {
+        return Err(GSJPError::OutOfRange("緯度が範囲外です。".into()));
+    }
+
+    Ok(lat)
+}
+
+/// 経度を検証する。
+///
+/// # 引数
+///
+/// * `lon` - 経度（度単位）
+///
+/// # 戻り値
+///
+/// 経度（度単位）
+fn validate_lon(lon: f64) -> Result<f64, GSJPError> {
+    if !(-180.0..=180.0).contains(&lon) {
+        return Err(GSJPError::OutOfRange("経度が範囲外です。".into()));
+    }
+
+    Ok(lon)
+}
+
+/// 隣のメッシュが標準地域メッシュの範囲外であることを示すエラーを返す。
+///
+/// # 引数
+///
+/// * `direction` - 範囲外になった隣のメッシュの方向
+///
+/// # 戻り値
+///
+/// 範囲外になった方向を示すエラー
+fn neighbor_out_of_range(direction: &str) -> GSJPError {
+    GSJPError::OutOfRange(format!("{}隣のメッシュが範囲外です。", direction).into())
+}
+
+/// メッシュコードの長さと、メッシュコードがASCII文字のみで構成されることを確認する。
+///
+/// 全角数字などのASCII以外の文字を含むメッシュコードは、バイト単位でスライスするとパニックするか、
+/// 期待しない位置で分割されるため、メッシュコードをスライスする前に拒否する。
+///
+/// # 引数
+///
+/// * `code` - メッシュコード
+/// * `len` - メッシュコードの長さ
+///
+/// # 戻り値
+///
+/// `()`
+pub(crate) fn validate_code_len(code: &str, len: usize) -> Result<(), GSJPError> {
+    if code.len() != len || !code.is_ascii() {
+        return Err(GSJPError::InvalidMeshCode);
+    }
+
+    Ok(())
+}
+
+/// 上位のメッシュ内の位置または分割番号が、範囲内であることを確認する。
+///
+/// # 引数
+///
+/// * `name` - 位置または分割番号の名前
+/// * `value` - 位置または分割番号
+/// * `range` - 位置または分割番号の範囲
+///
+/// # 戻り値
+///
+/// `()`。範囲外の場合は、範囲外になった位置または分割番号と、その値を示すメッセージを持つエラー
+pub(crate) fn validate_part(
+    name: &str,
+    value: u8,
+    range: RangeInclusive<u8>,
+) -> Result<(), GSJPError> {
+    if !range.contains(&value) {
+        return Err(GSJPError::OutOfRange(
+            format!("{}が範囲外です({})。", name, value).into(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// 標準地域メッシュが表現する範囲内に座標が含まれるか確認する。
+///
+/// 範囲は、緯度が`SOUTHERNMOST`以上`NORTHERNMOST`未満、経度が`WESTERNMOST`以上`EASTERNMOST`未満である。
+/// 北端と東端の線上の座標は、範囲外のメッシュコードになるため含まない。
+///
+/// # 引数
+///
+/// * `coord` - 座標
+///
+/// # 戻り値
+///
+/// `()`。範囲外の場合は、範囲外の緯度または経度と、その値を示すメッセージを持つエラー
+pub(crate) fn contains_coordinate(coord: &Coordinate) -> Result<(), GSJPError> {
+    let lat_ok = (SOUTHERNMOST..NORTHERNMOST).contains(&coord.lat());
+    let lon_ok = (WESTERNMOST..EASTERNMOST).contains(&coord.lon());
+    let message = match (lat_ok, lon_ok) {
+        (true, true) => return Ok(()),
+        (false, true) => format!("緯度が範囲外です({})。", coord.lat()),
+        (true, false) => format!("経度が範囲外です({})。", coord.lon()),
+        (false, false) => format!("緯度({})と経度({})が範囲外です。", coord.lat(), coord.lon()),
+    };
+
+    Err(GSJPError::OutOfRange(message.into()))
+}
+
+/// 整数のメッシュコードを、下位`N`バイトのビッグエンディアンのバイト列に変換する。
+///
+/// 各レベルのメッシュの`to_bytes`は、この形式でメッシュを格納する。
+/// 上位バイトが先頭であるため、同じレベルのメッシュのバイト列は、メッシュコードの順番に並ぶ。
+///
+/// # 引数
+///
+/// * `code` - 整数のメッシュコード
 ///
 /// # 戻り値
 ///
-/// 緯度（度単位）
-fn validate_lat(lat: f64) -> Result<f64, GSJPError> {
-    if !(-90.0..=90.0).contains(&lat) {
-        return Err(GSJPError::OutOfRange("緯度が範囲外です。".into()));
-    }
+/// バイト列
+pub(crate) fn code_to_bytes<const N: usize>(code: u64) -> [u8; N] {
+    debug_assert!(
+        code < 1 << (N * 8),
+        "メッシュコード({})が{}バイトに収まりません。",
+        code,
+        N
+    );
+    let mut bytes = [0; N];
+    bytes.copy_from_slice(&code.to_be_bytes()[8 - N..]);
 
-    Ok(lat)
+    bytes
 }
 
-/// 経度を検証する。
+/// ビッグエンディアンのバイト列を、整数のメッシュコードに変換する。
 ///
 /// # 引数
 ///
-/// * `lon` - 経度（度単位）
+/// * `bytes` - バイト列
 ///
 /// # 戻り値
 ///
-/// 経度（度単位）
-fn validate_lon(lon: f64) -> Result<f64, GSJPError> {
-    if !(-180.0..=180.0).contains(&lon) {
-        return Err(GSJPError::OutOfRange("経度が範囲外です。".into()));
+/// 整数のメッシュコード
+pub(crate) fn code_from_bytes<const N: usize>(bytes: [u8; N]) -> u64 {
+    bytes.iter().fold(0, |code, &b| code << 8 | b as u64)
+}
+
+/// 複数の座標について、座標を含むメッシュのメッシュコードを返す。
+///
+/// 戻り値の各要素は、引数の同じ位置の座標に対応する。
+/// 標準地域メッシュの範囲外の座標はその位置にエラーを格納し、他の座標の処理は継続する。
+///
+/// # 引数
+///
+/// * `coords` - 座標
+///
+/// # 戻り値
+///
+/// 座標を含むメッシュのメッシュコード、またはエラー
+pub fn codes_from_coordinates<M: Mesh>(coords: &[Coordinate]) -> Vec<Result<String, GSJPError>> {
+    coords
+        .iter()
+        .map(|coord| M::from_coordinate(*coord).map(|mesh| mesh.code().to_string()))
+        .collect()
+}
+
+/// 複数の座標を含むメッシュを、重複を取り除いて返す。
+///
+/// メッシュは、南端の緯度が小さい順、南端の緯度が同じ場合は西端の経度が小さい順に格納する。
+///
+/// # 引数
+///
+/// * `coords` - 座標
+///
+/// # 戻り値
+///
+/// 座標を含むメッシュ。いずれかの座標が標準地域メッシュの範囲外の場合はエラー
+pub fn covering_meshes<M: Mesh>(coords: &[Coordinate]) -> Result<Vec<M>, GSJPError> {
+    let mut codes = BTreeSet::new();
+    let mut meshes = vec![];
+    for coord in coords {
+        let mesh = M::from_coordinate(*coord)?;
+        if codes.insert(mesh.code().to_string()) {
+            meshes.push(mesh);
+        }
     }
+    meshes.sort_by(|a, b| {
+        a.south()
+            .total_cmp(&b.south())
+            .then(a.west().total_cmp(&b.west()))
+    });
 
-    Ok(lon)
+    Ok(meshes)
 }
 
-/// 標準地域メッシュが表現する範囲内に座標が含まれるか確認する。
+/// 座標を、座標を含むメッシュの中心の座標に変換する。
 ///
 /// # 引数
 ///
@@ -334,18 +2046,272 @@ fn validate_lon(lon: f64) -> Result<f64, GSJPError> {
 ///
 /// # 戻り値
 ///
-/// `()`
-pub(crate) fn contains_coordinate(coord: &Coordinate) -> Result<(), GSJPError> {
-    if coord.lat() < SOUTHERNMOST || coord.lat() >= NORTHERNMOST + 1.0 {
-        return Err(GSJPError::OutOfRange("緯度が範囲外です。".into()));
+/// 座標を含むメッシュの中心の座標。座標が標準地域メッシュの範囲外の場合はエラー
+pub fn snap_to_center<M: Mesh>(coord: Coordinate) -> Result<Coordinate, GSJPError> {
+    Ok(M::from_coordinate(coord)?.center())
+}
+
+/// 座標を、座標を含むメッシュの南西端の座標に変換する。
+///
+/// # 引数
+///
+/// * `coord` - 座標
+///
+/// # 戻り値
+///
+/// 座標を含むメッシュの南西端の座標。座標が標準地域メッシュの範囲外の場合はエラー
+pub fn snap_to_sw_corner<M: Mesh>(coord: Coordinate) -> Result<Coordinate, GSJPError> {
+    Ok(M::from_coordinate(coord)?.south_west())
+}
+
+/// 座標を、座標を含むメッシュの四隅のうち、座標に最も近い隅の座標に変換する。
+///
+/// 南北方向と東西方向の距離は独立しているため、メッシュの中心より北にあれば北側の隅、
+/// 東にあれば東側の隅が最も近い。
+/// 座標がメッシュの中心の緯度または経度上にある場合は、北側または東側の隅を返す。
+///
+/// # 引数
+///
+/// * `coord` - 座標
+///
+/// # 戻り値
+///
+/// 座標に最も近いメッシュの隅の座標。座標が標準地域メッシュの範囲外の場合はエラー
+pub fn snap_to_nearest_corner<M: Mesh>(coord: Coordinate) -> Result<Coordinate, GSJPError> {
+    let mesh = M::from_coordinate(coord)?;
+    let center = mesh.center();
+    let corner = match (center.lat() <= coord.lat(), center.lon() <= coord.lon()) {
+        (true, true) => mesh.north_east(),
+        (true, false) => mesh.north_west(),
+        (false, true) => mesh.south_east(),
+        (false, false) => mesh.south_west(),
+    };
+
+    Ok(corner)
+}
+
+/// 上位のメッシュの南端または西端からの距離から、座標を含む区画の位置を返す。
+///
+/// 区画の境界から`BOUNDARY_EPSILON`以内の座標は、境界上にあるとみなして北側または東側の区画に含める。
+///
+/// # 引数
+///
+/// * `offset` - 上位のメッシュの南端または西端からの距離（度単位）
+/// * `diff` - 区画の南端と北端の緯度の差、または西端と東端の経度の差（度単位）
+///
+/// # 戻り値
+///
+/// 南端または西端から数えた区画の位置（0から開始）
+pub(crate) fn cell_index(offset: f64, diff: f64) -> u8 {
+    math::floor((offset + BOUNDARY_EPSILON) / diff) as u8
+}
+
+/// 上位のメッシュを南北及び東西に2等分した区画のうち、座標を含む区画の分割番号を返す。
+///
+/// 分割番号は、南西が1、南東が2、北西が3、北東が4である。
+/// 区画の境界上の座標は、北側または東側の区画に含める。
+///
+/// # 引数
+///
+/// * `parent_south` - 上位のメッシュの南端の緯度（度単位）
+/// * `parent_west` - 上位のメッシュの西端の経度（度単位）
+/// * `lat_diff` - 分割した区画の南端と北端の緯度の差（度単位）
+/// * `lon_diff` - 分割した区画の西端と東端の経度の差（度単位）
+/// * `coord` - 座標
+///
+/// # 戻り値
+///
+/// 分割番号（1から4）
+pub fn quadrant_of(
+    parent_south: f64,
+    parent_west: f64,
+    lat_diff: f64,
+    lon_diff: f64,
+    coord: &Coordinate,
+) -> u8 {
+    // 上位のメッシュの範囲外の座標でも、分割番号が1から4になるように丸める
+    let lat_n = cell_index(coord.lat() - parent_south, lat_diff).min(1);
+    let lon_n = cell_index(coord.lon() - parent_west, lon_diff).min(1);
+
+    2 * lat_n + 1 + lon_n
+}
+
+/// メッシュを含む、指定されたレベルのメッシュのメッシュコードを返す。
+///
+/// # 引数
+///
+/// * `mesh` - メッシュ
+/// * `level` - メッシュより粗いメッシュのレベル
+///
+/// # 戻り値
+///
+/// 指定されたレベルのメッシュのメッシュコード
+pub(crate) fn ancestor_code<M: Mesh>(mesh: &M, level: MeshLevel) -> String {
+    debug_assert!(level < mesh.level());
+    // メッシュコードは検証済みであるため、失敗しない
+    parent_code(mesh.code(), mesh.level(), level).unwrap()
+}
+
+/// 分割地域メッシュの斜め隣のメッシュのメッシュコードを、分割番号から直接計算する。
+///
+/// 2行2列に分割した区画の斜め隣は、常に分割番号が`5 - n`の区画になる。
+/// 斜め隣のメッシュが同じ上位のメッシュに含まれる場合は、隣のメッシュを経由せずに計算して、
+/// 上位のメッシュの境界を越える場合だけ、上位のメッシュの隣または斜め隣のメッシュを求める。
+///
+/// # 引数
+///
+/// * `code` - 分割地域メッシュのメッシュコード
+/// * `parent` - 分割地域メッシュを含む上位のメッシュ
+/// * `north` - 北側の斜め隣の場合は`true`、南側の斜め隣の場合は`false`
+/// * `east` - 東側の斜め隣の場合は`true`、西側の斜め隣の場合は`false`
+///
+/// # 戻り値
+///
+/// 斜め隣のメッシュのメッシュコード。上位のメッシュの隣のメッシュが範囲外の場合は、
+/// 範囲外になった方向を示すエラー
+pub(crate) fn division_diagonal_code<P: Mesh>(
+    code: &str,
+    parent: &P,
+    north: bool,
+    east: bool,
+) -> Result<String, GSJPError> {
+    let n = code.as_bytes()[code.len() - 1] - b'0';
+    debug_assert!((1..=4).contains(&n));
+    // 分割番号の行（南が0、北が1）と列（西が0、東が1）
+    let (row, col) = ((n - 1) / 2, (n - 1) % 2);
+    let cross_lat = (row == 1) == north;
+    let cross_lon = (col == 1) == east;
+    let parent = match (cross_lat, cross_lon) {
+        (false, false) => None,
+        (true, false) => {
+            let (mesh, direction) = if north {
+                (parent.north_mesh_opt(), "北")
+            } else {
+                (parent.south_mesh_opt(), "南")
+            };
+            Some(mesh.ok_or_else(|| neighbor_out_of_range(direction))?)
+        }
+        (false, true) => {
+            let (mesh, direction) = if east {
+                (parent.east_mesh_opt(), "東")
+            } else {
+                (parent.west_mesh_opt(), "西")
+            };
+            Some(mesh.ok_or_else(|| neighbor_out_of_range(direction))?)
+        }
+        (true, true) => Some(match (north, east) {
+            (true, true) => parent.north_east_mesh()?,
+            (false, true) => parent.south_east_mesh()?,
+            (false, false) => parent.south_west_mesh()?,
+            (true, false) => parent.north_west_mesh()?,
+        }),
+    };
+    let parent_code = parent
+        .as_ref()
+        .map_or(&code[..code.len() - 1], |mesh| mesh.code());
+
+    Ok(format!("{}{}", parent_code, 5 - n))
+}
+
+/// 分割地域メッシュのメッシュコードを、代替の番号体系のメッシュコードに変換する。
+///
+/// 代替の番号体系は、モジュールのドキュメントを参照すること。
+///
+/// # 引数
+///
+/// * `code` - 分割地域メッシュのメッシュコード（検証済み）
+///
+/// # 戻り値
+///
+/// 代替の番号体系のメッシュコード
+pub(crate) fn to_alternate_code(code: &str) -> String {
+    // 基準地域メッシュのメッシュコードは8桁
+    let mut alternate = code[0..8].to_string();
+    for num in code[8..].chars() {
+        let pair = match num {
+            '1' => "00",
+            '2' => "01",
+            '3' => "10",
+            '4' => "11",
+            _ => unreachable!(),
+        };
+        alternate.push_str(pair);
     }
-    if coord.lon() < WESTERNMOST || coord.lon() >= EASTERNMOST + 1.0 {
-        return Err(GSJPError::OutOfRange("経度が範囲外です。".into()));
+
+    alternate
+}
+
+/// 代替の番号体系のメッシュコードを、分割地域メッシュのメッシュコードに変換する。
+///
+/// 番号の対応は[`to_alternate_code`]を参照すること。
+/// 基準地域メッシュ部分の検証は、変換したメッシュコードからメッシュを作成するときに実施する。
+///
+/// # 引数
+///
+/// * `code` - 代替の番号体系のメッシュコード
+/// * `level` - 分割地域メッシュのレベル
+///
+/// # 戻り値
+///
+/// 分割地域メッシュのメッシュコード
+pub(crate) fn from_alternate_code(code: &str, level: MeshLevel) -> Result<String, GSJPError> {
+    let divisions = level.code_len() - 8;
+    if !code.is_ascii() || code.len() != 8 + 2 * divisions {
+        return Err(GSJPError::InvalidMeshCode);
+    }
+    let mut standard = code[0..8].to_string();
+    for pair in code.as_bytes()[8..].chunks(2) {
+        let num = match pair {
+            b"00" => '1',
+            b"01" => '2',
+            b"10" => '3',
+            b"11" => '4',
+            _ => return Err(GSJPError::InvalidMeshCode),
+        };
+        standard.push(num);
     }
 
-    Ok(())
+    Ok(standard)
 }
 
+// 公開する型がスレッド間で共有できることを、コンパイル時に確認する
+// `Send`または`Sync`を実装しないフィールドを追加すると、ここでコンパイルエラーになる
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Mesh1>();
+    assert_send_sync::<Mesh2>();
+    assert_send_sync::<Mesh3>();
+    assert_send_sync::<Mesh4>();
+    assert_send_sync::<Mesh5>();
+    assert_send_sync::<Mesh6>();
+    assert_send_sync::<Mesh2x>();
+    assert_send_sync::<Mesh5x>();
+    assert_send_sync::<AnyMesh>();
+    assert_send_sync::<MeshSet<AnyMesh>>();
+    assert_send_sync::<MeshGrid<AnyMesh>>();
+    assert_send_sync::<MeshRange<AnyMesh>>();
+    assert_send_sync::<CodeGrid>();
+    assert_send_sync::<MeshInfo>();
+    assert_send_sync::<MeshLevel>();
+    assert_send_sync::<NeighborDirection>();
+    assert_send_sync::<Coordinate>();
+    assert_send_sync::<EarthModel>();
+    assert_send_sync::<Datum>();
+    assert_send_sync::<GSJPError>();
+    #[cfg(feature = "mvt")]
+    assert_send_sync::<TileCoord>();
+    #[cfg(feature = "mvt")]
+    assert_send_sync::<MvtFeature>();
+    #[cfg(feature = "std")]
+    assert_send_sync::<crate::base_map_frame::BaseMapFrame>();
+    #[cfg(feature = "std")]
+    assert_send_sync::<crate::base_map_frame::BaseMapFrameLevel>();
+    #[cfg(feature = "std")]
+    assert_send_sync::<crate::base_map_frame::BaseMapFrameErr>();
+    #[cfg(feature = "std")]
+    assert_send_sync::<crate::base_map_frame::FrameExtent>();
+};
+
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
@@ -397,6 +2363,405 @@ pub(crate) mod tests {
         assert!(Coordinate::new(-90.0, 180.0 + EPSILON).is_err());
     }
 
+    #[test]
+    fn coordinate_new_strict_ok() {
+        let inputs = vec![
+            (35.0, 139.0),
+            (SOUTHERNMOST, WESTERNMOST),
+            (NORTHERNMOST - EPSILON, EASTERNMOST - EPSILON),
+        ];
+        for (lat, lon) in inputs {
+            let coord = Coordinate::new_strict(lat, lon).unwrap();
+            assert_eq!(Coordinate::new(lat, lon).unwrap(), coord);
+        }
+    }
+
+    #[test]
+    fn coordinate_new_strict_err() {
+        // 日付変更線の西側の経度は、汎用的な座標としては作成できる
+        assert!(Coordinate::new(35.0, -179.0).is_ok());
+        let inputs = vec![
+            (35.0, -179.0, "経度が範囲外です(-179)。"),
+            (35.0, EASTERNMOST + 0.5, "経度が範囲外です(150.5)。"),
+            (SOUTHERNMOST - 1.0, 139.0, "緯度が範囲外です(19)。"),
+            (-35.0, -139.0, "緯度(-35)と経度(-139)が範囲外です。"),
+            // 北端と東端の線上は範囲外
+            (NORTHERNMOST, 139.0, "緯度が範囲外です(48)。"),
+            (35.0, EASTERNMOST, "経度が範囲外です(150)。"),
+            (
+                NORTHERNMOST,
+                EASTERNMOST,
+                "緯度(48)と経度(150)が範囲外です。",
+            ),
+        ];
+        for (lat, lon, expected) in inputs {
+            match Coordinate::new_strict(lat, lon) {
+                Err(GSJPError::OutOfRange(message)) => assert_eq!(expected, message),
+                result => panic!("{:?}", result),
+            }
+        }
+        // 汎用的な座標の範囲外
+        assert!(Coordinate::new_strict(90.1, 139.0).is_err());
+    }
+
+    #[test]
+    fn earth_model_radius_ok() {
+        assert_eq!(EarthModel::SphereMean, EarthModel::default());
+        assert!(eq_f64(EARTH_RADIUS, EarthModel::SphereMean.radius()));
+        assert!(eq_f64(
+            WGS84_AUTHALIC_RADIUS,
+            EarthModel::Wgs84Authalic.radius()
+        ));
+        assert!(eq_f64(
+            6_378_137.0,
+            EarthModel::Custom(6_378_137.0).radius()
+        ));
+    }
+
+    #[test]
+    fn coordinate_distance_m_ok() {
+        // 東京駅から新大阪駅まで
+        let tokyo = Coordinate::new(35.681236, 139.767125).unwrap();
+        let osaka = Coordinate::new(34.733462, 135.500592).unwrap();
+        let mean = tokyo.distance_m(&osaka, EarthModel::SphereMean);
+        let authalic = tokyo.distance_m(&osaka, EarthModel::Wgs84Authalic);
+        assert!((mean - 401_664.166).abs() < 1e-3, "{}", mean);
+        assert!((authalic - 401_664.064).abs() < 1e-3, "{}", authalic);
+        // 平均半径の球の方が、約10cm長い
+        assert!(0.09 < mean - authalic && mean - authalic < 0.11);
+        // 距離は球の半径に比例する
+        let custom = tokyo.distance_m(&osaka, EarthModel::Custom(EARTH_RADIUS * 2.0));
+        assert!((custom - mean * 2.0).abs() < 1e-6);
+        // 同じ座標
+        assert_eq!(0.0, tokyo.distance_m(&tokyo, EarthModel::default()));
+    }
+
+    #[test]
+    fn coordinate_to_jgd2011_ok() {
+        // 旧日本測地系の東京タワーの座標（北緯35度39分19秒、東経139度44分55秒付近）
+        let tokyo = Coordinate::new(35.655352, 139.748640).unwrap();
+        let jgd = tokyo.to_jgd2011(Datum::Tokyo).unwrap();
+        // 日本測地系2011の東京タワーの座標
+        let expected = Coordinate::new(35.658581, 139.745433).unwrap();
+        assert!(jgd.approx_eq(&expected, 1e-5), "{:?}", jgd);
+        // 変換した座標は東京タワーを含む基準地域メッシュになるが、変換しない座標は南隣のメッシュになる
+        assert_eq!("53393599", Mesh3::from_coordinate(jgd).unwrap().code());
+        assert_eq!("53393589", Mesh3::from_coordinate(tokyo).unwrap().code());
+        // 日本測地系2011の座標は変換しない
+        assert_eq!(expected, expected.to_jgd2011(Datum::Jgd2011).unwrap());
+    }
+
+    #[test]
+    fn coordinate_approx_eq_ok() {
+        let coord = Coordinate::new(35.0, 139.0).unwrap();
+        let near = Coordinate::new(35.0 + 1e-12, 139.0 - 1e-12).unwrap();
+        assert!(coord != near);
+        assert!(coord.approx_eq(&near, EPSILON));
+        assert!(near.approx_eq(&coord, EPSILON));
+        assert!(coord.approx_eq(&coord, EPSILON));
+        let far = Coordinate::new(35.0, 139.0 + 1e-6).unwrap();
+        assert!(!coord.approx_eq(&far, EPSILON));
+        assert!(coord.approx_eq(&far, 1e-5));
+    }
+
+    #[test]
+    fn mesh_width_height_m_with_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        assert!(eq_f64(
+            mesh.width_m(),
+            mesh.width_m_with(EarthModel::SphereMean)
+        ));
+        assert!(eq_f64(
+            mesh.height_m(),
+            mesh.height_m_with(EarthModel::SphereMean)
+        ));
+        let ratio = WGS84_AUTHALIC_RADIUS / EARTH_RADIUS;
+        assert!(eq_f64(
+            mesh.width_m() * ratio,
+            mesh.width_m_with(EarthModel::Wgs84Authalic)
+        ));
+        assert!(eq_f64(
+            mesh.height_m() * ratio,
+            mesh.height_m_with(EarthModel::Wgs84Authalic)
+        ));
+        let center = mesh.center();
+        assert!(eq_f64(
+            mesh.distance_to_edge_m(&center) * ratio,
+            mesh.distance_to_edge_m_with(&center, EarthModel::Wgs84Authalic)
+        ));
+    }
+
+    #[test]
+    fn coordinate_new_unchecked_ok() {
+        let coord = Coordinate::new_unchecked(35.0, 135.0);
+        assert!(eq_f64(35.0, coord.lat()));
+        assert!(eq_f64(135.0, coord.lon()));
+        assert_eq!(Coordinate::new(35.0, 135.0).unwrap(), coord);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "緯度が範囲外です")]
+    fn coordinate_new_unchecked_lat_panic() {
+        Coordinate::new_unchecked(90.0 + EPSILON, 135.0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "経度が範囲外です")]
+    fn coordinate_new_unchecked_lon_panic() {
+        Coordinate::new_unchecked(35.0, -180.0 - EPSILON);
+    }
+
+    #[test]
+    fn coordinate_new_const_ok() {
+        const COORD: Coordinate = Coordinate::new_const(35.0, 135.0);
+        const LAT: f64 = COORD.lat();
+        const LON: f64 = COORD.lon();
+        assert!(eq_f64(35.0, LAT));
+        assert!(eq_f64(135.0, LON));
+        assert_eq!(Coordinate::new(35.0, 135.0).unwrap(), COORD);
+        assert_eq!(
+            Coordinate::new(-90.0, 180.0).unwrap(),
+            Coordinate::new_const(-90.0, 180.0)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "緯度が範囲外です")]
+    fn coordinate_new_const_lat_panic() {
+        Coordinate::new_const(90.0 + EPSILON, 135.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "経度が範囲外です")]
+    fn coordinate_new_const_lon_panic() {
+        Coordinate::new_const(35.0, f64::NAN);
+    }
+
+    #[test]
+    fn coordinate_from_dms_ok() {
+        // 東京タワー
+        let coord = Coordinate::from_dms("35°39′31″N", "139°44′44″E").unwrap();
+        assert!(eq_f64(35.0 + 39.0 / 60.0 + 31.0 / 3600.0, coord.lat()));
+        assert!(eq_f64(139.0 + 44.0 / 60.0 + 44.0 / 3600.0, coord.lon()));
+        // 南半球と西半球、ASCIIの記号、空白、小数の秒
+        let coord = Coordinate::from_dms(" 33° 52' 7.5\" S ", "151°12'30\"W").unwrap();
+        assert!(eq_f64(-(33.0 + 52.0 / 60.0 + 7.5 / 3600.0), coord.lat()));
+        assert!(eq_f64(-(151.0 + 12.0 / 60.0 + 30.0 / 3600.0), coord.lon()));
+        // 分と秒の省略、半球の省略
+        let coord = Coordinate::from_dms("35°30′", "139°").unwrap();
+        assert!(eq_f64(35.5, coord.lat()));
+        assert!(eq_f64(139.0, coord.lon()));
+    }
+
+    #[test]
+    fn coordinate_from_dms_err() {
+        let inputs = vec![
+            ("", "139°44′44″E"),
+            ("35°39′31″E", "139°44′44″E"), // 緯度に経度の半球
+            ("35°39′31″N", "139°44′44″N"), // 経度に緯度の半球
+            ("35°60′00″N", "139°44′44″E"), // 分が60以上
+            ("35°39′60″N", "139°44′44″E"), // 秒が60以上
+            ("35°31″N", "139°44′44″E"),    // 分の記号がない
+            ("35°39′31″N", "139°44′44″E1"),
+            ("-35°39′31″N", "139°44′44″E"),
+            ("35°39′1e1″N", "139°44′44″E"),
+            ("35", "139"),
+        ];
+        for (lat, lon) in inputs {
+            let result = Coordinate::from_dms(lat, lon);
+            assert!(
+                matches!(result, Err(GSJPError::InvalidDms)),
+                "{}, {}: {:?}",
+                lat,
+                lon,
+                result
+            );
+        }
+        // 範囲外
+        assert!(matches!(
+            Coordinate::from_dms("91°00′00″N", "139°44′44″E"),
+            Err(GSJPError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn coordinate_to_dms_ok() {
+        let coord = Coordinate::new(35.658581, 139.745433).unwrap();
+        let (lat, lon) = coord.to_dms();
+        assert_eq!("35°39′30.89″N", lat);
+        assert_eq!("139°44′43.56″E", lon);
+        let restored = Coordinate::from_dms(&lat, &lon).unwrap();
+        assert!((coord.lat() - restored.lat()).abs() < 1e-5);
+        assert!((coord.lon() - restored.lon()).abs() < 1e-5);
+        // 南半球と西半球、秒の繰り上がり
+        let coord = Coordinate::new(-(10.0 + 59.0 / 60.0 + 59.999 / 3600.0), -0.5).unwrap();
+        assert_eq!(
+            (String::from("11°0′0.00″S"), String::from("0°30′0.00″W")),
+            coord.to_dms()
+        );
+    }
+
+    #[test]
+    fn coordinate_lerp_ok() {
+        let a = Coordinate::new(35.0, 139.0).unwrap();
+        let b = Coordinate::new(36.0, 141.0).unwrap();
+        assert_eq!(a, a.lerp(b, 0.0).unwrap());
+        assert_eq!(b, a.lerp(b, 1.0).unwrap());
+        let c = a.lerp(b, 0.5).unwrap();
+        assert!(eq_f64(c.lat(), 35.5));
+        assert!(eq_f64(c.lon(), 140.0));
+        let c = a.lerp(b, 0.25).unwrap();
+        assert!(eq_f64(c.lat(), 35.25));
+        assert!(eq_f64(c.lon(), 139.5));
+        // 直線の延長上の座標
+        let c = a.lerp(b, -1.0).unwrap();
+        assert!(eq_f64(c.lat(), 34.0));
+        assert!(eq_f64(c.lon(), 137.0));
+    }
+
+    #[test]
+    fn coordinate_lerp_err() {
+        let a = Coordinate::new(80.0, 170.0).unwrap();
+        let b = Coordinate::new(85.0, 175.0).unwrap();
+        assert!(a.lerp(b, 3.0).is_err());
+    }
+
+    #[test]
+    fn coordinate_midpoint_ok() {
+        let a = Coordinate::new(35.0, 139.0).unwrap();
+        let b = Coordinate::new(36.0, 141.0).unwrap();
+        let c = a.midpoint(b);
+        assert!(eq_f64(c.lat(), 35.5));
+        assert!(eq_f64(c.lon(), 140.0));
+        assert_eq!(a.lerp(b, 0.5).unwrap(), c);
+        assert_eq!(c, b.midpoint(a));
+    }
+
+    #[cfg(feature = "geo")]
+    #[test]
+    fn coordinate_into_geo_coord_ok() {
+        let coord: geo::Coord<f64> = Coordinate::new(35.658581, 139.745433).unwrap().into();
+        assert!(eq_f64(139.745433, coord.x));
+        assert!(eq_f64(35.658581, coord.y));
+    }
+
+    #[cfg(feature = "geo")]
+    #[test]
+    fn to_geo_polygon_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let polygon = mesh.to_geo_polygon();
+        let exterior = polygon.exterior();
+        assert_eq!(5, exterior.0.len());
+        assert!(exterior.is_closed());
+        assert_eq!(exterior.0[0], exterior.0[4]);
+        assert_eq!(geo::Coord::from(mesh.south_west()), exterior.0[0]);
+        assert_eq!(geo::Coord::from(mesh.south_east()), exterior.0[1]);
+        assert_eq!(geo::Coord::from(mesh.north_east()), exterior.0[2]);
+        assert_eq!(geo::Coord::from(mesh.north_west()), exterior.0[3]);
+        assert!(polygon.interiors().is_empty());
+    }
+
+    #[test]
+    fn mesh_z_index_ok() {
+        // 南西端から、南東、北西、北東の順に連続する
+        let mesh = Mesh3::new(String::from("53393500")).unwrap();
+        let z = mesh.z_index();
+        assert_eq!(3, z >> 60);
+        assert_eq!(z + 1, mesh.east_mesh().unwrap().z_index());
+        assert_eq!(z + 2, mesh.north_mesh().unwrap().z_index());
+        assert_eq!(z + 3, mesh.north_east_mesh().unwrap().z_index());
+        // 第1次地域区画でも、列が偶数のメッシュと東隣のメッシュのインデックスは連続する
+        let mesh = Mesh1::new(String::from("5338")).unwrap();
+        assert_eq!(1, mesh.z_index() >> 60);
+        assert_eq!(mesh.z_index() + 1, mesh.east_mesh().unwrap().z_index());
+        // 同じレベルで単射
+        let meshes = meshes_in_bbox::<Mesh4>(
+            Coordinate::new(35.5, 139.5).unwrap(),
+            Coordinate::new(35.6, 139.6).unwrap(),
+        )
+        .unwrap();
+        let indices = meshes.iter().map(|m| m.z_index()).collect::<BTreeSet<_>>();
+        assert_eq!(meshes.len(), indices.len());
+        // 全国の範囲の北東端の8分の1地域メッシュ
+        let mesh = Mesh6::new(String::from("71497799444")).unwrap();
+        assert_eq!((42 * 640 - 1, 32 * 640 - 1), deinterleave(mesh.z_index()));
+        // 異なるレベルのインデックスは一致しない
+        let mesh3 = Mesh3::new(String::from("53393599")).unwrap();
+        let mesh4 = Mesh4::new(String::from("533935991")).unwrap();
+        assert_ne!(mesh3.z_index(), mesh4.z_index());
+        assert_eq!(4, mesh4.z_index() >> 60);
+    }
+
+    /// Z階数曲線のインデックスから、行と列の位置を取り出す。
+    fn deinterleave(z: u64) -> (u64, u64) {
+        (0..30).fold((0, 0), |(row, col), bit| {
+            (
+                row | ((z >> (2 * bit + 1)) & 1) << bit,
+                col | ((z >> (2 * bit)) & 1) << bit,
+            )
+        })
+    }
+
+    #[test]
+    fn mesh_level_name_ok() {
+        let levels = [
+            MeshLevel::Mesh1,
+            MeshLevel::Mesh2,
+            MeshLevel::Mesh3,
+            MeshLevel::Mesh4,
+            MeshLevel::Mesh5,
+            MeshLevel::Mesh6,
+        ];
+        for level in levels {
+            assert_eq!(format!("{:?}", level), level.name());
+            assert_eq!(Some(level), MeshLevel::from_name(level.name()));
+        }
+        assert_eq!(None, MeshLevel::from_name("Mesh7"));
+        assert_eq!(None, MeshLevel::from_name(""));
+    }
+
+    #[test]
+    fn mesh_contains_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let inputs = vec![
+            (mesh.center(), true),
+            (mesh.south_west(), true),
+            (mesh.north_west(), false),
+            (mesh.south_east(), false),
+            (mesh.north_east(), false),
+            // 境界の南側で`BOUNDARY_EPSILON`以内の座標は、北側のメッシュに含まれる
+            (
+                Coordinate::new(mesh.south() - BOUNDARY_EPSILON / 2.0, mesh.center().lon())
+                    .unwrap(),
+                true,
+            ),
+            (
+                Coordinate::new(mesh.north() - BOUNDARY_EPSILON / 2.0, mesh.center().lon())
+                    .unwrap(),
+                false,
+            ),
+            (
+                Coordinate::new(mesh.north() - 1e-8, mesh.east() - 1e-8).unwrap(),
+                true,
+            ),
+            (
+                Coordinate::new(mesh.south() - 1e-8, mesh.center().lon()).unwrap(),
+                false,
+            ),
+        ];
+        for (coord, expected) in inputs {
+            assert_eq!(expected, mesh.contains(&coord), "{:?}", coord);
+            assert_eq!(
+                expected,
+                Mesh3::from_coordinate(coord).unwrap() == mesh,
+                "{:?}",
+                coord
+            );
+        }
+    }
+
     #[test]
     fn coordinate_lat_lon_ok() {
         let coordinate = Coordinate::new(35.0, 135.0).unwrap();
@@ -408,11 +2773,197 @@ pub(crate) mod tests {
     fn contains_coordinate_ok() {
         assert!(contains_coordinate(&Coordinate::new(SOUTHERNMOST, WESTERNMOST).unwrap()).is_ok());
         assert!(contains_coordinate(
-            &Coordinate::new(NORTHERNMOST + 1.0 - EPSILON, EASTERNMOST + 1.0 - EPSILON).unwrap()
+            &Coordinate::new(NORTHERNMOST - EPSILON, EASTERNMOST - EPSILON).unwrap()
         )
         .is_ok());
     }
 
+    #[test]
+    fn codes_from_coordinates_ok() {
+        let coords = vec![
+            Coordinate::new(35.70078, 139.71475).unwrap(),
+            // 標準地域メッシュの範囲外
+            Coordinate::new(10.0, 139.71475).unwrap(),
+            Coordinate::new(34.70078, 135.48904).unwrap(),
+            // 標準地域メッシュの範囲外
+            Coordinate::new(35.70078, 170.0).unwrap(),
+        ];
+        let codes = codes_from_coordinates::<Mesh3>(&coords);
+        assert_eq!(coords.len(), codes.len());
+        assert_eq!("53394547", codes[0].as_ref().unwrap());
+        assert!(codes[1].is_err());
+        assert_eq!(
+            Mesh3::from_coordinate(coords[2]).unwrap().code(),
+            codes[2].as_ref().unwrap()
+        );
+        assert!(codes[3].is_err());
+    }
+
+    #[test]
+    fn codes_from_coordinates_empty() {
+        assert!(codes_from_coordinates::<Mesh1>(&[]).is_empty());
+    }
+
+    #[test]
+    fn covering_meshes_ok() {
+        let coords = vec![
+            // 53394600
+            Coordinate::new(35.6700, 139.7510).unwrap(),
+            // 53393599
+            Coordinate::new(35.6600, 139.7400).unwrap(),
+            // 53394600
+            Coordinate::new(35.6670, 139.7550).unwrap(),
+            // 53393599
+            Coordinate::new(35.6630, 139.7450).unwrap(),
+            // 53394600
+            Coordinate::new(35.6740, 139.7620).unwrap(),
+        ];
+        let meshes = covering_meshes::<Mesh3>(&coords).unwrap();
+        let codes = meshes.iter().map(|mesh| mesh.code()).collect::<Vec<_>>();
+        assert_eq!(vec!["53393599", "53394600"], codes);
+    }
+
+    #[test]
+    fn covering_meshes_err() {
+        let coords = vec![
+            Coordinate::new(35.6600, 139.7400).unwrap(),
+            Coordinate::new(10.0, 139.7400).unwrap(),
+        ];
+        assert!(covering_meshes::<Mesh3>(&coords).is_err());
+    }
+
+    #[test]
+    fn snap_to_center_ok() {
+        // どちらも53393599に含まれる座標
+        let a = snap_to_center::<Mesh3>(Coordinate::new(35.6600, 139.7400).unwrap()).unwrap();
+        let b = snap_to_center::<Mesh3>(Coordinate::new(35.6630, 139.7450).unwrap()).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(Mesh3::new(String::from("53393599")).unwrap().center(), a);
+        // 53394600に含まれる座標
+        let c = snap_to_center::<Mesh3>(Coordinate::new(35.6700, 139.7510).unwrap()).unwrap();
+        assert_ne!(a, c);
+        assert_eq!(Mesh3::new(String::from("53394600")).unwrap().center(), c);
+    }
+
+    #[test]
+    fn snap_to_center_err() {
+        assert!(snap_to_center::<Mesh3>(Coordinate::new(10.0, 139.74).unwrap()).is_err());
+    }
+
+    #[test]
+    fn snap_to_sw_corner_ok() {
+        // 53393599に含まれる座標
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let expected = mesh.south_west();
+        for i in 0..10 {
+            for j in 0..10 {
+                let coord = Coordinate::new(
+                    mesh.south() + (mesh.north() - mesh.south()) * (i as f64 + 0.5) / 10.0,
+                    mesh.west() + (mesh.east() - mesh.west()) * (j as f64 + 0.5) / 10.0,
+                )
+                .unwrap();
+                assert_eq!(expected, snap_to_sw_corner::<Mesh3>(coord).unwrap());
+            }
+        }
+        // 53394600に含まれる座標
+        let coord = Coordinate::new(35.6700, 139.7510).unwrap();
+        assert_eq!(
+            Mesh3::new(String::from("53394600")).unwrap().south_west(),
+            snap_to_sw_corner::<Mesh3>(coord).unwrap()
+        );
+    }
+
+    #[test]
+    fn snap_to_sw_corner_err() {
+        assert!(snap_to_sw_corner::<Mesh3>(Coordinate::new(10.0, 139.74).unwrap()).is_err());
+    }
+
+    #[test]
+    fn snap_to_nearest_corner_ok() {
+        let mesh = Mesh3::new(String::from("53393599")).unwrap();
+        let (lat_diff, lon_diff) = (mesh.north() - mesh.south(), mesh.east() - mesh.west());
+        let inputs = vec![
+            ((0.1, 0.1), mesh.south_west()),
+            ((0.1, 0.9), mesh.south_east()),
+            ((0.9, 0.1), mesh.north_west()),
+            ((0.9, 0.9), mesh.north_east()),
+            ((0.4, 0.6), mesh.south_east()),
+            ((0.6, 0.4), mesh.north_west()),
+        ];
+        for ((lat_ratio, lon_ratio), expected) in inputs {
+            let coord = Coordinate::new(
+                mesh.south() + lat_diff * lat_ratio,
+                mesh.west() + lon_diff * lon_ratio,
+            )
+            .unwrap();
+            let corner = snap_to_nearest_corner::<Mesh3>(coord).unwrap();
+            assert_eq!(expected, corner, "{:?}", coord);
+            // 四隅の中で最も近い隅であることを、メートル単位の距離で確認
+            let lon_scale = coord.lat().to_radians().cos();
+            let distance = |c: &Coordinate| {
+                ((c.lat() - coord.lat()).powi(2) + ((c.lon() - coord.lon()) * lon_scale).powi(2))
+                    .sqrt()
+            };
+            for other in mesh.corners() {
+                assert!(distance(&corner) <= distance(&other), "{:?}", coord);
+            }
+        }
+    }
+
+    #[test]
+    fn snap_to_nearest_corner_err() {
+        assert!(snap_to_nearest_corner::<Mesh3>(Coordinate::new(10.0, 139.74).unwrap()).is_err());
+    }
+
+    #[test]
+    fn quadrant_of_ok() {
+        // 南端が北緯35度、西端が東経139度で、南北に2度、東西に4度のメッシュを2等分
+        let inputs = vec![
+            // 南西
+            (35.5, 139.5, 1),
+            // 南東
+            (35.5, 141.5, 2),
+            // 北西
+            (36.5, 139.5, 3),
+            // 北東
+            (36.5, 141.5, 4),
+            // 南西端
+            (35.0, 139.0, 1),
+            // 区画の境界上は北側と東側の区画
+            (36.0, 139.0, 3),
+            (35.0, 141.0, 2),
+            (36.0, 141.0, 4),
+            // 区画の境界の直前
+            (36.0 - EPSILON, 141.0 - EPSILON, 1),
+        ];
+        for (lat, lon, expected) in inputs {
+            let coord = Coordinate::new(lat, lon).unwrap();
+            assert_eq!(
+                expected,
+                quadrant_of(35.0, 139.0, 1.0, 2.0, &coord),
+                "lat: {}, lon: {}",
+                lat,
+                lon
+            );
+        }
+    }
+
+    #[test]
+    fn contains_coordinate_err_message() {
+        let inputs = vec![
+            (35.0, WESTERNMOST - 1.0, "経度が範囲外です(117)。"),
+            (SOUTHERNMOST - 1.0, 139.0, "緯度が範囲外です(19)。"),
+            (10.0, 100.0, "緯度(10)と経度(100)が範囲外です。"),
+        ];
+        for (lat, lon, expected) in inputs {
+            let coord = Coordinate::new(lat, lon).unwrap();
+            match contains_coordinate(&coord) {
+                Err(GSJPError::OutOfRange(message)) => assert_eq!(expected, message),
+                result => panic!("{:?}", result),
+            }
+        }
+    }
+
     #[test]
     fn contains_coordinate_err() {
         assert!(contains_coordinate(
@@ -424,12 +2975,84 @@ pub(crate) mod tests {
         )
         .is_err());
         assert!(contains_coordinate(
-            &Coordinate::new(NORTHERNMOST + 1.0, EASTERNMOST + 1.0 - EPSILON).unwrap()
+            &Coordinate::new(NORTHERNMOST, EASTERNMOST - EPSILON).unwrap()
         )
         .is_err());
         assert!(contains_coordinate(
-            &Coordinate::new(NORTHERNMOST + 1.0 - EPSILON, EASTERNMOST + 1.0).unwrap()
+            &Coordinate::new(NORTHERNMOST - EPSILON, EASTERNMOST).unwrap()
         )
         .is_err());
+        assert!(contains_coordinate(&Coordinate::new(NORTHERNMOST, WESTERNMOST).unwrap()).is_err());
+        assert!(contains_coordinate(&Coordinate::new(SOUTHERNMOST, EASTERNMOST).unwrap()).is_err());
+    }
+
+    #[test]
+    fn from_coordinate_north_of_extent_err() {
+        // 全国の範囲の北端より北の座標は、どのレベルでも範囲外のエラー
+        let coord = Coordinate::new(NORTHERNMOST + 0.5, 139.0).unwrap();
+        let results = [
+            Mesh1::from_coordinate(coord).map(AnyMesh::from),
+            Mesh2::from_coordinate(coord).map(AnyMesh::from),
+            Mesh3::from_coordinate(coord).map(AnyMesh::from),
+            Mesh4::from_coordinate(coord).map(AnyMesh::from),
+            Mesh5::from_coordinate(coord).map(AnyMesh::from),
+            Mesh6::from_coordinate(coord).map(AnyMesh::from),
+        ];
+        for result in results {
+            assert!(
+                matches!(result, Err(GSJPError::OutOfRange(_))),
+                "{:?}",
+                result
+            );
+        }
+    }
+
+    /// 指定されたレベルの型で、メッシュコードからメッシュを作成する。
+    fn new_mesh(level: MeshLevel, code: &str) -> Result<AnyMesh, GSJPError> {
+        let code = code.to_string();
+        match level {
+            MeshLevel::Mesh1 => Mesh1::new(code).map(AnyMesh::from),
+            MeshLevel::Mesh2 => Mesh2::new(code).map(AnyMesh::from),
+            MeshLevel::Mesh3 => Mesh3::new(code).map(AnyMesh::from),
+            MeshLevel::Mesh4 => Mesh4::new(code).map(AnyMesh::from),
+            MeshLevel::Mesh5 => Mesh5::new(code).map(AnyMesh::from),
+            MeshLevel::Mesh6 => Mesh6::new(code).map(AnyMesh::from),
+        }
+    }
+
+    #[test]
+    fn mesh_new_err() {
+        // 第1次地域区画の全国の範囲外のメッシュコードは、`validate_mesh1_code_national_corners`で確認する
+        let inputs = vec![
+            // 下位2桁の数値が不正
+            (MeshLevel::Mesh2, "695480"),
+            (MeshLevel::Mesh2, "695408"),
+            (MeshLevel::Mesh2, "724907"), // 最北東端(714977)の1つ北側
+            (MeshLevel::Mesh2, "725070"), // 最北東端の1つ東側
+            (MeshLevel::Mesh2, "294977"), // 最南東端(304907)の1つ南側
+            (MeshLevel::Mesh2, "305000"), // 最南東端の1つ東側
+            (MeshLevel::Mesh2, "291870"), // 最南西端(301800)の1つ南側
+            (MeshLevel::Mesh2, "301707"), // 最南西端の1つ西側
+            (MeshLevel::Mesh2, "721800"), // 最北西端(711870)の1つ北側
+            (MeshLevel::Mesh2, "711777"), // 最北西端の1つ西側
+            (MeshLevel::Mesh3, "72490709"),
+            (MeshLevel::Mesh3, "71507090"),
+            (MeshLevel::Mesh3, "29497799"),
+            (MeshLevel::Mesh3, "30500000"),
+            (MeshLevel::Mesh3, "29187090"),
+            (MeshLevel::Mesh3, "30170709"),
+            (MeshLevel::Mesh3, "72180000"),
+            (MeshLevel::Mesh3, "71170709"),
+            // 分割番号が1から4の範囲外
+            (MeshLevel::Mesh4, "533935990"),
+            (MeshLevel::Mesh4, "533935995"),
+            (MeshLevel::Mesh5, "5339359910"),
+            (MeshLevel::Mesh5, "5339359915"),
+            (MeshLevel::Mesh6, "53393599110"),
+            (MeshLevel::Mesh6, "53393599115"),
+        ];
+        for (level, code) in inputs {
+            assert!(new_mesh(level, code).is_err(), "{:?} {}", level, code);
+        }
     }
 }
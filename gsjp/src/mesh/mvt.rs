@@ -0,0 +1,176 @@
+//! Mapbox Vector Tile（MVT）形式の地物
+//!
+//! メッシュのポリゴンを、Webメルカトル投影のタイル内の整数座標に投影する。
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+use crate::math;
+use crate::mesh::Coordinate;
+
+/// タイル内の座標の範囲（MVTの既定値）
+pub const MVT_EXTENT: u32 = 4096;
+
+/// Webメルカトル投影のタイルの座標
+///
+/// `x`と`y`は`u32`であるため、ズームレベルは0から32の範囲で指定する。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TileCoord {
+    /// ズームレベル
+    pub z: u8,
+    /// 西から数えたタイルの位置
+    pub x: u32,
+    /// 北から数えたタイルの位置
+    pub y: u32,
+}
+
+impl TileCoord {
+    /// 座標を含むタイルの座標を返す。
+    ///
+    /// # 引数
+    ///
+    /// * `coord` - 座標
+    /// * `z` - ズームレベル
+    ///
+    /// # 戻り値
+    ///
+    /// 座標を含むタイルの座標
+    #[must_use]
+    pub fn from_coordinate(coord: &Coordinate, z: u8) -> Self {
+        let (x, y) = world_position(coord, z);
+
+        Self {
+            z,
+            x: math::floor(x) as u32,
+            y: math::floor(y) as u32,
+        }
+    }
+}
+
+/// MVT形式の地物
+#[derive(Debug, Clone, PartialEq)]
+pub struct MvtFeature {
+    /// メッシュコード
+    pub code: String,
+    /// ポリゴンの外側のリングの頂点
+    ///
+    /// タイルの北西端を原点として、東方向をx座標、南方向をy座標とする、0から`MVT_EXTENT`の整数座標で、
+    /// 北西端から時計回りに格納する。MVTのリングと同様に、始点を終点として繰り返さない。
+    pub ring: Vec<(i32, i32)>,
+}
+
+/// 座標を、ズームレベルの全体のタイルを単位とする、Webメルカトル投影の位置に投影する。
+///
+/// # 引数
+///
+/// * `coord` - 座標
+/// * `z` - ズームレベル
+///
+/// # 戻り値
+///
+/// 北西端を原点とする、東方向と南方向のタイルを単位とした位置
+fn world_position(coord: &Coordinate, z: u8) -> (f64, f64) {
+    let n = (1_u64 << z) as f64;
+    let lat = coord.lat().to_radians();
+    let x = (coord.lon() + 180.0) / 360.0 * n;
+    let y = (1.0 - math::ln(math::tan(PI / 4.0 + lat / 2.0)) / PI) / 2.0 * n;
+
+    (x, y)
+}
+
+/// 座標を、タイル内の座標に投影する。
+///
+/// タイルの範囲外の座標は制限せず、0未満または`MVT_EXTENT`より大きい値を返す。
+///
+/// # 引数
+///
+/// * `coord` - 座標
+/// * `tile` - タイルの座標
+///
+/// # 戻り値
+///
+/// タイル内の座標（x座標, y座標）
+pub(crate) fn project(coord: &Coordinate, tile: &TileCoord) -> (f64, f64) {
+    let (x, y) = world_position(coord, tile.z);
+    let extent = f64::from(MVT_EXTENT);
+
+    (
+        (x - f64::from(tile.x)) * extent,
+        (y - f64::from(tile.y)) * extent,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::{Mesh, Mesh1, Mesh3};
+
+    #[test]
+    fn tile_coord_from_coordinate_ok() {
+        // 東京駅
+        let coord = Coordinate::new(35.681236, 139.767125).unwrap();
+        assert_eq!(
+            TileCoord { z: 0, x: 0, y: 0 },
+            TileCoord::from_coordinate(&coord, 0)
+        );
+        assert_eq!(
+            TileCoord {
+                z: 10,
+                x: 909,
+                y: 403
+            },
+            TileCoord::from_coordinate(&coord, 10)
+        );
+    }
+
+    #[test]
+    fn mesh_to_mvt_feature_inside() {
+        let mesh = Mesh3::new(String::from("53394611")).unwrap();
+        let tile = TileCoord::from_coordinate(&mesh.center(), 12);
+        let feature = mesh.to_mvt_feature(&tile).unwrap();
+        assert_eq!("53394611", feature.code);
+        assert_eq!(4, feature.ring.len());
+        let extent = MVT_EXTENT as i32;
+        for &(x, y) in &feature.ring {
+            assert!(
+                (0..=extent).contains(&x) && (0..=extent).contains(&y),
+                "{:?}",
+                feature
+            );
+        }
+        // 北西端から時計回り
+        let [nw, ne, se, sw] = [0, 1, 2, 3].map(|i| feature.ring[i]);
+        assert!(nw.0 == sw.0 && ne.0 == se.0 && nw.0 < ne.0);
+        assert!(nw.1 == ne.1 && sw.1 == se.1 && nw.1 < sw.1);
+    }
+
+    #[test]
+    fn mesh_to_mvt_feature_clipped() {
+        // タイルより大きいメッシュは、タイルの範囲に切り抜く
+        let mesh = Mesh1::new(String::from("5339")).unwrap();
+        let tile = TileCoord::from_coordinate(&mesh.center(), 12);
+        let feature = mesh.to_mvt_feature(&tile).unwrap();
+        let extent = MVT_EXTENT as i32;
+        assert_eq!(
+            vec![(0, 0), (extent, 0), (extent, extent), (0, extent)],
+            feature.ring
+        );
+    }
+
+    #[test]
+    fn mesh_to_mvt_feature_none() {
+        let mesh = Mesh3::new(String::from("53394611")).unwrap();
+        // 大阪駅を含むタイル
+        let coord = Coordinate::new(34.702485, 135.495951).unwrap();
+        let tile = TileCoord::from_coordinate(&coord, 12);
+        assert!(mesh.to_mvt_feature(&tile).is_none());
+        // 東隣のタイル
+        let tile = TileCoord::from_coordinate(&mesh.center(), 12);
+        let mut east = tile;
+        while mesh.to_mvt_feature(&east).is_some() {
+            east.x += 1;
+        }
+        assert!(tile.x < east.x);
+    }
+}
@@ -0,0 +1,137 @@
+//! 座標から作成したメッシュが、その座標を含むことを確認するプロパティテスト
+//!
+//! 標準地域メッシュの範囲内の座標を無作為に生成して、すべてのレベルで
+//! `from_coordinate`で作成したメッシュが`contains`で座標を含むことを確認する。
+//! 浮動小数点数の誤差が問題になりやすいメッシュの境界上の座標も生成する。
+
+use proptest::prelude::*;
+
+use gsjp::mesh::{
+    Coordinate, Mesh, Mesh1, Mesh2, Mesh3, Mesh4, Mesh5, Mesh6, EASTERNMOST, NORTHERNMOST,
+    SOUTHERNMOST, WESTERNMOST,
+};
+
+/// 座標から作成したメッシュが、その座標を含むか確認する。
+fn check_contains<M: Mesh>(lat: f64, lon: f64) -> Result<(), TestCaseError> {
+    let coord = Coordinate::new(lat, lon).unwrap();
+    let mesh = M::from_coordinate(coord).unwrap();
+    prop_assert!(
+        mesh.contains(&coord),
+        "{}は{:?}を含みません。",
+        mesh.code(),
+        coord
+    );
+
+    Ok(())
+}
+
+/// メッシュの境界上の座標から作成したメッシュが、その座標を含むか確認する。
+///
+/// # 引数
+///
+/// * `row` - 標準地域メッシュの南端から数えたメッシュの位置
+/// * `col` - 標準地域メッシュの西端から数えたメッシュの位置
+/// * `lat_diff` - メッシュの南端と北端の緯度の差（度単位）
+/// * `lon_diff` - メッシュの西端と東端の経度の差（度単位）
+fn check_contains_edge<M: Mesh>(
+    row: u32,
+    col: u32,
+    lat_diff: f64,
+    lon_diff: f64,
+) -> Result<(), TestCaseError> {
+    let lat = (SOUTHERNMOST + row as f64 * lat_diff).min(NORTHERNMOST - lat_diff);
+    let lon = (WESTERNMOST + col as f64 * lon_diff).min(EASTERNMOST - lon_diff);
+    let coord = Coordinate::new(lat, lon).unwrap();
+    let mesh = M::from_coordinate(coord).unwrap();
+    prop_assert!(
+        mesh.contains(&coord),
+        "{}は{:?}を含みません。",
+        mesh.code(),
+        coord
+    );
+    // 境界上の座標は、南西端が境界上の座標と一致するメッシュに含まれる
+    prop_assert!((mesh.south() - lat).abs() < 1e-9);
+    prop_assert!((mesh.west() - lon).abs() < 1e-9);
+
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn mesh1_contains_coordinate(
+        lat in SOUTHERNMOST..NORTHERNMOST,
+        lon in WESTERNMOST..EASTERNMOST,
+    ) {
+        check_contains::<Mesh1>(lat, lon)?;
+    }
+
+    #[test]
+    fn mesh2_contains_coordinate(
+        lat in SOUTHERNMOST..NORTHERNMOST,
+        lon in WESTERNMOST..EASTERNMOST,
+    ) {
+        check_contains::<Mesh2>(lat, lon)?;
+    }
+
+    #[test]
+    fn mesh3_contains_coordinate(
+        lat in SOUTHERNMOST..NORTHERNMOST,
+        lon in WESTERNMOST..EASTERNMOST,
+    ) {
+        check_contains::<Mesh3>(lat, lon)?;
+    }
+
+    #[test]
+    fn mesh4_contains_coordinate(
+        lat in SOUTHERNMOST..NORTHERNMOST,
+        lon in WESTERNMOST..EASTERNMOST,
+    ) {
+        check_contains::<Mesh4>(lat, lon)?;
+    }
+
+    #[test]
+    fn mesh5_contains_coordinate(
+        lat in SOUTHERNMOST..NORTHERNMOST,
+        lon in WESTERNMOST..EASTERNMOST,
+    ) {
+        check_contains::<Mesh5>(lat, lon)?;
+    }
+
+    #[test]
+    fn mesh6_contains_coordinate(
+        lat in SOUTHERNMOST..NORTHERNMOST,
+        lon in WESTERNMOST..EASTERNMOST,
+    ) {
+        check_contains::<Mesh6>(lat, lon)?;
+    }
+
+    #[test]
+    fn mesh1_contains_edge_coordinate(row in 0_u32..42, col in 0_u32..32) {
+        check_contains_edge::<Mesh1>(row, col, 40.0 / 60.0, 1.0)?;
+    }
+
+    #[test]
+    fn mesh2_contains_edge_coordinate(row in 0_u32..336, col in 0_u32..256) {
+        check_contains_edge::<Mesh2>(row, col, 5.0 / 60.0, 7.5 / 60.0)?;
+    }
+
+    #[test]
+    fn mesh3_contains_edge_coordinate(row in 0_u32..3_360, col in 0_u32..2_560) {
+        check_contains_edge::<Mesh3>(row, col, 30.0 / 3600.0, 45.0 / 3600.0)?;
+    }
+
+    #[test]
+    fn mesh4_contains_edge_coordinate(row in 0_u32..6_720, col in 0_u32..5_120) {
+        check_contains_edge::<Mesh4>(row, col, 15.0 / 3600.0, 22.5 / 3600.0)?;
+    }
+
+    #[test]
+    fn mesh5_contains_edge_coordinate(row in 0_u32..13_440, col in 0_u32..10_240) {
+        check_contains_edge::<Mesh5>(row, col, 7.5 / 3600.0, 11.25 / 3600.0)?;
+    }
+
+    #[test]
+    fn mesh6_contains_edge_coordinate(row in 0_u32..26_880, col in 0_u32..20_480) {
+        check_contains_edge::<Mesh6>(row, col, 3.75 / 3600.0, 5.625 / 3600.0)?;
+    }
+}
@@ -0,0 +1,14 @@
+//! 戻り値を使用しない場合に警告する`#[must_use]`を付けたメソッドのテスト
+//!
+//! `tests/ui`に、`#[must_use]`を付けたメソッドの戻り値を無視するとコンパイルエラーになるコードを格納する。
+//! コンパイラのメッセージが変わった場合は、次の通り実行して期待するメッセージを更新する。
+//!
+//! ```sh
+//! TRYBUILD=overwrite cargo test -p gsjp --test must_use
+//! ```
+
+#[test]
+fn must_use_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/must_use.rs");
+}
@@ -0,0 +1,87 @@
+//! `no_std`環境でメッシュを扱えることを確認するテスト
+//!
+//! `std`フィーチャーを無効にして、次の通り実行する。
+//!
+//! ```sh
+//! cargo test -p gsjp --no-default-features --features libm --test no_std
+//! ```
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+
+use gsjp::mesh::{AnyMesh, Coordinate, Mesh, Mesh1, Mesh2, Mesh3, Mesh4, Mesh5, Mesh6, MeshLevel};
+
+/// 東京タワーの座標
+fn tokyo_tower() -> Coordinate {
+    Coordinate::new(35.658581, 139.745433).unwrap()
+}
+
+#[test]
+fn coordinate_ok() {
+    assert!(Coordinate::new(35.0, 135.0).is_ok());
+    assert!(Coordinate::new(90.1, 135.0).is_err());
+    assert!(Coordinate::new(35.0, 180.1).is_err());
+}
+
+#[test]
+fn mesh_new_ok() {
+    assert_eq!("5339", Mesh1::new(String::from("5339")).unwrap().code());
+    assert_eq!("533935", Mesh2::new(String::from("533935")).unwrap().code());
+    assert_eq!(
+        "53393599",
+        Mesh3::new(String::from("53393599")).unwrap().code()
+    );
+    assert_eq!(
+        "533935994",
+        Mesh4::new(String::from("533935994")).unwrap().code()
+    );
+    assert_eq!(
+        "5339359943",
+        Mesh5::new(String::from("5339359943")).unwrap().code()
+    );
+    assert_eq!(
+        "53393599432",
+        Mesh6::new(String::from("53393599432")).unwrap().code()
+    );
+}
+
+#[test]
+fn mesh_new_err() {
+    assert!(Mesh1::new(String::from("7249")).is_err());
+    assert!(Mesh1::new(String::from("5:39")).is_err());
+    assert!(Mesh2::new(String::from("533980")).is_err());
+    assert!(Mesh3::new(String::from("5339350a")).is_err());
+    assert!(Mesh4::new(String::from("533935995")).is_err());
+    assert!(Mesh5::new(String::from("5339359940")).is_err());
+    assert!(Mesh6::new(String::from("53393599435")).is_err());
+}
+
+#[test]
+fn mesh_from_coordinate_ok() {
+    let coord = tokyo_tower();
+    assert_eq!("5339", Mesh1::from_coordinate(coord).unwrap().code());
+    assert_eq!("533935", Mesh2::from_coordinate(coord).unwrap().code());
+    assert_eq!("53393599", Mesh3::from_coordinate(coord).unwrap().code());
+    assert_eq!("533935992", Mesh4::from_coordinate(coord).unwrap().code());
+    assert_eq!("5339359921", Mesh5::from_coordinate(coord).unwrap().code());
+    assert_eq!("53393599212", Mesh6::from_coordinate(coord).unwrap().code());
+}
+
+#[test]
+fn any_mesh_ok() {
+    let codes = vec!["5339", "533935", "53393599", "533935992"];
+    let levels = vec![
+        MeshLevel::Mesh1,
+        MeshLevel::Mesh2,
+        MeshLevel::Mesh3,
+        MeshLevel::Mesh4,
+    ];
+    for (code, level) in codes.into_iter().zip(levels) {
+        let mesh = AnyMesh::new(String::from(code)).unwrap();
+        assert_eq!(level, mesh.level());
+        assert_eq!(code, mesh.code());
+    }
+}
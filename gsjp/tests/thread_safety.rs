@@ -0,0 +1,53 @@
+//! メッシュをスレッド間で共有できることを確認するテスト
+//!
+//! 公開する型が`Send`と`Sync`を実装することは、ライブラリ内でコンパイル時に確認している。
+//! ここでは、実際にメッシュを別のスレッドに移動したり、複数のスレッドから参照したりできることを確認する。
+
+use std::sync::Arc;
+use std::thread;
+
+use gsjp::mesh::{AnyMesh, Coordinate, Mesh, Mesh3, MeshLevel};
+
+#[test]
+fn move_mesh_to_thread() {
+    let mesh = Mesh3::new(String::from("53393599")).unwrap();
+    let handle = thread::spawn(move || mesh.north_mesh().unwrap());
+    assert_eq!("53394509", handle.join().unwrap().code());
+}
+
+#[test]
+fn share_meshes_between_threads() {
+    let mesh = Mesh3::new(String::from("53393599")).unwrap();
+    let children = Arc::new(mesh.subdivide_to(MeshLevel::Mesh5).unwrap());
+    let handles = (0..4)
+        .map(|i| {
+            let children = Arc::clone(&children);
+            thread::spawn(move || {
+                children
+                    .iter()
+                    .skip(i)
+                    .step_by(4)
+                    .map(|child| child.center())
+                    .collect::<Vec<Coordinate>>()
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        for center in handle.join().unwrap() {
+            assert!(mesh.contains(&center), "{:?}", center);
+        }
+    }
+}
+
+#[test]
+fn borrow_mesh_in_scoped_threads() {
+    let mesh = AnyMesh::new(String::from("533935991")).unwrap();
+    let codes = thread::scope(|scope| {
+        let handles = [
+            scope.spawn(|| mesh.to_level(MeshLevel::Mesh3).unwrap()),
+            scope.spawn(|| mesh.to_level(MeshLevel::Mesh2).unwrap()),
+        ];
+        handles.map(|handle| handle.join().unwrap().code().to_string())
+    });
+    assert_eq!(["53393599", "533935"], codes);
+}
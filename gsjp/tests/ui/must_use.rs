@@ -0,0 +1,10 @@
+#![deny(unused_must_use)]
+
+use gsjp::mesh::{Coordinate, Mesh, Mesh3};
+
+fn main() {
+    let mesh = Mesh3::new(String::from("53393599")).unwrap();
+    mesh.center();
+    mesh.north();
+    Coordinate::new(35.0, 139.0).unwrap().lat();
+}
@@ -0,0 +1,212 @@
+use std::path::Path;
+
+use shapefile::{Point, Polygon};
+
+/// メッシュを切り抜くポリゴン
+///
+/// ポリゴンは、X座標を経度、Y座標を緯度とする。
+#[derive(Debug, Clone)]
+pub struct ClipPolygon {
+    /// ポリゴン
+    polygons: Vec<Polygon>,
+}
+
+impl ClipPolygon {
+    /// ポリゴンから切り抜くポリゴンを作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `polygons` - ポリゴン
+    ///
+    /// # 戻り値
+    ///
+    /// 切り抜くポリゴン
+    pub fn new(polygons: Vec<Polygon>) -> Self {
+        Self { polygons }
+    }
+
+    /// Shapeファイルからポリゴンを読み込んで、切り抜くポリゴンを作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `path` - ポリゴンを記録したShapeファイルのパス
+    ///
+    /// # 戻り値
+    ///
+    /// 切り抜くポリゴン
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, shapefile::Error> {
+        Ok(Self::new(shapefile::read_shapes_as::<_, Polygon>(path)?))
+    }
+
+    /// 矩形が切り抜くポリゴンと交差するか確認する。
+    ///
+    /// 最初に矩形とポリゴンの境界矩形が重なるか確認して、重なる場合にポリゴンの辺と
+    /// 矩形が交差するか、または矩形がポリゴンの内部にあるかを確認する。
+    ///
+    /// # 引数
+    ///
+    /// * `north` - 矩形の北端の緯度
+    /// * `south` - 矩形の南端の緯度
+    /// * `west` - 矩形の西端の経度
+    /// * `east` - 矩形の東端の経度
+    ///
+    /// # 戻り値
+    ///
+    /// 矩形がいずれかのポリゴンと交差する場合は`true`、交差しない場合は`false`
+    pub fn intersects(&self, north: f64, south: f64, west: f64, east: f64) -> bool {
+        let rect = Rect {
+            north,
+            south,
+            west,
+            east,
+        };
+        self.polygons
+            .iter()
+            .any(|polygon| polygon_intersects_rect(polygon, &rect))
+    }
+}
+
+/// 矩形
+struct Rect {
+    north: f64,
+    south: f64,
+    west: f64,
+    east: f64,
+}
+
+/// ポリゴンと矩形が交差するか確認する。
+fn polygon_intersects_rect(polygon: &Polygon, rect: &Rect) -> bool {
+    let bbox = polygon.bbox();
+    if bbox.max.x < rect.west
+        || rect.east < bbox.min.x
+        || bbox.max.y < rect.south
+        || rect.north < bbox.min.y
+    {
+        return false;
+    }
+    // ポリゴンのいずれかの辺が矩形と交差する場合
+    for ring in polygon.rings() {
+        for edge in ring.points().windows(2) {
+            if segment_intersects_rect(&edge[0], &edge[1], rect) {
+                return true;
+            }
+        }
+    }
+    // ポリゴンの辺と矩形が交差しない場合は、矩形全体がポリゴンの内部にあるか、外部にある
+    contains_point(polygon, rect.west, rect.south)
+}
+
+/// 線分と矩形が交差するか、Liang-Barskyのアルゴリズムで確認する。
+fn segment_intersects_rect(p1: &Point, p2: &Point, rect: &Rect) -> bool {
+    let dx = p2.x - p1.x;
+    let dy = p2.y - p1.y;
+    let mut t0 = 0.0_f64;
+    let mut t1 = 1.0_f64;
+    let clips = [
+        (-dx, p1.x - rect.west),
+        (dx, rect.east - p1.x),
+        (-dy, p1.y - rect.south),
+        (dy, rect.north - p1.y),
+    ];
+    for (p, q) in clips {
+        if p == 0.0 {
+            if q < 0.0 {
+                return false;
+            }
+        } else {
+            let t = q / p;
+            if p < 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+            if t1 < t0 {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+/// 点がポリゴンの内部にあるか、交差数判定で確認する。
+///
+/// すべてのリングの辺との交差数を数えるため、内側のリング（穴）の内部にある点は
+/// ポリゴンの外部にあると判定する。
+fn contains_point(polygon: &Polygon, x: f64, y: f64) -> bool {
+    let mut inside = false;
+    for ring in polygon.rings() {
+        for edge in ring.points().windows(2) {
+            let (a, b) = (&edge[0], &edge[1]);
+            if (a.y <= y) != (b.y <= y) {
+                let cross_x = a.x + (y - a.y) / (b.y - a.y) * (b.x - a.x);
+                if x < cross_x {
+                    inside = !inside;
+                }
+            }
+        }
+    }
+
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use shapefile::PolygonRing;
+
+    /// 東経135度から136度、北緯35度から36度の範囲に収まる三角形
+    fn triangle() -> ClipPolygon {
+        ClipPolygon::new(vec![Polygon::with_rings(vec![PolygonRing::Outer(vec![
+            Point::new(135.0, 35.0),
+            Point::new(135.0, 36.0),
+            Point::new(136.0, 35.0),
+            Point::new(135.0, 35.0),
+        ])])])
+    }
+
+    #[test]
+    fn intersects_ok() {
+        let clip = triangle();
+        let inputs = vec![
+            // 三角形の内部にある矩形
+            (35.2, 35.1, 135.1, 135.2),
+            // 三角形の斜辺と交差する矩形
+            (35.6, 35.4, 135.4, 135.6),
+            // 三角形を内部に含む矩形
+            (37.0, 34.0, 134.0, 137.0),
+        ];
+        for (north, south, west, east) in inputs {
+            assert!(
+                clip.intersects(north, south, west, east),
+                "north: {}, south: {}, west: {}, east: {}",
+                north,
+                south,
+                west,
+                east
+            );
+        }
+    }
+
+    #[test]
+    fn intersects_not() {
+        let clip = triangle();
+        let inputs = vec![
+            // 三角形の境界矩形の外部にある矩形
+            (37.0, 36.5, 135.1, 135.2),
+            // 三角形の境界矩形の内部にあるが、三角形の外部にある矩形
+            (35.9, 35.8, 135.8, 135.9),
+        ];
+        for (north, south, west, east) in inputs {
+            assert!(
+                !clip.intersects(north, south, west, east),
+                "north: {}, south: {}, west: {}, east: {}",
+                north,
+                south,
+                west,
+                east
+            );
+        }
+    }
+}
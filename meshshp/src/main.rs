@@ -1,63 +1,134 @@
+mod clip;
+mod sink;
+
 use std::path::PathBuf;
 
 use clap::Parser;
-use gsjp::mesh::{Coordinate, Mesh, Mesh1, Mesh2, Mesh3, Mesh4, Mesh5, Mesh6};
-use shapefile::dbase::{FieldName, FieldValue, Record, TableWriterBuilder};
-use shapefile::{Point, Polygon, PolygonRing, ShapeWriter};
+use gsjp::mesh::{
+    Coordinate, Mesh, Mesh1, Mesh2, Mesh3, Mesh4, Mesh5, Mesh6, MeshGrid, MeshInfo, MeshLevel,
+    EASTERNMOST, NORTHERNMOST, SOUTHERNMOST, WESTERNMOST,
+};
+
+use crate::clip::ClipPolygon;
+use crate::sink::{CsvSink, GeoJsonSink, MeshSink, ShapefileSink};
 
-/// 北緯48度から20度、東経118度から150度までの範囲の標準地域メッシュをShapeファイル形式で出力する。
+/// 北緯48度から20度、東経118度から150度までの範囲の標準地域メッシュを、Shapeファイル、CSVまたはGeoJSON形式で出力する。
 fn main() {
     // コマンドライン引数をパース
     let args = Args::parse();
 
+    let clip = match args.clip.as_deref().map(load_clip).transpose() {
+        Ok(clip) => clip,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    };
     let boundary = Boundary {
         northernmost: args.northernmost,
         southernmost: args.southernmost,
         westernmost: args.westernmost,
         easternmost: args.easternmost,
+        clip,
     };
-    let shp_path = PathBuf::from(&args.output);
-    let dbf_path = shp_path.with_extension("dbf");
+    if let Err(message) = boundary.validate() {
+        eprintln!("{}", message);
+        std::process::exit(1);
+    }
+    let path = PathBuf::from(&args.output);
 
-    let width = args.mesh_kind.width();
-    let height = args.mesh_kind.height();
+    match args.format {
+        OutputFormat::Shapefile => {
+            let mut sink = ShapefileSink::new(&path, args.mesh_kind.code_len());
+            write_meshes(args.mesh_kind, &boundary, &mut sink);
+        }
+        OutputFormat::Csv => {
+            write_meshes(args.mesh_kind, &boundary, &mut CsvSink::from_path(&path))
+        }
+        OutputFormat::GeoJson => write_meshes(
+            args.mesh_kind,
+            &boundary,
+            &mut GeoJsonSink::from_path(&path),
+        ),
+    }
+}
 
-    let mut shape_writer = ShapeWriter::from_path(shp_path).unwrap();
-    let code_field_name = FieldName::try_from("code").unwrap();
-    let mut table_writer = TableWriterBuilder::new()
-        .add_character_field(code_field_name, 11)
-        .build_with_file_dest(dbf_path)
-        .unwrap();
+/// 切り抜くポリゴンをShapeファイルから読み込む。
+///
+/// # 引数
+///
+/// * `path` - ポリゴンを記録したShapeファイルのパス
+///
+/// # 戻り値
+///
+/// 切り抜くポリゴン。読み込めない場合は、Shapeファイルのパスを示すエラーメッセージ
+fn load_clip(path: &str) -> Result<ClipPolygon, String> {
+    ClipPolygon::from_path(path).map_err(|e| {
+        format!(
+            "切り抜くポリゴンのShapeファイル({})を読み込めません({})。",
+            path, e
+        )
+    })
+}
 
-    // 西から東、南から北に向かってメッシュを出力
-    // メッシュの中心の座標を走査
-    let mut lat = 20.0 + height / 2.0;
-    while lat < 48.0 {
-        let mut lon = 118.0 + width / 2.0;
-        while lon < 150.0 {
-            if boundary.contains(lat, lon) {
-                let mesh_info = args.mesh_kind.mesh_info(lat, lon);
-                let mesh = Polygon::with_rings(vec![PolygonRing::Outer(vec![
-                    Point::new(mesh_info.west, mesh_info.north),
-                    Point::new(mesh_info.east, mesh_info.north),
-                    Point::new(mesh_info.east, mesh_info.south),
-                    Point::new(mesh_info.west, mesh_info.south),
-                    Point::new(mesh_info.west, mesh_info.north),
-                ])]);
-                shape_writer.write_shape(&mesh).unwrap();
-                let mut record = Record::default();
-                record.insert(
-                    String::from("code"),
-                    FieldValue::Character(Some(mesh_info.code)),
-                );
-                table_writer.write_record(&record).unwrap();
-            }
-            lon += width;
+/// 境界内の標準地域メッシュを出力先に書き込む。
+///
+/// すべてのメッシュを書き込んだ後に、出力先の出力を完了する。
+///
+/// # 引数
+///
+/// * `mesh_kind` - 出力するメッシュの種類
+/// * `boundary` - 出力するメッシュの境界
+/// * `sink` - メッシュの出力先
+fn write_meshes(mesh_kind: MeshKind, boundary: &Boundary, sink: &mut impl MeshSink) {
+    match mesh_kind {
+        MeshKind::Mesh1 => write_grid::<Mesh1>(boundary, sink),
+        MeshKind::Mesh2 => write_grid::<Mesh2>(boundary, sink),
+        MeshKind::Mesh3 => write_grid::<Mesh3>(boundary, sink),
+        MeshKind::Mesh4 => write_grid::<Mesh4>(boundary, sink),
+        MeshKind::Mesh5 => write_grid::<Mesh5>(boundary, sink),
+        MeshKind::Mesh6 => write_grid::<Mesh6>(boundary, sink),
+    }
+
+    sink.finish();
+}
+
+/// 境界と重なるメッシュのうち、切り抜くポリゴンと交差するメッシュを出力先に書き込む。
+///
+/// メッシュは、西から東、南から北に向かって書き込む。
+///
+/// # 引数
+///
+/// * `boundary` - 出力するメッシュの境界
+/// * `sink` - メッシュの出力先
+fn write_grid<M: Mesh>(boundary: &Boundary, sink: &mut impl MeshSink) {
+    let (south_west, north_east) = boundary.corners();
+    // 南西端が全国の範囲の北端または東端上にある場合は、出力するメッシュがない
+    let Ok(grid) = MeshGrid::<M>::new(south_west, north_east) else {
+        return;
+    };
+    for mesh in grid {
+        let mesh_info = mesh.into_info();
+        if boundary.intersects(&mesh_info) {
+            sink.write(&mesh_info);
         }
-        lat += height;
     }
 }
 
+/// 出力形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum OutputFormat {
+    /// Shapeファイル形式
+    Shapefile,
+
+    /// CSV形式
+    Csv,
+
+    /// GeoJSON形式
+    #[value(name = "geojson")]
+    GeoJson,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 enum MeshKind {
     /// 第1次地域区画
@@ -79,109 +150,25 @@ enum MeshKind {
     Mesh6,
 }
 
-struct MeshInfo {
-    /// メッシュコード
-    code: String,
-    /// メッシュの北端の緯度
-    north: f64,
-    /// メッシュの南端の緯度
-    south: f64,
-    /// メッシュの西端の経度
-    west: f64,
-    /// メッシュの東端の経度
-    east: f64,
-}
-
 impl MeshKind {
-    /// メッシュの幅を度単位で返す。
-    fn width(&self) -> f64 {
-        match self {
-            MeshKind::Mesh1 => 1.0,
-            MeshKind::Mesh2 => 7.0 / 60.0 + 30.0 / 3600.0,
-            MeshKind::Mesh3 => 45.0 / 3600.0,
-            MeshKind::Mesh4 => 22.5 / 3600.0,
-            MeshKind::Mesh5 => 11.25 / 3600.0,
-            MeshKind::Mesh6 => 5.625 / 3600.0,
-        }
-    }
-
-    /// メッシュの高さを度単位で返す。
-    fn height(&self) -> f64 {
+    /// メッシュのレベルを返す。
+    fn level(&self) -> MeshLevel {
         match self {
-            MeshKind::Mesh1 => 40.0 / 60.0,
-            MeshKind::Mesh2 => 5.0 / 60.0,
-            MeshKind::Mesh3 => 30.0 / 3600.0,
-            MeshKind::Mesh4 => 15.0 / 3600.0,
-            MeshKind::Mesh5 => 7.5 / 3600.0,
-            MeshKind::Mesh6 => 3.75 / 3600.0,
+            MeshKind::Mesh1 => MeshLevel::Mesh1,
+            MeshKind::Mesh2 => MeshLevel::Mesh2,
+            MeshKind::Mesh3 => MeshLevel::Mesh3,
+            MeshKind::Mesh4 => MeshLevel::Mesh4,
+            MeshKind::Mesh5 => MeshLevel::Mesh5,
+            MeshKind::Mesh6 => MeshLevel::Mesh6,
         }
     }
 
-    /// メッシュのコードを返す。
-    fn mesh_info(&self, lat: f64, lon: f64) -> MeshInfo {
-        let coord = Coordinate::new(lat, lon).unwrap();
-        match self {
-            MeshKind::Mesh1 => {
-                let mesh = Mesh1::from_coordinate(coord).unwrap();
-                MeshInfo {
-                    code: mesh.code().to_string(),
-                    north: mesh.north(),
-                    south: mesh.south(),
-                    west: mesh.west(),
-                    east: mesh.east(),
-                }
-            }
-            MeshKind::Mesh2 => {
-                let mesh = Mesh2::from_coordinate(coord).unwrap();
-                MeshInfo {
-                    code: mesh.code().to_string(),
-                    north: mesh.north(),
-                    south: mesh.south(),
-                    west: mesh.west(),
-                    east: mesh.east(),
-                }
-            }
-            MeshKind::Mesh3 => {
-                let mesh = Mesh3::from_coordinate(coord).unwrap();
-                MeshInfo {
-                    code: mesh.code().to_string(),
-                    north: mesh.north(),
-                    south: mesh.south(),
-                    west: mesh.west(),
-                    east: mesh.east(),
-                }
-            }
-            MeshKind::Mesh4 => {
-                let mesh = Mesh4::from_coordinate(coord).unwrap();
-                MeshInfo {
-                    code: mesh.code().to_string(),
-                    north: mesh.north(),
-                    south: mesh.south(),
-                    west: mesh.west(),
-                    east: mesh.east(),
-                }
-            }
-            MeshKind::Mesh5 => {
-                let mesh = Mesh5::from_coordinate(coord).unwrap();
-                MeshInfo {
-                    code: mesh.code().to_string(),
-                    north: mesh.north(),
-                    south: mesh.south(),
-                    west: mesh.west(),
-                    east: mesh.east(),
-                }
-            }
-            MeshKind::Mesh6 => {
-                let mesh = Mesh6::from_coordinate(coord).unwrap();
-                MeshInfo {
-                    code: mesh.code().to_string(),
-                    north: mesh.north(),
-                    south: mesh.south(),
-                    west: mesh.west(),
-                    east: mesh.east(),
-                }
-            }
-        }
+    /// メッシュコードの文字数を返す。
+    ///
+    /// DBFファイルのメッシュコードを格納するフィールドの幅として使用する。
+    fn code_len(&self) -> u8 {
+        // メッシュコードは最長でも11文字であるため、`u8`に収まる
+        self.level().code_len() as u8
     }
 }
 
@@ -208,8 +195,26 @@ struct Args {
     #[arg(short, long, help = "格子点を出力する最東端の経度(例:136.0)")]
     easternmost: Option<f64>,
 
-    /// 出力Shapeファイル
-    #[arg(help = "出力Shapeファイルのパス")]
+    /// 出力するメッシュを切り抜くポリゴンを記録したShapeファイル
+    #[arg(
+        short,
+        long,
+        help = "メッシュを切り抜くポリゴンを記録したShapeファイルのパス"
+    )]
+    clip: Option<String>,
+
+    /// 出力形式
+    #[arg(
+        short,
+        long,
+        value_enum,
+        default_value = "shapefile",
+        help = "出力形式"
+    )]
+    format: OutputFormat,
+
+    /// 出力ファイル
+    #[arg(help = "出力ファイルのパス")]
     output: String,
 }
 
@@ -219,31 +224,375 @@ pub struct Boundary {
     southernmost: Option<f64>,
     westernmost: Option<f64>,
     easternmost: Option<f64>,
+    clip: Option<ClipPolygon>,
 }
 
 impl Boundary {
-    fn contains(&self, lat: f64, lon: f64) -> bool {
-        if let Some(northernmost) = self.northernmost {
-            if northernmost < lat {
-                return false;
+    /// 境界を検証する。
+    ///
+    /// 境界が全国の範囲（北緯20度から48度、東経118度から150度）の外にある場合や、
+    /// 最北端と最南端、または最西端と最東端が逆に指定されている場合は、出力するメッシュがないためエラーとする。
+    ///
+    /// # 戻り値
+    ///
+    /// `()`。境界が不正な場合はエラーメッセージ
+    fn validate(&self) -> Result<(), String> {
+        let lats = [("最北端", self.northernmost), ("最南端", self.southernmost)];
+        for (name, lat) in lats {
+            if let Some(lat) = lat {
+                if !(SOUTHERNMOST..=NORTHERNMOST).contains(&lat) {
+                    return Err(format!(
+                        "{}の緯度は{}度から{}度の範囲で指定してください({})。",
+                        name, SOUTHERNMOST, NORTHERNMOST, lat
+                    ));
+                }
+            }
+        }
+        let lons = [("最西端", self.westernmost), ("最東端", self.easternmost)];
+        for (name, lon) in lons {
+            if let Some(lon) = lon {
+                if !(WESTERNMOST..=EASTERNMOST).contains(&lon) {
+                    return Err(format!(
+                        "{}の経度は{}度から{}度の範囲で指定してください({})。",
+                        name, WESTERNMOST, EASTERNMOST, lon
+                    ));
+                }
             }
         }
-        if let Some(southernmost) = self.southernmost {
-            if lat < southernmost {
-                return false;
+        if let (Some(northernmost), Some(southernmost)) = (self.northernmost, self.southernmost) {
+            if northernmost < southernmost {
+                return Err(format!(
+                    "最北端の緯度({})が最南端の緯度({})より南にあります。",
+                    northernmost, southernmost
+                ));
             }
         }
-        if let Some(westernmost) = self.westernmost {
-            if lon < westernmost {
-                return false;
+        if let (Some(westernmost), Some(easternmost)) = (self.westernmost, self.easternmost) {
+            if easternmost < westernmost {
+                return Err(format!(
+                    "最東端の経度({})が最西端の経度({})より西にあります。",
+                    easternmost, westernmost
+                ));
             }
         }
-        if let Some(easternmost) = self.easternmost {
-            if easternmost < lon {
-                return false;
+
+        Ok(())
+    }
+
+    /// 境界の南西端と北東端の座標を返す。
+    ///
+    /// 指定されていない境界は、全国の範囲の端とする。
+    ///
+    /// # 戻り値
+    ///
+    /// 境界の南西端と北東端の座標
+    fn corners(&self) -> (Coordinate, Coordinate) {
+        // 境界は検証済みであるため、座標を作成できる
+        let south_west = Coordinate::new(
+            self.southernmost.unwrap_or(SOUTHERNMOST),
+            self.westernmost.unwrap_or(WESTERNMOST),
+        )
+        .unwrap();
+        let north_east = Coordinate::new(
+            self.northernmost.unwrap_or(NORTHERNMOST),
+            self.easternmost.unwrap_or(EASTERNMOST),
+        )
+        .unwrap();
+
+        (south_west, north_east)
+    }
+
+    /// メッシュが切り抜くポリゴンと交差するか確認する。
+    ///
+    /// 切り抜くポリゴンが指定されていない場合は、常に交差すると判定する。
+    fn intersects(&self, mesh_info: &MeshInfo) -> bool {
+        match &self.clip {
+            Some(clip) => clip.intersects(
+                mesh_info.north,
+                mesh_info.south,
+                mesh_info.west,
+                mesh_info.east,
+            ),
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use shapefile::dbase::{FieldValue, Reader};
+    use shapefile::{Point, Polygon, PolygonRing, ShapeWriter};
+
+    /// 書き込まれたメッシュをメモリに保持する出力先
+    #[derive(Default)]
+    struct MemorySink {
+        infos: Vec<MeshInfo>,
+        finished: bool,
+    }
+
+    impl MeshSink for MemorySink {
+        fn write(&mut self, info: &MeshInfo) {
+            assert!(!self.finished);
+            self.infos.push(info.clone());
+        }
+
+        fn finish(&mut self) {
+            self.finished = true;
+        }
+    }
+
+    #[test]
+    fn write_meshes_mesh1_code_field_ok() {
+        let shp_path = std::env::temp_dir().join("meshshp_write_meshes_mesh1.shp");
+        let dbf_path = shp_path.with_extension("dbf");
+        let boundary = Boundary {
+            northernmost: Some(36.0),
+            southernmost: Some(35.0),
+            westernmost: Some(135.0),
+            easternmost: Some(140.0),
+            clip: None,
+        };
+        write_meshes(
+            MeshKind::Mesh1,
+            &boundary,
+            &mut ShapefileSink::new(&shp_path, MeshKind::Mesh1.code_len()),
+        );
+
+        let mut reader = Reader::from_path(&dbf_path).unwrap();
+        let field = reader
+            .fields()
+            .iter()
+            .find(|field| field.name() == "code")
+            .unwrap();
+        assert_eq!(4, field.length());
+        let records = reader.read().unwrap();
+        assert!(!records.is_empty());
+        for record in records {
+            match record.get("code") {
+                Some(FieldValue::Character(Some(code))) => {
+                    assert_eq!(4, code.len(), "{}", code);
+                    assert!(Mesh1::new(code.clone()).is_ok(), "{}", code);
+                }
+                value => panic!("想定していないフィールドの値を取得しました({:?})。", value),
             }
         }
 
-        true
+        std::fs::remove_file(&shp_path).unwrap();
+        std::fs::remove_file(&dbf_path).unwrap();
+        std::fs::remove_file(shp_path.with_extension("prj")).unwrap();
+    }
+
+    #[test]
+    fn write_meshes_clip_ok() {
+        let clip_path = std::env::temp_dir().join("meshshp_write_meshes_clip_polygon.shp");
+        let shp_path = std::env::temp_dir().join("meshshp_write_meshes_clip.shp");
+        let dbf_path = shp_path.with_extension("dbf");
+        // 第1次地域区画5339の南西寄りに収まる三角形
+        let mut clip_writer = ShapeWriter::from_path(&clip_path).unwrap();
+        clip_writer
+            .write_shape(&Polygon::with_rings(vec![PolygonRing::Outer(vec![
+                Point::new(139.01, 35.34),
+                Point::new(139.01, 35.8),
+                Point::new(139.3, 35.49),
+                Point::new(139.01, 35.34),
+            ])]))
+            .unwrap();
+        drop(clip_writer);
+        let boundary = Boundary {
+            northernmost: Some(36.0),
+            southernmost: Some(35.0),
+            westernmost: Some(138.0),
+            easternmost: Some(140.0),
+            clip: Some(ClipPolygon::from_path(&clip_path).unwrap()),
+        };
+        write_meshes(
+            MeshKind::Mesh2,
+            &boundary,
+            &mut ShapefileSink::new(&shp_path, MeshKind::Mesh2.code_len()),
+        );
+
+        let mut reader = Reader::from_path(&dbf_path).unwrap();
+        let codes = reader
+            .read()
+            .unwrap()
+            .into_iter()
+            .map(|record| match record.get("code") {
+                Some(FieldValue::Character(Some(code))) => code.clone(),
+                value => panic!("想定していないフィールドの値を取得しました({:?})。", value),
+            })
+            .collect::<Vec<_>>();
+        // 三角形の内部にある、または三角形と交差するメッシュ
+        for code in ["533900", "533901", "533911", "533912", "533950"] {
+            assert!(codes.contains(&code.to_string()), "{}", code);
+        }
+        // 三角形の外部にあるメッシュ
+        for code in ["533907", "533960", "533977", "533807", "523970"] {
+            assert!(!codes.contains(&code.to_string()), "{}", code);
+        }
+
+        std::fs::remove_file(&clip_path).unwrap();
+        std::fs::remove_file(&shp_path).unwrap();
+        std::fs::remove_file(&dbf_path).unwrap();
+        std::fs::remove_file(shp_path.with_extension("prj")).unwrap();
+    }
+
+    #[test]
+    fn write_meshes_prj_ok() {
+        let shp_path = std::env::temp_dir().join("meshshp_write_meshes_prj.shp");
+        let dbf_path = shp_path.with_extension("dbf");
+        let prj_path = shp_path.with_extension("prj");
+        let boundary = Boundary {
+            northernmost: Some(36.0),
+            southernmost: Some(35.0),
+            westernmost: Some(139.0),
+            easternmost: Some(140.0),
+            clip: None,
+        };
+        write_meshes(
+            MeshKind::Mesh1,
+            &boundary,
+            &mut ShapefileSink::new(&shp_path, MeshKind::Mesh1.code_len()),
+        );
+
+        let wkt = std::fs::read_to_string(&prj_path).unwrap();
+        assert!(wkt.starts_with("GEOGCS["), "{}", wkt);
+        assert!(wkt.contains("WGS 84"), "{}", wkt);
+
+        std::fs::remove_file(&shp_path).unwrap();
+        std::fs::remove_file(&dbf_path).unwrap();
+        std::fs::remove_file(&prj_path).unwrap();
+    }
+
+    #[test]
+    fn mesh_kind_code_len_ok() {
+        let inputs = vec![
+            (MeshKind::Mesh1, Mesh1::CODE_LEN),
+            (MeshKind::Mesh2, Mesh2::CODE_LEN),
+            (MeshKind::Mesh3, Mesh3::CODE_LEN),
+            (MeshKind::Mesh4, Mesh4::CODE_LEN),
+            (MeshKind::Mesh5, Mesh5::CODE_LEN),
+            (MeshKind::Mesh6, Mesh6::CODE_LEN),
+        ];
+        for (kind, expected) in inputs {
+            assert_eq!(expected, usize::from(kind.code_len()), "{:?}", kind);
+        }
+    }
+
+    #[test]
+    fn load_clip_err() {
+        let path = std::env::temp_dir().join("meshshp_load_clip_missing.shp");
+        let path = path.to_str().unwrap();
+        let message = load_clip(path).unwrap_err();
+        assert!(message.contains(path), "{}", message);
+    }
+
+    #[test]
+    fn boundary_validate_ok() {
+        assert!(Boundary::default().validate().is_ok());
+        let boundary = Boundary {
+            northernmost: Some(36.0),
+            southernmost: Some(35.0),
+            westernmost: Some(135.0),
+            easternmost: Some(136.0),
+            clip: None,
+        };
+        assert!(boundary.validate().is_ok());
+        let boundary = Boundary {
+            northernmost: Some(NORTHERNMOST),
+            southernmost: Some(SOUTHERNMOST),
+            westernmost: Some(WESTERNMOST),
+            easternmost: Some(EASTERNMOST),
+            clip: None,
+        };
+        assert!(boundary.validate().is_ok());
+    }
+
+    #[test]
+    fn boundary_validate_swapped_north_south() {
+        let boundary = Boundary {
+            northernmost: Some(35.0),
+            southernmost: Some(36.0),
+            ..Default::default()
+        };
+        let message = boundary.validate().unwrap_err();
+        assert!(message.contains("最北端"), "{}", message);
+    }
+
+    #[test]
+    fn boundary_validate_swapped_east_west() {
+        let boundary = Boundary {
+            westernmost: Some(136.0),
+            easternmost: Some(135.0),
+            ..Default::default()
+        };
+        let message = boundary.validate().unwrap_err();
+        assert!(message.contains("最東端"), "{}", message);
+    }
+
+    #[test]
+    fn boundary_validate_out_of_extent() {
+        let inputs = vec![
+            Boundary {
+                northernmost: Some(48.5),
+                ..Default::default()
+            },
+            Boundary {
+                southernmost: Some(19.5),
+                ..Default::default()
+            },
+            Boundary {
+                westernmost: Some(117.5),
+                ..Default::default()
+            },
+            Boundary {
+                easternmost: Some(150.5),
+                ..Default::default()
+            },
+        ];
+        for boundary in inputs {
+            assert!(boundary.validate().is_err());
+        }
+    }
+
+    #[test]
+    fn write_meshes_memory_sink_ok() {
+        // 第1次地域区画5339に含まれる第2次地域区画
+        let boundary = Boundary {
+            northernmost: Some(36.0),
+            southernmost: Some(35.34),
+            westernmost: Some(139.0),
+            easternmost: Some(140.0),
+            clip: None,
+        };
+        let mut sink = MemorySink::default();
+        write_meshes(MeshKind::Mesh2, &boundary, &mut sink);
+        assert!(sink.finished);
+        assert_eq!(8 * 8, sink.infos.len());
+        for info in &sink.infos {
+            assert!(info.code.starts_with("5339"), "{}", info.code);
+        }
+        assert_eq!("533900", sink.infos[0].code);
+        assert_eq!("533977", sink.infos[63].code);
+    }
+
+    #[test]
+    fn write_meshes_national_extent_ok() {
+        // 境界を指定しない場合は、全国の範囲の第1次地域区画
+        let mut sink = MemorySink::default();
+        write_meshes(MeshKind::Mesh1, &Boundary::default(), &mut sink);
+        assert_eq!(42 * 32, sink.infos.len());
+        assert_eq!("3018", sink.infos[0].code);
+        assert_eq!("7149", sink.infos[sink.infos.len() - 1].code);
+        // 全国の範囲の北端上の境界には、メッシュがない
+        let boundary = Boundary {
+            southernmost: Some(NORTHERNMOST),
+            ..Default::default()
+        };
+        let mut sink = MemorySink::default();
+        write_meshes(MeshKind::Mesh1, &boundary, &mut sink);
+        assert!(sink.finished);
+        assert!(sink.infos.is_empty());
     }
 }
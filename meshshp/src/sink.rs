@@ -0,0 +1,279 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use gsjp::mesh::MeshInfo;
+use shapefile::dbase::{FieldName, FieldValue, Record, TableWriter, TableWriterBuilder};
+use shapefile::{Point, Polygon, PolygonRing, ShapeWriter};
+
+/// 出力するメッシュの座標参照系（WGS 84、EPSG:4326）を表すWKT
+const WGS84_WKT: &str = r#"GEOGCS["WGS 84",DATUM["WGS_1984",SPHEROID["WGS 84",6378137,298.257223563,AUTHORITY["EPSG","7030"]],AUTHORITY["EPSG","6326"]],PRIMEM["Greenwich",0,AUTHORITY["EPSG","8901"]],UNIT["degree",0.0174532925199433,AUTHORITY["EPSG","9122"]],AUTHORITY["EPSG","4326"]]"#;
+
+/// メッシュの出力先
+///
+/// メッシュを作成する処理と、メッシュを書き込む形式を分離するために使用する。
+pub trait MeshSink {
+    /// メッシュを書き込む。
+    ///
+    /// # 引数
+    ///
+    /// * `info` - メッシュの情報
+    fn write(&mut self, info: &MeshInfo);
+
+    /// すべてのメッシュを書き込んだ後に、出力を完了する。
+    fn finish(&mut self);
+}
+
+/// メッシュをShapeファイル形式で出力する出力先
+///
+/// GISソフトウェアが座標参照系を認識できるように、Shapeファイルと同じ場所にPRJファイルも出力する。
+pub struct ShapefileSink {
+    /// Shapeファイルの書き込み
+    shape_writer: Option<ShapeWriter<BufWriter<File>>>,
+    /// DBFファイルの書き込み
+    table_writer: Option<TableWriter<BufWriter<File>>>,
+}
+
+impl ShapefileSink {
+    /// メッシュをShapeファイル形式で出力する出力先を作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `shp_path` - 出力Shapeファイルのパス
+    /// * `code_len` - メッシュコードを格納するフィールドの幅
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュをShapeファイル形式で出力する出力先
+    pub fn new(shp_path: &Path, code_len: u8) -> Self {
+        std::fs::write(shp_path.with_extension("prj"), WGS84_WKT).unwrap();
+        let shape_writer = ShapeWriter::from_path(shp_path).unwrap();
+        let code_field_name = FieldName::try_from("code").unwrap();
+        let table_writer = TableWriterBuilder::new()
+            .add_character_field(code_field_name, code_len)
+            .build_with_file_dest(shp_path.with_extension("dbf"))
+            .unwrap();
+
+        Self {
+            shape_writer: Some(shape_writer),
+            table_writer: Some(table_writer),
+        }
+    }
+}
+
+impl MeshSink for ShapefileSink {
+    fn write(&mut self, info: &MeshInfo) {
+        // Shapeファイルのポリゴンの外側のリングは時計回り
+        let mesh = Polygon::with_rings(vec![PolygonRing::Outer(vec![
+            Point::new(info.west, info.north),
+            Point::new(info.east, info.north),
+            Point::new(info.east, info.south),
+            Point::new(info.west, info.south),
+            Point::new(info.west, info.north),
+        ])]);
+        self.shape_writer
+            .as_mut()
+            .unwrap()
+            .write_shape(&mesh)
+            .unwrap();
+        let mut record = Record::default();
+        record.insert(
+            String::from("code"),
+            FieldValue::Character(Some(info.code.clone())),
+        );
+        self.table_writer
+            .as_mut()
+            .unwrap()
+            .write_record(&record)
+            .unwrap();
+    }
+
+    /// Shapeファイルとdbfファイルの書き込みを破棄して、ファイルに書き込む。
+    fn finish(&mut self) {
+        self.shape_writer.take();
+        self.table_writer.take();
+    }
+}
+
+/// メッシュをCSV形式で出力する出力先
+///
+/// 1行目にフィールド名を出力して、2行目以降にメッシュコードと、メッシュの北端、南端、西端及び東端を出力する。
+pub struct CsvSink<W: Write> {
+    /// CSVの書き込み
+    writer: W,
+}
+
+impl CsvSink<BufWriter<File>> {
+    /// メッシュをCSVファイルに出力する出力先を作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `path` - 出力CSVファイルのパス
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュをCSVファイルに出力する出力先
+    pub fn from_path(path: &Path) -> Self {
+        Self::new(BufWriter::new(File::create(path).unwrap()))
+    }
+}
+
+impl<W: Write> CsvSink<W> {
+    /// メッシュをCSV形式で出力する出力先を作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `writer` - CSVの書き込み先
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュをCSV形式で出力する出力先
+    pub fn new(mut writer: W) -> Self {
+        writeln!(writer, "code,north,south,west,east").unwrap();
+
+        Self { writer }
+    }
+}
+
+impl<W: Write> MeshSink for CsvSink<W> {
+    fn write(&mut self, info: &MeshInfo) {
+        writeln!(
+            self.writer,
+            "{},{},{},{},{}",
+            info.code, info.north, info.south, info.west, info.east
+        )
+        .unwrap();
+    }
+
+    fn finish(&mut self) {
+        self.writer.flush().unwrap();
+    }
+}
+
+/// メッシュをGeoJSON形式で出力する出力先
+///
+/// メッシュを、メッシュコードを`code`プロパティに持つポリゴンのフィーチャーとして、
+/// 1つのフィーチャーコレクションに出力する。
+pub struct GeoJsonSink<W: Write> {
+    /// GeoJSONの書き込み
+    writer: W,
+    /// 書き込んだメッシュの数
+    count: usize,
+}
+
+impl GeoJsonSink<BufWriter<File>> {
+    /// メッシュをGeoJSONファイルに出力する出力先を作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `path` - 出力GeoJSONファイルのパス
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュをGeoJSONファイルに出力する出力先
+    pub fn from_path(path: &Path) -> Self {
+        Self::new(BufWriter::new(File::create(path).unwrap()))
+    }
+}
+
+impl<W: Write> GeoJsonSink<W> {
+    /// メッシュをGeoJSON形式で出力する出力先を作成する。
+    ///
+    /// # 引数
+    ///
+    /// * `writer` - GeoJSONの書き込み先
+    ///
+    /// # 戻り値
+    ///
+    /// メッシュをGeoJSON形式で出力する出力先
+    pub fn new(mut writer: W) -> Self {
+        write!(writer, r#"{{"type":"FeatureCollection","features":["#).unwrap();
+
+        Self { writer, count: 0 }
+    }
+}
+
+impl<W: Write> MeshSink for GeoJsonSink<W> {
+    fn write(&mut self, info: &MeshInfo) {
+        if 0 < self.count {
+            write!(self.writer, ",").unwrap();
+        }
+        // GeoJSONのポリゴンの外側のリングは反時計回り
+        write!(
+            self.writer,
+            r#"{{"type":"Feature","properties":{{"code":"{}"}},"geometry":{{"type":"Polygon","coordinates":[[[{w},{s}],[{e},{s}],[{e},{n}],[{w},{n}],[{w},{s}]]]}}}}"#,
+            info.code,
+            n = info.north,
+            s = info.south,
+            w = info.west,
+            e = info.east,
+        )
+        .unwrap();
+        self.count += 1;
+    }
+
+    fn finish(&mut self) {
+        writeln!(self.writer, "]}}").unwrap();
+        self.writer.flush().unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mesh_info() -> MeshInfo {
+        MeshInfo {
+            code: String::from("5339"),
+            north: 36.0,
+            south: 35.333333333333336,
+            west: 139.0,
+            east: 140.0,
+        }
+    }
+
+    #[test]
+    fn csv_sink_ok() {
+        let mut sink = CsvSink::new(Vec::new());
+        sink.write(&mesh_info());
+        sink.finish();
+        let csv = String::from_utf8(sink.writer).unwrap();
+        assert_eq!(
+            "code,north,south,west,east\n5339,36,35.333333333333336,139,140\n",
+            csv
+        );
+    }
+
+    #[test]
+    fn geojson_sink_ok() {
+        let mut sink = GeoJsonSink::new(Vec::new());
+        sink.write(&mesh_info());
+        sink.write(&MeshInfo {
+            code: String::from("5340"),
+            west: 140.0,
+            east: 141.0,
+            ..mesh_info()
+        });
+        sink.finish();
+        let geojson = String::from_utf8(sink.writer).unwrap();
+        assert!(geojson.starts_with(r#"{"type":"FeatureCollection","features":[{"#));
+        assert!(geojson.ends_with("]}\n"));
+        assert_eq!(2, geojson.matches(r#""type":"Feature","#).count());
+        assert!(geojson.contains(r#""properties":{"code":"5339"}"#));
+        assert!(geojson.contains(
+            r#""coordinates":[[[139,35.333333333333336],[140,35.333333333333336],[140,36],[139,36],[139,35.333333333333336]]]"#
+        ));
+        assert!(geojson.contains(r#"}},{"type":"Feature","properties":{"code":"5340"}"#));
+    }
+
+    #[test]
+    fn geojson_sink_empty() {
+        let mut sink = GeoJsonSink::new(Vec::new());
+        sink.finish();
+        let geojson = String::from_utf8(sink.writer).unwrap();
+        assert_eq!(
+            "{\"type\":\"FeatureCollection\",\"features\":[]}\n",
+            geojson
+        );
+    }
+}